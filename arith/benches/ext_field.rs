@@ -1,6 +1,9 @@
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 use arith::GF2_128x8_256;
-use arith::{ExtensionField, Field, GF2_128x8, M31Ext3, M31Ext3x16, GF2_128};
+use arith::{
+    BabyBearExt4, BabyBearExt4x16, ExtensionField, Field, GF2_128x8, GoldilocksExt2,
+    GoldilocksExt2x8, M31Ext3, M31Ext3x16, M31Ext6, M31Ext6x16, GF2_128,
+};
 use ark_std::test_rng;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 use tynm::type_name;
@@ -154,9 +157,15 @@ pub(crate) fn bench_field<F: Field + ExtensionField>(c: &mut Criterion) {
 fn ext_by_base_benchmark(c: &mut Criterion) {
     bench_field::<M31Ext3>(c);
     bench_field::<M31Ext3x16>(c);
+    bench_field::<M31Ext6>(c);
+    bench_field::<M31Ext6x16>(c);
+    bench_field::<GoldilocksExt2>(c);
+    bench_field::<GoldilocksExt2x8>(c);
+    bench_field::<BabyBearExt4>(c);
+    bench_field::<BabyBearExt4x16>(c);
     bench_field::<GF2_128>(c);
     bench_field::<GF2_128x8>(c);
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
     bench_field::<GF2_128x8_256>(c);
 }
 
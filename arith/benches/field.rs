@@ -1,7 +1,11 @@
 // this module benchmarks the performance of different field operations
 
-use arith::{Field, GF2_128x8, GF2x8, M31Ext3, M31Ext3x16, M31x16, GF2, GF2_128, M31};
-#[cfg(target_arch = "x86_64")]
+use arith::{
+    BabyBear, BabyBearExt4, BabyBearExt4x16, BabyBearx16, Field, Frx8, GF2_128x8, GF2x8,
+    Goldilocks, GoldilocksExt2, GoldilocksExt2x8, Goldilocksx8, M31Ext3, M31Ext3x16, M31Ext6,
+    M31Ext6x16, M31x16, GF2, GF2_128, M31,
+};
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 use arith::{GF2_128x8_256, M31x16_256};
 use ark_std::test_rng;
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
@@ -176,16 +180,27 @@ pub(crate) fn bench_field<F: Field>(c: &mut Criterion) {
 fn criterion_benchmark(c: &mut Criterion) {
     bench_field::<M31>(c);
     bench_field::<M31x16>(c);
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
     bench_field::<M31x16_256>(c);
     bench_field::<M31Ext3>(c);
     bench_field::<M31Ext3x16>(c);
+    bench_field::<M31Ext6>(c);
+    bench_field::<M31Ext6x16>(c);
+    bench_field::<Goldilocks>(c);
+    bench_field::<Goldilocksx8>(c);
+    bench_field::<GoldilocksExt2>(c);
+    bench_field::<GoldilocksExt2x8>(c);
+    bench_field::<BabyBear>(c);
+    bench_field::<BabyBearx16>(c);
+    bench_field::<BabyBearExt4>(c);
+    bench_field::<BabyBearExt4x16>(c);
     bench_field::<Fr>(c);
+    bench_field::<Frx8>(c);
     bench_field::<GF2>(c);
     bench_field::<GF2x8>(c);
     bench_field::<GF2_128>(c);
     bench_field::<GF2_128x8>(c);
-    #[cfg(target_arch = "x86_64")]
+    #[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
     bench_field::<GF2_128x8_256>(c);
 }
 
@@ -0,0 +1,136 @@
+//! Batched field operations, sharing the work between many elements.
+
+use crate::{Field, ScalarField, SimdField};
+
+/// Inverts the elements in place with Montgomery's trick, with one inversion for the whole slice.
+/// Zero elements are left as they are.
+///
+/// Packed fields have nonzero elements without an inverse, use `batch_inversion_simd` for them.
+pub fn batch_inversion<F: ScalarField>(v: &mut [F]) {
+    // prefix[i] is the product of the invertible elements before v[i]
+    let mut acc = F::one();
+    let prefix = v
+        .iter()
+        .map(|x| {
+            let p = acc;
+            if !x.is_zero() {
+                acc *= x;
+            }
+            p
+        })
+        .collect::<Vec<_>>();
+
+    // a product of nonzero scalars
+    let mut acc_inv = acc.inv().unwrap();
+
+    for (x, p) in v.iter_mut().zip(prefix).rev() {
+        if !x.is_zero() {
+            let next_acc_inv = acc_inv * *x;
+            *x = acc_inv * p;
+            acc_inv = next_acc_inv;
+        }
+    }
+}
+
+/// Inverts the packed elements in place lane by lane, with one inversion for the whole slice.
+/// Zero lanes are left as they are.
+pub fn batch_inversion_simd<F: SimdField>(v: &mut [F])
+where
+    F::Scalar: ScalarField,
+{
+    let mut lanes = v.iter().flat_map(|x| x.unpack()).collect::<Vec<_>>();
+    batch_inversion(&mut lanes);
+    v.iter_mut()
+        .zip(lanes.chunks(F::pack_size()))
+        .for_each(|(x, lanes)| *x = F::pack(lanes));
+}
+
+/// base^exponent for each of the bases, sharing the walk over the bits of the exponent.
+pub fn batch_exp<F: Field>(bases: &[F], exponent: u128) -> Vec<F> {
+    let mut res = vec![F::one(); bases.len()];
+    for i in (0..128 - exponent.leading_zeros()).rev() {
+        res.iter_mut().for_each(|r| *r = r.square());
+        if (exponent >> i) & 1 == 1 {
+            res.iter_mut().zip(bases).for_each(|(r, b)| *r *= b);
+        }
+    }
+    res
+}
+
+/// The table [1, base, base^2, ..., base^(n - 1)].
+pub fn powers<F: Field>(base: &F, n: usize) -> Vec<F> {
+    let mut res = Vec::with_capacity(n);
+    let mut cur = F::one();
+    for _ in 0..n {
+        res.push(cur);
+        cur *= base;
+    }
+    res
+}
+
+/// Lagrange interpolation over a small evaluation domain, with the barycentric weights
+/// w_i = 1 / prod_{j != i} (x_i - x_j) computed once, so that evaluating needs no inversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LagrangeDomain<F: Field> {
+    points: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: ScalarField> LagrangeDomain<F> {
+    /// The points must be distinct.
+    pub fn new(points: &[F]) -> Self {
+        let mut weights = points
+            .iter()
+            .enumerate()
+            .map(|(i, x_i)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(_, x_j)| *x_i - x_j)
+                    .product::<F>()
+            })
+            .collect::<Vec<_>>();
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "the points of a Lagrange domain must be distinct"
+        );
+        batch_inversion(&mut weights);
+
+        Self {
+            points: points.to_vec(),
+            weights,
+        }
+    }
+
+    #[inline]
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    #[inline]
+    pub fn weights(&self) -> &[F] {
+        &self.weights
+    }
+
+    /// The polynomial of degree < n taking the values vals at the n points of the domain, at x.
+    ///
+    ///   sum_i vals[i] * w_i * prod_{j != i} (x - x_j)
+    pub fn evaluate(&self, vals: &[F], x: &F) -> F {
+        debug_assert_eq!(vals.len(), self.points.len());
+
+        // suffix[i] = prod_{j >= i} (x - x_j)
+        let mut suffix = vec![F::one(); self.points.len() + 1];
+        for (i, x_i) in self.points.iter().enumerate().rev() {
+            suffix[i] = suffix[i + 1] * (*x - x_i);
+        }
+
+        let mut res = F::zero();
+        let mut prefix = F::one();
+        for (i, x_i) in self.points.iter().enumerate() {
+            res += vals[i] * self.weights[i] * prefix * suffix[i + 1];
+            prefix *= *x - x_i;
+        }
+        res
+    }
+}
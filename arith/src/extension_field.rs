@@ -1,18 +1,30 @@
+mod babybear_ext;
+mod babybear_ext4x16;
 mod fr_ext;
 // mod gf2_127;
 mod gf2_128;
-mod gf2_128x8;
+pub(crate) mod gf2_128x8;
+mod goldilocks_ext;
+mod goldilocks_ext2x8;
 mod m31_ext;
 mod m31_ext3x16;
+mod m31_ext6;
+mod m31_ext6x16;
 use crate::{Field, FieldSerde};
 
+pub use babybear_ext::BabyBearExt4;
+pub use babybear_ext4x16::BabyBearExt4x16;
 // pub use gf2_127::*;
 pub use gf2_128::*;
-pub use gf2_128x8::GF2_128x8;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub use gf2_128x8::GF2_128x8_256;
+pub use gf2_128x8::{GF2_128x8, PortableGF2_128x8};
+pub use goldilocks_ext::GoldilocksExt2;
+pub use goldilocks_ext2x8::GoldilocksExt2x8;
 pub use m31_ext::M31Ext3;
 pub use m31_ext3x16::M31Ext3x16;
+pub use m31_ext6::M31Ext6;
+pub use m31_ext6x16::M31Ext6x16;
 
 /// Configurations for Extension Field over
 /// - either the Binomial polynomial x^DEGREE - W
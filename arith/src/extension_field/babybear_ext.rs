@@ -0,0 +1,321 @@
+use rand::RngCore;
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{field_common, BabyBear, Field, FieldSerde, FieldSerdeResult, ScalarField};
+
+use super::ExtensionField;
+
+/// The degree 4 extension of BabyBear, modulo x^4 - 11.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BabyBearExt4 {
+    pub v: [BabyBear; 4],
+}
+
+field_common!(BabyBearExt4);
+
+impl FieldSerde for BabyBearExt4 {
+    const SERIALIZED_SIZE: usize = (32 / 8) * 4;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| x.serialize_into(&mut writer))
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        Ok(BabyBearExt4 {
+            v: [
+                BabyBear::deserialize_from(&mut reader)?,
+                BabyBear::deserialize_from(&mut reader)?,
+                BabyBear::deserialize_from(&mut reader)?,
+                BabyBear::deserialize_from(&mut reader)?,
+            ],
+        })
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self::from(BabyBear::try_deserialize_from_ecc_format(
+            reader,
+        )?))
+    }
+}
+
+impl ScalarField for BabyBearExt4 {}
+
+impl Field for BabyBearExt4 {
+    const NAME: &'static str = "BabyBear Extension 4";
+
+    const SIZE: usize = 32 / 8 * 4;
+
+    const FIELD_SIZE: usize = 32 * 4;
+
+    const ZERO: Self = BabyBearExt4 {
+        v: [BabyBear::ZERO; 4],
+    };
+
+    const ONE: Self = BabyBearExt4 {
+        v: [
+            BabyBear::ONE,
+            BabyBear::ZERO,
+            BabyBear::ZERO,
+            BabyBear::ZERO,
+        ],
+    };
+
+    const INV_2: BabyBearExt4 = BabyBearExt4 {
+        v: [
+            BabyBear::INV_2,
+            BabyBear::ZERO,
+            BabyBear::ZERO,
+            BabyBear::ZERO,
+        ],
+    };
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        BabyBearExt4 {
+            v: std::array::from_fn(|_| BabyBear::random_unsafe(&mut rng)),
+        }
+    }
+
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        BabyBearExt4 {
+            v: [
+                BabyBear::random_bool(&mut rng),
+                BabyBear::zero(),
+                BabyBear::zero(),
+                BabyBear::zero(),
+            ],
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            let b = e & 1;
+            if b == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    // With y = x^2, write a = A + B*x for A, B in F[y] / (y^2 - 11).
+    // Then a * (A - B*x) = A^2 - y*B^2 lies in the quadratic subfield,
+    // where it is inverted through its norm, non-zero since 11 is not a square.
+    fn inv(&self) -> Option<Self> {
+        let a = &self.v;
+        let w = BabyBear::from(11);
+
+        // A^2 - y*B^2 = n0 + n1*y
+        let n0 = a[0].square() + w * (a[2].square() - (a[1] * a[3]).double());
+        let n1 = (a[0] * a[2]).double() - a[1].square() - w * a[3].square();
+
+        let norm = n0.square() - w * n1.square();
+        norm.inv().map(|norm_inv| {
+            let conjugate = BabyBearExt4 {
+                v: [a[0], -a[1], a[2], -a[3]],
+            };
+            let n_inv = BabyBearExt4 {
+                v: [
+                    n0 * norm_inv,
+                    BabyBear::ZERO,
+                    -n1 * norm_inv,
+                    BabyBear::ZERO,
+                ],
+            };
+            conjugate * n_inv
+        })
+    }
+
+    /// Squaring
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Self {
+            v: square_internal(&self.v),
+        }
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        self.v[0].as_u32_unchecked()
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        BabyBearExt4 {
+            v: std::array::from_fn(|i| {
+                BabyBear::from(u32::from_le_bytes(
+                    bytes[i * 4..(i + 1) * 4].try_into().unwrap(),
+                ))
+            }),
+        }
+    }
+}
+
+impl ExtensionField for BabyBearExt4 {
+    const DEGREE: usize = 4;
+
+    /// Extension Field
+    const W: u32 = 11;
+
+    const X: Self = BabyBearExt4 {
+        v: [
+            BabyBear::ZERO,
+            BabyBear::ONE,
+            BabyBear::ZERO,
+            BabyBear::ZERO,
+        ],
+    };
+
+    /// Base field for the extension
+    type BaseField = BabyBear;
+
+    #[inline(always)]
+    /// Multiply the extension field with the base field
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        Self {
+            v: self.v.map(|x| x * base),
+        }
+    }
+
+    #[inline(always)]
+    /// Add the extension field with the base field
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let mut res = *self;
+        res.v[0] += base;
+        res
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: [
+                self.v[3] * BabyBear::from(11),
+                self.v[0],
+                self.v[1],
+                self.v[2],
+            ],
+        }
+    }
+}
+
+impl Add<BabyBear> for BabyBearExt4 {
+    type Output = BabyBearExt4;
+
+    #[inline(always)]
+    fn add(self, rhs: BabyBear) -> Self::Output {
+        self.add_by_base_field(&rhs)
+    }
+}
+
+impl Mul<BabyBear> for BabyBearExt4 {
+    type Output = BabyBearExt4;
+
+    #[inline(always)]
+    fn mul(self, rhs: BabyBear) -> Self::Output {
+        self.mul_by_base_field(&rhs)
+    }
+}
+
+impl Neg for BabyBearExt4 {
+    type Output = BabyBearExt4;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        BabyBearExt4 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+impl From<u32> for BabyBearExt4 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        BabyBearExt4::from(BabyBear::from(x))
+    }
+}
+
+impl From<BabyBear> for BabyBearExt4 {
+    #[inline(always)]
+    fn from(x: BabyBear) -> Self {
+        BabyBearExt4 {
+            v: [x, BabyBear::zero(), BabyBear::zero(), BabyBear::zero()],
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &BabyBearExt4, b: &BabyBearExt4) -> BabyBearExt4 {
+    BabyBearExt4 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &BabyBearExt4, b: &BabyBearExt4) -> BabyBearExt4 {
+    BabyBearExt4 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+// polynomial mod (x^4 - 11)
+//
+//   (a0 + a1*x + a2*x^2 + a3*x^3) * (b0 + b1*x + b2*x^2 + b3*x^3) mod (x^4 - 11)
+// = a0*b0 + 11*(a1*b3 + a2*b2 + a3*b1)
+// + (a0*b1 + a1*b0 + 11*(a2*b3 + a3*b2))*x
+// + (a0*b2 + a1*b1 + a2*b0 + 11*a3*b3)*x^2
+// + (a0*b3 + a1*b2 + a2*b1 + a3*b0)*x^3
+#[inline(always)]
+fn mul_internal(a: &BabyBearExt4, b: &BabyBearExt4) -> BabyBearExt4 {
+    let a = &a.v;
+    let b = &b.v;
+    let w = BabyBear::from(11);
+    BabyBearExt4 {
+        v: [
+            a[0] * b[0] + w * (a[1] * b[3] + a[2] * b[2] + a[3] * b[1]),
+            a[0] * b[1] + a[1] * b[0] + w * (a[2] * b[3] + a[3] * b[2]),
+            a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + w * a[3] * b[3],
+            a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0],
+        ],
+    }
+}
+
+// same as mul; merge identical terms
+#[inline(always)]
+fn square_internal(a: &[BabyBear; 4]) -> [BabyBear; 4] {
+    let w = BabyBear::from(11);
+    [
+        a[0].square() + w * ((a[1] * a[3]).double() + a[2].square()),
+        (a[0] * a[1] + w * a[2] * a[3]).double(),
+        (a[0] * a[2]).double() + a[1].square() + w * a[3].square(),
+        (a[0] * a[3] + a[1] * a[2]).double(),
+    ]
+}
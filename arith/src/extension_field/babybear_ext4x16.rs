@@ -0,0 +1,353 @@
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    field_common, BabyBear, BabyBearExt4, BabyBearx16, ExtensionField, Field, FieldSerde,
+    FieldSerdeResult, SimdField,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BabyBearExt4x16 {
+    pub v: [BabyBearx16; 4],
+}
+
+field_common!(BabyBearExt4x16);
+
+impl FieldSerde for BabyBearExt4x16 {
+    const SERIALIZED_SIZE: usize = (512 / 8) * 4;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| x.serialize_into(&mut writer))
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self {
+            v: [
+                BabyBearx16::deserialize_from(&mut reader)?,
+                BabyBearx16::deserialize_from(&mut reader)?,
+                BabyBearx16::deserialize_from(&mut reader)?,
+                BabyBearx16::deserialize_from(&mut reader)?,
+            ],
+        })
+    }
+
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self::from(BabyBearx16::try_deserialize_from_ecc_format(
+            reader,
+        )?))
+    }
+}
+
+impl SimdField for BabyBearExt4x16 {
+    type Scalar = BabyBearExt4;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        BabyBearx16::pack_size()
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == Self::pack_size());
+        Self {
+            v: std::array::from_fn(|i| {
+                let vis = base_vec.iter().map(|x| x.v[i]).collect::<Vec<_>>();
+                BabyBearx16::pack(&vis)
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        let vs = self.v.map(|x| x.unpack());
+
+        (0..Self::pack_size())
+            .map(|j| BabyBearExt4 {
+                v: std::array::from_fn(|i| vs[i][j]),
+            })
+            .collect()
+    }
+}
+
+impl From<BabyBearx16> for BabyBearExt4x16 {
+    #[inline(always)]
+    fn from(x: BabyBearx16) -> Self {
+        Self {
+            v: [
+                x,
+                BabyBearx16::zero(),
+                BabyBearx16::zero(),
+                BabyBearx16::zero(),
+            ],
+        }
+    }
+}
+
+impl ExtensionField for BabyBearExt4x16 {
+    const DEGREE: usize = 4;
+
+    const W: u32 = 11;
+
+    const X: Self = BabyBearExt4x16 {
+        v: [
+            BabyBearx16::ZERO,
+            BabyBearx16::ONE,
+            BabyBearx16::ZERO,
+            BabyBearx16::ZERO,
+        ],
+    };
+
+    type BaseField = BabyBearx16;
+
+    #[inline(always)]
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        BabyBearExt4x16 {
+            v: self.v.map(|x| x * base),
+        }
+    }
+
+    #[inline(always)]
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let mut res = *self;
+        res.v[0] += base;
+        res
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: [
+                self.v[3] * BabyBear::from(11),
+                self.v[0],
+                self.v[1],
+                self.v[2],
+            ],
+        }
+    }
+}
+
+impl From<BabyBearExt4> for BabyBearExt4x16 {
+    #[inline(always)]
+    fn from(x: BabyBearExt4) -> Self {
+        Self {
+            v: x.v.map(BabyBearx16::pack_full),
+        }
+    }
+}
+
+impl Field for BabyBearExt4x16 {
+    const NAME: &'static str = "Packed BabyBear Extension 4";
+
+    const SIZE: usize = 512 / 8 * 4;
+
+    const FIELD_SIZE: usize = 32 * 4;
+
+    const ZERO: Self = Self {
+        v: [BabyBearx16::ZERO; 4],
+    };
+
+    const ONE: Self = Self {
+        v: [
+            BabyBearx16::ONE,
+            BabyBearx16::ZERO,
+            BabyBearx16::ZERO,
+            BabyBearx16::ZERO,
+        ],
+    };
+
+    const INV_2: Self = Self {
+        v: [
+            BabyBearx16::INV_2,
+            BabyBearx16::ZERO,
+            BabyBearx16::ZERO,
+            BabyBearx16::ZERO,
+        ],
+    };
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn random_unsafe(mut rng: impl rand::RngCore) -> Self {
+        BabyBearExt4x16 {
+            v: std::array::from_fn(|_| BabyBearx16::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl rand::RngCore) -> Self {
+        BabyBearExt4x16::from(BabyBearx16::random_bool(&mut rng))
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Self {
+            v: square_internal(&self.v),
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t.square();
+            e >>= 1;
+        }
+        res
+    }
+
+    fn inv(&self) -> Option<Self> {
+        let inverses = self
+            .unpack()
+            .iter()
+            .map(|x| x.inv())
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::pack(&inverses))
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    fn from_uniform_bytes(_bytes: &[u8; 32]) -> Self {
+        unimplemented!("vec babybear: cannot convert from 32 bytes")
+    }
+}
+
+impl Mul<BabyBearExt4> for BabyBearExt4x16 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: BabyBearExt4) -> Self::Output {
+        // polynomial mod (x^4 - 11), see mul_internal
+        let a = &self.v;
+        let b = &rhs.v;
+        let w = BabyBear::from(11);
+        let w_b = rhs.v.map(|x| x * w);
+        Self {
+            v: [
+                a[0] * b[0] + a[1] * w_b[3] + a[2] * w_b[2] + a[3] * w_b[1],
+                a[0] * b[1] + a[1] * b[0] + a[2] * w_b[3] + a[3] * w_b[2],
+                a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + a[3] * w_b[3],
+                a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0],
+            ],
+        }
+    }
+}
+
+impl Mul<BabyBear> for BabyBearExt4x16 {
+    type Output = BabyBearExt4x16;
+    #[inline(always)]
+    fn mul(self, rhs: BabyBear) -> Self::Output {
+        BabyBearExt4x16 {
+            // BabyBearx16 * BabyBear
+            v: self.v.map(|x| x * rhs),
+        }
+    }
+}
+
+impl Add<BabyBear> for BabyBearExt4x16 {
+    type Output = BabyBearExt4x16;
+    #[inline(always)]
+    fn add(self, rhs: BabyBear) -> Self::Output {
+        let mut res = self;
+        // BabyBearx16 + BabyBear
+        res.v[0] = res.v[0] + rhs;
+        res
+    }
+}
+
+impl Neg for BabyBearExt4x16 {
+    type Output = BabyBearExt4x16;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        BabyBearExt4x16 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+impl From<u32> for BabyBearExt4x16 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        BabyBearExt4x16::from(BabyBearx16::from(x))
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &BabyBearExt4x16, b: &BabyBearExt4x16) -> BabyBearExt4x16 {
+    BabyBearExt4x16 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &BabyBearExt4x16, b: &BabyBearExt4x16) -> BabyBearExt4x16 {
+    BabyBearExt4x16 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+// polynomial mod (x^4 - 11)
+//
+//   (a0 + a1*x + a2*x^2 + a3*x^3) * (b0 + b1*x + b2*x^2 + b3*x^3) mod (x^4 - 11)
+// = a0*b0 + 11*(a1*b3 + a2*b2 + a3*b1)
+// + (a0*b1 + a1*b0 + 11*(a2*b3 + a3*b2))*x
+// + (a0*b2 + a1*b1 + a2*b0 + 11*a3*b3)*x^2
+// + (a0*b3 + a1*b2 + a2*b1 + a3*b0)*x^3
+#[inline(always)]
+fn mul_internal(a: &BabyBearExt4x16, b: &BabyBearExt4x16) -> BabyBearExt4x16 {
+    let a = &a.v;
+    let b = &b.v;
+    let w = BabyBear::from(11);
+    BabyBearExt4x16 {
+        v: [
+            a[0] * b[0] + (a[1] * b[3] + a[2] * b[2] + a[3] * b[1]) * w,
+            a[0] * b[1] + a[1] * b[0] + (a[2] * b[3] + a[3] * b[2]) * w,
+            a[0] * b[2] + a[1] * b[1] + a[2] * b[0] + a[3] * b[3] * w,
+            a[0] * b[3] + a[1] * b[2] + a[2] * b[1] + a[3] * b[0],
+        ],
+    }
+}
+
+// same as mul; merge identical terms
+#[inline(always)]
+fn square_internal(a: &[BabyBearx16; 4]) -> [BabyBearx16; 4] {
+    let w = BabyBear::from(11);
+    [
+        a[0].square() + ((a[1] * a[3]).double() + a[2].square()) * w,
+        (a[0] * a[1] + a[2] * a[3] * w).double(),
+        (a[0] * a[2]).double() + a[1].square() + a[3].square() * w,
+        (a[0] * a[3] + a[1] * a[2]).double(),
+    ]
+}
@@ -1,9 +1,18 @@
-#[cfg(target_arch = "aarch64")]
+mod portable;
+pub use portable::PortableGF2_128;
+
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 pub(crate) mod neon;
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 pub type GF2_128 = neon::NeonGF2_128;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 mod avx;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub type GF2_128 = avx::AVX512GF2_128;
+
+#[cfg(any(
+    feature = "portable",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+pub type GF2_128 = PortableGF2_128;
@@ -5,7 +5,10 @@ use std::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::{field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, GF2};
+use crate::{
+    field_common, gf2_128_backend, ExtensionField, Field, FieldSerde, FieldSerdeResult,
+    ScalarField, SimdBackend, GF2,
+};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AVX512GF2_128 {
@@ -46,6 +49,8 @@ impl FieldSerde for AVX512GF2_128 {
     }
 }
 
+impl ScalarField for AVX512GF2_128 {}
+
 impl Field for AVX512GF2_128 {
     const NAME: &'static str = "Galios Field 2^128";
 
@@ -190,10 +195,9 @@ impl ExtensionField for AVX512GF2_128 {
             // Create the reduction value (0x87) and the comparison value (1)
             let reduction = {
                 let multiplier = _mm_set_epi64x(0, 0x87);
-                let one = _mm_set_epi64x(0, 1);
 
-                // Check if the MSB was 1 and create a mask
-                let mask = _mm_cmpeq_epi64(_mm_srli_si128(msb, 8), one);
+                // Check if the MSB was 1 and create a mask, 0 - 1 being all ones
+                let mask = _mm_sub_epi64(_mm_setzero_si128(), _mm_srli_si128(msb, 8));
 
                 _mm_and_si128(mask, multiplier)
             };
@@ -216,6 +220,7 @@ impl From<GF2> for AVX512GF2_128 {
 }
 
 #[inline]
+#[target_feature(enable = "pclmulqdq")]
 unsafe fn gfmul(a: __m128i, b: __m128i) -> __m128i {
     let xmm_mask = _mm_setr_epi32((0xffffffff_u32) as i32, 0x0, 0x0, 0x0);
 
@@ -274,7 +279,7 @@ impl Default for AVX512GF2_128 {
 impl PartialEq for AVX512GF2_128 {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
-        unsafe { _mm_test_all_ones(_mm_cmpeq_epi8(self.v, other.v)) == 1 }
+        unsafe { _mm_movemask_epi8(_mm_cmpeq_epi8(self.v, other.v)) == 0xFFFF }
     }
 }
 
@@ -313,6 +318,14 @@ fn sub_internal(a: &AVX512GF2_128, b: &AVX512GF2_128) -> AVX512GF2_128 {
 #[inline(always)]
 fn mul_internal(a: &AVX512GF2_128, b: &AVX512GF2_128) -> AVX512GF2_128 {
     AVX512GF2_128 {
-        v: unsafe { gfmul(a.v, b.v) },
+        v: match gf2_128_backend() {
+            SimdBackend::Portable => unsafe {
+                transmute::<u128, __m128i>(super::portable::gfmul(
+                    transmute::<__m128i, u128>(a.v),
+                    transmute::<__m128i, u128>(b.v),
+                ))
+            },
+            _ => unsafe { gfmul(a.v, b.v) },
+        },
     }
 }
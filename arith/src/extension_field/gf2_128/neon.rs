@@ -2,7 +2,7 @@ use std::iter::{Product, Sum};
 use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::{arch::aarch64::*, mem::transmute};
 
-use crate::{field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, GF2};
+use crate::{field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, ScalarField, GF2};
 
 #[derive(Clone, Copy, Debug)]
 pub struct NeonGF2_128 {
@@ -65,6 +65,8 @@ impl FieldSerde for NeonGF2_128 {
     }
 }
 
+impl ScalarField for NeonGF2_128 {}
+
 impl Field for NeonGF2_128 {
     const NAME: &'static str = "Galios Field 2^128";
 
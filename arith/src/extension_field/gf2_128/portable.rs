@@ -0,0 +1,248 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, ScalarField, GF2};
+
+/// GF(2^128) with the polynomial x^128 + x^7 + x^2 + x + 1, without any SIMD instructions.
+/// The coefficient of x^i is the i-th bit of `v`, same as the AVX and NEON versions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PortableGF2_128 {
+    pub v: u128,
+}
+
+field_common!(PortableGF2_128);
+
+#[inline(always)]
+fn add_internal(a: &PortableGF2_128, b: &PortableGF2_128) -> PortableGF2_128 {
+    PortableGF2_128 { v: a.v ^ b.v }
+}
+
+#[inline(always)]
+fn mul_internal(a: &PortableGF2_128, b: &PortableGF2_128) -> PortableGF2_128 {
+    PortableGF2_128 { v: gfmul(a.v, b.v) }
+}
+
+#[inline(always)]
+fn sub_internal(a: &PortableGF2_128, b: &PortableGF2_128) -> PortableGF2_128 {
+    add_internal(a, b)
+}
+
+impl FieldSerde for PortableGF2_128 {
+    const SERIALIZED_SIZE: usize = 16;
+
+    #[inline(always)]
+    fn serialize_into<W: std::io::Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        writer.write_all(self.v.to_le_bytes().as_ref())?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn deserialize_from<R: std::io::Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut u = [0u8; 16];
+        reader.read_exact(&mut u)?;
+        Ok(PortableGF2_128 {
+            v: u128::from_le_bytes(u),
+        })
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: std::io::Read>(mut reader: R) -> FieldSerdeResult<Self>
+    where
+        Self: Sized,
+    {
+        let mut u = [0u8; 32];
+        reader.read_exact(&mut u)?;
+        Ok(PortableGF2_128 {
+            v: u128::from_le_bytes(u[..16].try_into().unwrap()),
+        })
+    }
+}
+
+impl ScalarField for PortableGF2_128 {}
+
+impl Field for PortableGF2_128 {
+    const NAME: &'static str = "Galios Field 2^128";
+
+    const SIZE: usize = 128 / 8;
+
+    const FIELD_SIZE: usize = 128; // in bits
+
+    const ZERO: Self = PortableGF2_128 { v: 0 };
+
+    const ONE: Self = PortableGF2_128 { v: 1 };
+
+    const INV_2: Self = PortableGF2_128 { v: 0 }; // should not be used
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn random_unsafe(mut rng: impl rand::RngCore) -> Self {
+        let mut u = [0u8; 16];
+        rng.fill_bytes(&mut u);
+        PortableGF2_128 {
+            v: u128::from_le_bytes(u),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl rand::RngCore) -> Self {
+        PortableGF2_128 {
+            v: (rng.next_u32() & 1) as u128,
+        }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v == 0
+    }
+
+    #[inline(always)]
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let p_m2 = u128::MAX - 1;
+        Some(Self::exp(self, p_m2))
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        self * self
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("u32 for GF128 doesn't make sense")
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        PortableGF2_128 {
+            v: u128::from_le_bytes(bytes[..16].try_into().unwrap()),
+        }
+    }
+}
+
+impl ExtensionField for PortableGF2_128 {
+    const DEGREE: usize = 128;
+
+    const W: u32 = 0x87;
+
+    const X: Self = PortableGF2_128 { v: 2 };
+
+    type BaseField = GF2;
+
+    #[inline(always)]
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        if base.is_zero() {
+            Self::zero()
+        } else {
+            *self
+        }
+    }
+
+    #[inline(always)]
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        if base.is_zero() {
+            return *self;
+        }
+        add_internal(&Self::one(), self)
+    }
+
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: mul_by_x_internal(self.v),
+        }
+    }
+}
+
+impl From<GF2> for PortableGF2_128 {
+    #[inline(always)]
+    fn from(v: GF2) -> Self {
+        match v.v {
+            0 => Self::zero(),
+            1 => Self::one(),
+            _ => panic!("Invalid value for GF2"),
+        }
+    }
+}
+
+impl Neg for PortableGF2_128 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl From<u32> for PortableGF2_128 {
+    #[inline(always)]
+    fn from(v: u32) -> Self {
+        PortableGF2_128 { v: v as u128 }
+    }
+}
+
+// carry-less multiplication of two polynomials of degree < 64,
+// in constant time with respect to b
+#[inline(always)]
+fn clmul64(a: u64, b: u64) -> u128 {
+    let a = a as u128;
+    let mut res = 0u128;
+    for i in 0..64 {
+        let mask = 0u128.wrapping_sub(((b >> i) & 1) as u128);
+        res ^= (a << i) & mask;
+    }
+    res
+}
+
+#[inline]
+pub(crate) fn gfmul(a: u128, b: u128) -> u128 {
+    // a = a0|a1, b = b0|b1
+    let (a0, a1) = (a as u64, (a >> 64) as u64);
+    let (b0, b1) = (b as u64, (b >> 64) as u64);
+
+    // Karatsuba: a0 * b1 + a1 * b0 = (a0 + a1) * (b0 + b1) - a0 * b0 - a1 * b1
+    let lo = clmul64(a0, b0);
+    let hi = clmul64(a1, b1);
+    let mid = clmul64(a0 ^ a1, b0 ^ b1) ^ lo ^ hi;
+
+    // the low and high 128 coefficients of the product
+    let lo = lo ^ (mid << 64);
+    let hi = hi ^ (mid >> 64);
+
+    // x^128 = x^7 + x^2 + x + 1, first for the coefficients that stay below x^128,
+    // then for the ones of degree < 7 that overflow once more
+    let overflow = (hi >> 127) ^ (hi >> 126) ^ (hi >> 121);
+    let hi = hi ^ overflow;
+    lo ^ hi ^ (hi << 1) ^ (hi << 2) ^ (hi << 7)
+}
+
+#[inline(always)]
+pub(crate) fn mul_by_x_internal(a: u128) -> u128 {
+    (a << 1) ^ ((a >> 127) * 0x87)
+}
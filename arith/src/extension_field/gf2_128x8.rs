@@ -1,15 +1,24 @@
-#[cfg(target_arch = "aarch64")]
+mod portable;
+pub use portable::PortableGF2_128x8;
+
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 pub(crate) mod neon;
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 pub type GF2_128x8 = neon::NeonGF2_128x8;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 mod avx;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 mod avx256;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod dispatch;
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub type GF2_128x8_256 = avx256::AVX256GF2_128x8;
-#[cfg(all(target_arch = "x86_64", feature = "avx256"))]
-pub type GF2_128x8 = avx256::AVX256GF2_128x8;
-#[cfg(all(target_arch = "x86_64", not(feature = "avx256")))]
-pub type GF2_128x8 = avx::AVX512GF2_128x8;
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub type GF2_128x8 = dispatch::DispatchGF2_128x8;
+
+#[cfg(any(
+    feature = "portable",
+    not(any(target_arch = "x86_64", target_arch = "aarch64"))
+))]
+pub type GF2_128x8 = PortableGF2_128x8;
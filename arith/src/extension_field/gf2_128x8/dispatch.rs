@@ -0,0 +1,307 @@
+use std::iter::{Product, Sum};
+use std::mem::transmute;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{
+    field_common, gf2_128_backend, ExtensionField, Field, FieldSerde, FieldSerdeResult, GF2x8,
+    SimdBackend, SimdField, GF2, GF2_128,
+};
+
+/// DispatchGF2_128x8 packs 8 GF2_128 elements, and multiplies them with the AVX-512
+/// kernel when the host CPU supports VPCLMULQDQ, otherwise one by one.
+/// It has the layout of the AVX-512 type, which the kernel operates on in place.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[repr(C, align(64))]
+pub struct DispatchGF2_128x8 {
+    v: [GF2_128; 8],
+}
+
+field_common!(DispatchGF2_128x8);
+
+// The kernel is compiled for its instruction set, whatever the target CPU of the crate,
+// and must only be called once the CPU is known to support it.
+pub(crate) mod avx512 {
+    use super::{transmute, DispatchGF2_128x8};
+    use crate::extension_field::gf2_128x8::avx::AVX512GF2_128x8;
+
+    #[target_feature(enable = "avx512f,avx512bw,pclmulqdq,vpclmulqdq")]
+    pub(crate) unsafe fn mul(a: &DispatchGF2_128x8, b: &DispatchGF2_128x8) -> DispatchGF2_128x8 {
+        transmute(transmute::<_, AVX512GF2_128x8>(*a) * transmute::<_, AVX512GF2_128x8>(*b))
+    }
+}
+
+impl DispatchGF2_128x8 {
+    // the bits of a GF2x8, from the most significant one, as the constant terms of the lanes
+    #[inline(always)]
+    fn from_bits(v: u8) -> [GF2_128; 8] {
+        std::array::from_fn(|i| {
+            GF2_128::from(GF2 {
+                v: (v >> (7 - i)) & 1u8,
+            })
+        })
+    }
+}
+
+impl FieldSerde for DispatchGF2_128x8 {
+    const SERIALIZED_SIZE: usize = 16 * 8;
+
+    #[inline(always)]
+    fn serialize_into<W: std::io::Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|vv| vv.serialize_into(&mut writer))
+    }
+
+    #[inline(always)]
+    fn deserialize_from<R: std::io::Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut res = Self::zero();
+        for vv in res.v.iter_mut() {
+            *vv = GF2_128::deserialize_from(&mut reader)?;
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: std::io::Read>(mut _reader: R) -> FieldSerdeResult<Self>
+    where
+        Self: Sized,
+    {
+        unimplemented!("We don't have a serialization for gf2_128 in ecc yet.")
+    }
+}
+
+impl Field for DispatchGF2_128x8 {
+    const NAME: &'static str = "Dispatched Galios Field 2^128x8";
+
+    const SIZE: usize = 16 * 8;
+
+    const FIELD_SIZE: usize = 128 * 8; // in bits
+
+    const ZERO: Self = DispatchGF2_128x8 {
+        v: [GF2_128::ZERO; 8],
+    };
+
+    const ONE: Self = DispatchGF2_128x8 {
+        v: [GF2_128::ONE; 8],
+    };
+
+    const INV_2: Self = DispatchGF2_128x8 {
+        v: [GF2_128::INV_2; 8],
+    }; // should not be used
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|vv| vv.is_zero())
+    }
+
+    #[inline(always)]
+    fn random_unsafe(mut rng: impl rand::RngCore) -> Self {
+        DispatchGF2_128x8 {
+            v: std::array::from_fn(|_| GF2_128::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl rand::RngCore) -> Self {
+        DispatchGF2_128x8 {
+            v: std::array::from_fn(|_| GF2_128::random_bool(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let p_m2 = !(0u128) - 1;
+        Some(Self::exp(self, p_m2))
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        self * self
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("u32 for GF128 doesn't make sense")
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(_bytes: &[u8; 32]) -> Self {
+        unimplemented!("from_uniform_bytes for GF128 doesn't make sense")
+    }
+}
+
+impl SimdField for DispatchGF2_128x8 {
+    type Scalar = GF2_128;
+
+    #[inline(always)]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * DispatchGF2_128x8::from(*challenge)
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == 8);
+        DispatchGF2_128x8 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<GF2_128> for DispatchGF2_128x8 {
+    #[inline(always)]
+    fn from(v: GF2_128) -> Self {
+        DispatchGF2_128x8 { v: [v; 8] }
+    }
+}
+
+impl Neg for DispatchGF2_128x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl From<u32> for DispatchGF2_128x8 {
+    #[inline(always)]
+    fn from(v: u32) -> Self {
+        DispatchGF2_128x8 {
+            v: [GF2_128::from(v); 8],
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &DispatchGF2_128x8, b: &DispatchGF2_128x8) -> DispatchGF2_128x8 {
+    DispatchGF2_128x8 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &DispatchGF2_128x8, b: &DispatchGF2_128x8) -> DispatchGF2_128x8 {
+    add_internal(a, b)
+}
+
+#[inline(always)]
+fn mul_internal(a: &DispatchGF2_128x8, b: &DispatchGF2_128x8) -> DispatchGF2_128x8 {
+    match gf2_128_backend() {
+        // safety: the backend is only selected when the CPU supports it
+        SimdBackend::Avx512 => unsafe { avx512::mul(a, b) },
+        _ => DispatchGF2_128x8 {
+            v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+        },
+    }
+}
+
+impl ExtensionField for DispatchGF2_128x8 {
+    const DEGREE: usize = GF2_128::DEGREE;
+
+    const W: u32 = GF2_128::W;
+
+    const X: Self = DispatchGF2_128x8 { v: [GF2_128::X; 8] };
+
+    type BaseField = GF2x8;
+
+    #[inline(always)]
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let bits = Self::from_bits(base.v);
+        DispatchGF2_128x8 {
+            v: std::array::from_fn(|i| {
+                if bits[i].is_zero() {
+                    GF2_128::ZERO
+                } else {
+                    self.v[i]
+                }
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let bits = Self::from_bits(base.v);
+        DispatchGF2_128x8 {
+            v: std::array::from_fn(|i| self.v[i] + bits[i]),
+        }
+    }
+
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        DispatchGF2_128x8 {
+            v: self.v.map(|vv| vv.mul_by_x()),
+        }
+    }
+}
+
+impl From<GF2x8> for DispatchGF2_128x8 {
+    #[inline(always)]
+    fn from(v: GF2x8) -> Self {
+        DispatchGF2_128x8 {
+            v: Self::from_bits(v.v),
+        }
+    }
+}
+
+impl Mul<GF2> for DispatchGF2_128x8 {
+    type Output = DispatchGF2_128x8;
+
+    #[inline(always)]
+    fn mul(self, rhs: GF2) -> Self::Output {
+        if rhs.is_zero() {
+            Self::zero()
+        } else {
+            self
+        }
+    }
+}
+
+impl Add<GF2> for DispatchGF2_128x8 {
+    type Output = DispatchGF2_128x8;
+
+    #[inline(always)]
+    fn add(self, rhs: GF2) -> Self::Output {
+        let rhs_extended = GF2_128::from(rhs);
+        DispatchGF2_128x8 {
+            v: self.v.map(|vv| vv + rhs_extended),
+        }
+    }
+}
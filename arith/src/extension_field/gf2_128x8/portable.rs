@@ -0,0 +1,289 @@
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{
+    field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, GF2x8, PortableGF2_128,
+    SimdField, GF2,
+};
+
+/// PortableGF2_128x8 packs 8 GF2_128 elements, without any SIMD instructions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PortableGF2_128x8 {
+    v: [PortableGF2_128; 8],
+}
+
+field_common!(PortableGF2_128x8);
+
+impl PortableGF2_128x8 {
+    // the bits of a GF2x8, from the most significant one, as the constant terms of the lanes
+    #[inline(always)]
+    fn from_bits(v: u8) -> [PortableGF2_128; 8] {
+        std::array::from_fn(|i| PortableGF2_128 {
+            v: ((v >> (7 - i)) & 1u8) as u128,
+        })
+    }
+}
+
+impl FieldSerde for PortableGF2_128x8 {
+    const SERIALIZED_SIZE: usize = 16 * 8;
+
+    #[inline(always)]
+    fn serialize_into<W: std::io::Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|vv| vv.serialize_into(&mut writer))
+    }
+
+    #[inline(always)]
+    fn deserialize_from<R: std::io::Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut res = Self::zero();
+        for vv in res.v.iter_mut() {
+            *vv = PortableGF2_128::deserialize_from(&mut reader)?;
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: std::io::Read>(mut _reader: R) -> FieldSerdeResult<Self>
+    where
+        Self: Sized,
+    {
+        unimplemented!("We don't have a serialization for gf2_128 in ecc yet.")
+    }
+}
+
+impl Field for PortableGF2_128x8 {
+    const NAME: &'static str = "Portable Galios Field 2^128x8";
+
+    const SIZE: usize = 16 * 8;
+
+    const FIELD_SIZE: usize = 128 * 8; // in bits
+
+    const ZERO: Self = PortableGF2_128x8 {
+        v: [PortableGF2_128::ZERO; 8],
+    };
+
+    const ONE: Self = PortableGF2_128x8 {
+        v: [PortableGF2_128::ONE; 8],
+    };
+
+    const INV_2: Self = PortableGF2_128x8 {
+        v: [PortableGF2_128::ZERO; 8],
+    }; // should not be used
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|vv| vv.is_zero())
+    }
+
+    #[inline(always)]
+    fn random_unsafe(mut rng: impl rand::RngCore) -> Self {
+        PortableGF2_128x8 {
+            v: std::array::from_fn(|_| PortableGF2_128::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl rand::RngCore) -> Self {
+        PortableGF2_128x8 {
+            v: std::array::from_fn(|_| PortableGF2_128::random_bool(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        let p_m2 = !(0u128) - 1;
+        Some(Self::exp(self, p_m2))
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        self * self
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("u32 for GF128 doesn't make sense")
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(_bytes: &[u8; 32]) -> Self {
+        unimplemented!("from_uniform_bytes for GF128 doesn't make sense")
+    }
+}
+
+impl SimdField for PortableGF2_128x8 {
+    type Scalar = PortableGF2_128;
+
+    #[inline(always)]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        PortableGF2_128x8 {
+            v: self.v.map(|vv| vv * challenge),
+        }
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == 8);
+        PortableGF2_128x8 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<PortableGF2_128> for PortableGF2_128x8 {
+    #[inline(always)]
+    fn from(v: PortableGF2_128) -> Self {
+        PortableGF2_128x8 { v: [v; 8] }
+    }
+}
+
+impl Neg for PortableGF2_128x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
+
+impl From<u32> for PortableGF2_128x8 {
+    #[inline(always)]
+    fn from(v: u32) -> Self {
+        PortableGF2_128x8 {
+            v: [PortableGF2_128::from(v); 8],
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &PortableGF2_128x8, b: &PortableGF2_128x8) -> PortableGF2_128x8 {
+    PortableGF2_128x8 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &PortableGF2_128x8, b: &PortableGF2_128x8) -> PortableGF2_128x8 {
+    add_internal(a, b)
+}
+
+#[inline(always)]
+fn mul_internal(a: &PortableGF2_128x8, b: &PortableGF2_128x8) -> PortableGF2_128x8 {
+    PortableGF2_128x8 {
+        v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+    }
+}
+
+impl ExtensionField for PortableGF2_128x8 {
+    const DEGREE: usize = PortableGF2_128::DEGREE;
+
+    const W: u32 = PortableGF2_128::W;
+
+    const X: Self = PortableGF2_128x8 {
+        v: [PortableGF2_128::X; 8],
+    };
+
+    type BaseField = GF2x8;
+
+    #[inline(always)]
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let bits = Self::from_bits(base.v);
+        PortableGF2_128x8 {
+            v: std::array::from_fn(|i| {
+                if bits[i].is_zero() {
+                    PortableGF2_128::ZERO
+                } else {
+                    self.v[i]
+                }
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let bits = Self::from_bits(base.v);
+        PortableGF2_128x8 {
+            v: std::array::from_fn(|i| self.v[i] + bits[i]),
+        }
+    }
+
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        PortableGF2_128x8 {
+            v: self.v.map(|vv| vv.mul_by_x()),
+        }
+    }
+}
+
+impl From<GF2x8> for PortableGF2_128x8 {
+    #[inline(always)]
+    fn from(v: GF2x8) -> Self {
+        PortableGF2_128x8 {
+            v: Self::from_bits(v.v),
+        }
+    }
+}
+
+impl Mul<GF2> for PortableGF2_128x8 {
+    type Output = PortableGF2_128x8;
+
+    #[inline(always)]
+    fn mul(self, rhs: GF2) -> Self::Output {
+        if rhs.is_zero() {
+            Self::zero()
+        } else {
+            self
+        }
+    }
+}
+
+impl Add<GF2> for PortableGF2_128x8 {
+    type Output = PortableGF2_128x8;
+
+    #[inline(always)]
+    fn add(self, rhs: GF2) -> Self::Output {
+        let rhs_extended = PortableGF2_128::from(rhs);
+        PortableGF2_128x8 {
+            v: self.v.map(|vv| vv + rhs_extended),
+        }
+    }
+}
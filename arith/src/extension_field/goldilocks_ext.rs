@@ -0,0 +1,268 @@
+use rand::RngCore;
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{field_common, Field, FieldSerde, FieldSerdeResult, Goldilocks, ScalarField};
+
+use super::ExtensionField;
+
+/// The quadratic extension of Goldilocks, modulo x^2 - 7.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GoldilocksExt2 {
+    pub v: [Goldilocks; 2],
+}
+
+field_common!(GoldilocksExt2);
+
+impl FieldSerde for GoldilocksExt2 {
+    const SERIALIZED_SIZE: usize = (64 / 8) * 2;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v[0].serialize_into(&mut writer)?;
+        self.v[1].serialize_into(&mut writer)
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        Ok(GoldilocksExt2 {
+            v: [
+                Goldilocks::deserialize_from(&mut reader)?,
+                Goldilocks::deserialize_from(&mut reader)?,
+            ],
+        })
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self::from(Goldilocks::try_deserialize_from_ecc_format(
+            reader,
+        )?))
+    }
+}
+
+impl ScalarField for GoldilocksExt2 {}
+
+impl Field for GoldilocksExt2 {
+    const NAME: &'static str = "Goldilocks Extension 2";
+
+    const SIZE: usize = 64 / 8 * 2;
+
+    const FIELD_SIZE: usize = 64 * 2;
+
+    const ZERO: Self = GoldilocksExt2 {
+        v: [Goldilocks::ZERO, Goldilocks::ZERO],
+    };
+
+    const ONE: Self = GoldilocksExt2 {
+        v: [Goldilocks::ONE, Goldilocks::ZERO],
+    };
+
+    const INV_2: GoldilocksExt2 = GoldilocksExt2 {
+        v: [Goldilocks::INV_2, Goldilocks::ZERO],
+    };
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v[0].is_zero() && self.v[1].is_zero()
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        GoldilocksExt2 {
+            v: [
+                Goldilocks::random_unsafe(&mut rng),
+                Goldilocks::random_unsafe(&mut rng),
+            ],
+        }
+    }
+
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        GoldilocksExt2 {
+            v: [Goldilocks::random_bool(&mut rng), Goldilocks::zero()],
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            let b = e & 1;
+            if b == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    // (a0 + a1*x)^-1 = (a0 - a1*x) / (a0^2 - 7*a1^2),
+    // where the norm is non-zero since 7 is not a square
+    fn inv(&self) -> Option<Self> {
+        let norm = self.v[0].square() - self.v[1].square() * Goldilocks::from(7);
+        norm.inv().map(|norm_inv| GoldilocksExt2 {
+            v: [self.v[0] * norm_inv, -self.v[1] * norm_inv],
+        })
+    }
+
+    /// Squaring
+    #[inline(always)]
+    fn square(&self) -> Self {
+        Self {
+            v: square_internal(&self.v),
+        }
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        self.v[0].as_u32_unchecked()
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        let v0 = u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+        let v1 = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+        Self {
+            v: [Goldilocks::from_u64(v0), Goldilocks::from_u64(v1)],
+        }
+    }
+}
+
+impl ExtensionField for GoldilocksExt2 {
+    const DEGREE: usize = 2;
+
+    /// Extension Field
+    const W: u32 = 7;
+
+    const X: Self = GoldilocksExt2 {
+        v: [Goldilocks::ZERO, Goldilocks::ONE],
+    };
+
+    /// Base field for the extension
+    type BaseField = Goldilocks;
+
+    #[inline(always)]
+    /// Multiply the extension field with the base field
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        Self {
+            v: [self.v[0] * base, self.v[1] * base],
+        }
+    }
+
+    #[inline(always)]
+    /// Add the extension field with the base field
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        Self {
+            v: [self.v[0] + base, self.v[1]],
+        }
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: [self.v[1] * Goldilocks::from(7), self.v[0]],
+        }
+    }
+}
+
+impl Add<Goldilocks> for GoldilocksExt2 {
+    type Output = GoldilocksExt2;
+
+    #[inline(always)]
+    fn add(self, rhs: Goldilocks) -> Self::Output {
+        self.add_by_base_field(&rhs)
+    }
+}
+
+impl Mul<Goldilocks> for GoldilocksExt2 {
+    type Output = GoldilocksExt2;
+
+    #[inline(always)]
+    fn mul(self, rhs: Goldilocks) -> Self::Output {
+        self.mul_by_base_field(&rhs)
+    }
+}
+
+impl Neg for GoldilocksExt2 {
+    type Output = GoldilocksExt2;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        GoldilocksExt2 {
+            v: [-self.v[0], -self.v[1]],
+        }
+    }
+}
+
+impl From<u32> for GoldilocksExt2 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        GoldilocksExt2 {
+            v: [Goldilocks::from(x), Goldilocks::zero()],
+        }
+    }
+}
+
+impl From<Goldilocks> for GoldilocksExt2 {
+    #[inline(always)]
+    fn from(x: Goldilocks) -> Self {
+        GoldilocksExt2 {
+            v: [x, Goldilocks::zero()],
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &GoldilocksExt2, b: &GoldilocksExt2) -> GoldilocksExt2 {
+    GoldilocksExt2 {
+        v: [a.v[0] + b.v[0], a.v[1] + b.v[1]],
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &GoldilocksExt2, b: &GoldilocksExt2) -> GoldilocksExt2 {
+    GoldilocksExt2 {
+        v: [a.v[0] - b.v[0], a.v[1] - b.v[1]],
+    }
+}
+
+// polynomial mod (x^2 - 7)
+//
+//   (a0 + a1*x) * (b0 + b1*x) mod (x^2 - 7)
+// = a0*b0 + (a0*b1 + a1*b0)*x + a1*b1*x^2 mod (x^2 - 7)
+// = a0*b0 + 7*a1*b1 + (a0*b1 + a1*b0)*x
+#[inline(always)]
+fn mul_internal(a: &GoldilocksExt2, b: &GoldilocksExt2) -> GoldilocksExt2 {
+    let a = &a.v;
+    let b = &b.v;
+    GoldilocksExt2 {
+        v: [
+            a[0] * b[0] + Goldilocks::from(7) * a[1] * b[1],
+            a[0] * b[1] + a[1] * b[0],
+        ],
+    }
+}
+
+#[inline(always)]
+fn square_internal(a: &[Goldilocks; 2]) -> [Goldilocks; 2] {
+    [
+        a[0].square() + Goldilocks::from(7) * a[1].square(),
+        a[0] * a[1].double(),
+    ]
+}
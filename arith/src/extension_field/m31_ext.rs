@@ -6,7 +6,7 @@ use std::{
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::{field_common, mod_reduce_u32, Field, FieldSerde, FieldSerdeResult, M31};
+use crate::{field_common, mod_reduce_u32, Field, FieldSerde, FieldSerdeResult, ScalarField, M31};
 
 use super::ExtensionField;
 
@@ -52,6 +52,8 @@ impl FieldSerde for M31Ext3 {
     }
 }
 
+impl ScalarField for M31Ext3 {}
+
 impl Field for M31Ext3 {
     const NAME: &'static str = "Mersenne 31 Extension 3";
 
@@ -0,0 +1,294 @@
+use rand::RngCore;
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    field_common, mod_reduce_u32, Field, FieldSerde, FieldSerdeResult, M31Ext3, ScalarField, M31,
+};
+
+use super::ExtensionField;
+
+/// The degree 6 extension of M31, modulo x^6 - 5, for about 186 bits of challenge space.
+/// x^6 - 5 is irreducible since 5 is neither a square nor a cube mod 2^31 - 1.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct M31Ext6 {
+    pub v: [M31; 6],
+}
+
+field_common!(M31Ext6);
+
+impl FieldSerde for M31Ext6 {
+    const SERIALIZED_SIZE: usize = (32 / 8) * 6;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| x.serialize_into(&mut writer))
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut res = Self::zero();
+        for x in res.v.iter_mut() {
+            *x = M31::deserialize_from(&mut reader)?;
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self::from(M31::try_deserialize_from_ecc_format(reader)?))
+    }
+}
+
+impl ScalarField for M31Ext6 {}
+
+impl Field for M31Ext6 {
+    const NAME: &'static str = "Mersenne 31 Extension 6";
+
+    const SIZE: usize = 32 / 8 * 6;
+
+    const FIELD_SIZE: usize = 32 * 6;
+
+    const ZERO: Self = M31Ext6 { v: [M31::ZERO; 6] };
+
+    const ONE: Self = M31Ext6 {
+        v: [
+            M31::ONE,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+        ],
+    };
+
+    const INV_2: M31Ext6 = M31Ext6 {
+        v: [
+            M31::INV_2,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+        ],
+    };
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        M31Ext6 {
+            v: std::array::from_fn(|_| M31::random_unsafe(&mut rng)),
+        }
+    }
+
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        M31Ext6::from(M31::random_bool(&mut rng))
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            let b = e & 1;
+            if b == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    // p^6 does not fit in the u128 exponent, so the inverse goes through M31Ext3.
+    // With u = x^2, u^3 = 5 and M31Ext3 = M31[u] / (u^3 - 5), write a = A + B*x
+    // for A, B in M31Ext3. Then a * (A - B*x) = A^2 - u*B^2 lies in M31Ext3, and
+    // a^-1 = (A - B*x) / (A^2 - u*B^2).
+    fn inv(&self) -> Option<Self> {
+        let a = M31Ext3 {
+            v: [self.v[0], self.v[2], self.v[4]],
+        };
+        let b = M31Ext3 {
+            v: [self.v[1], self.v[3], self.v[5]],
+        };
+        let norm = a.square() - b.square().mul_by_x();
+        norm.inv().map(|norm_inv| {
+            let a_n = a * norm_inv;
+            let b_n = b * norm_inv;
+            M31Ext6 {
+                v: [
+                    a_n.v[0], -b_n.v[0], a_n.v[1], -b_n.v[1], a_n.v[2], -b_n.v[2],
+                ],
+            }
+        })
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        self.v[0].as_u32_unchecked()
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        M31Ext6 {
+            v: std::array::from_fn(|i| M31 {
+                v: mod_reduce_u32(mod_reduce_u32(u32::from_be_bytes(
+                    bytes[i * 4..(i + 1) * 4].try_into().unwrap(),
+                ))),
+            }),
+        }
+    }
+}
+
+impl ExtensionField for M31Ext6 {
+    const DEGREE: usize = 6;
+
+    /// Extension Field
+    const W: u32 = 5;
+
+    const X: Self = M31Ext6 {
+        v: [
+            M31::ZERO,
+            M31::ONE,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+            M31::ZERO,
+        ],
+    };
+
+    /// Base field for the extension
+    type BaseField = M31;
+
+    #[inline(always)]
+    /// Multiply the extension field with the base field
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        Self {
+            v: self.v.map(|x| x * base),
+        }
+    }
+
+    #[inline(always)]
+    /// Add the extension field with the base field
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let mut res = self.v;
+        res[0] += base;
+        Self { v: res }
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: [
+                self.v[5].mul_by_5(),
+                self.v[0],
+                self.v[1],
+                self.v[2],
+                self.v[3],
+                self.v[4],
+            ],
+        }
+    }
+}
+
+impl Add<M31> for M31Ext6 {
+    type Output = M31Ext6;
+
+    #[inline(always)]
+    fn add(self, rhs: M31) -> Self::Output {
+        self.add_by_base_field(&rhs)
+    }
+}
+
+impl Mul<M31> for M31Ext6 {
+    type Output = M31Ext6;
+
+    #[inline(always)]
+    fn mul(self, rhs: M31) -> Self::Output {
+        self.mul_by_base_field(&rhs)
+    }
+}
+
+impl Neg for M31Ext6 {
+    type Output = M31Ext6;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        M31Ext6 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+impl From<u32> for M31Ext6 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        M31Ext6::from(M31::from(x))
+    }
+}
+
+impl From<M31> for M31Ext6 {
+    #[inline(always)]
+    fn from(x: M31) -> Self {
+        let mut res = Self::ZERO;
+        res.v[0] = x;
+        res
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &M31Ext6, b: &M31Ext6) -> M31Ext6 {
+    M31Ext6 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &M31Ext6, b: &M31Ext6) -> M31Ext6 {
+    M31Ext6 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+// polynomial mod (x^6 - 5)
+//
+//   (a0 + a1*x + ... + a5*x^5) * (b0 + b1*x + ... + b5*x^5) mod (x^6 - 5)
+// = sum_{k < 6} (sum_{i + j = k} ai*bj + 5 * sum_{i + j = k + 6} ai*bj) * x^k
+#[inline(always)]
+fn mul_internal(a: &M31Ext6, b: &M31Ext6) -> M31Ext6 {
+    let a = &a.v;
+    let b = &b.v;
+    let mut lo = [M31::ZERO; 6];
+    let mut hi = [M31::ZERO; 5];
+    for (i, ai) in a.iter().enumerate() {
+        for (j, bj) in b.iter().enumerate() {
+            if i + j < 6 {
+                lo[i + j] += *ai * bj;
+            } else {
+                hi[i + j - 6] += *ai * bj;
+            }
+        }
+    }
+    lo.iter_mut().zip(hi).for_each(|(l, h)| *l += h.mul_by_5());
+    M31Ext6 { v: lo }
+}
@@ -0,0 +1,332 @@
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{
+    field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, M31Ext6, M31x16, SimdField,
+    M31,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct M31Ext6x16 {
+    pub v: [M31x16; 6],
+}
+
+field_common!(M31Ext6x16);
+
+impl FieldSerde for M31Ext6x16 {
+    const SERIALIZED_SIZE: usize = (512 / 8) * 6;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| x.serialize_into(&mut writer))
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut res = Self::zero();
+        for x in res.v.iter_mut() {
+            *x = M31x16::deserialize_from(&mut reader)?;
+        }
+        Ok(res)
+    }
+
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Ok(Self::from(M31x16::try_deserialize_from_ecc_format(reader)?))
+    }
+}
+
+impl SimdField for M31Ext6x16 {
+    type Scalar = M31Ext6;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        M31x16::pack_size()
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == Self::pack_size());
+        Self {
+            v: std::array::from_fn(|i| {
+                let vis = base_vec.iter().map(|x| x.v[i]).collect::<Vec<_>>();
+                M31x16::pack(&vis)
+            }),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        let vs = self.v.map(|x| x.unpack());
+
+        (0..Self::pack_size())
+            .map(|j| M31Ext6 {
+                v: std::array::from_fn(|i| vs[i][j]),
+            })
+            .collect()
+    }
+}
+
+impl From<M31x16> for M31Ext6x16 {
+    #[inline(always)]
+    fn from(x: M31x16) -> Self {
+        let mut res = Self::ZERO;
+        res.v[0] = x;
+        res
+    }
+}
+
+impl ExtensionField for M31Ext6x16 {
+    const DEGREE: usize = 6;
+
+    const W: u32 = 5;
+
+    const X: Self = M31Ext6x16 {
+        v: [
+            M31x16::ZERO,
+            M31x16::ONE,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+        ],
+    };
+
+    type BaseField = M31x16;
+
+    #[inline(always)]
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        M31Ext6x16 {
+            v: self.v.map(|x| x * base),
+        }
+    }
+
+    #[inline(always)]
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        let mut res = self.v;
+        res[0] += base;
+        Self { v: res }
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    #[inline(always)]
+    fn mul_by_x(&self) -> Self {
+        Self {
+            v: [
+                self.v[5].mul_by_5(),
+                self.v[0],
+                self.v[1],
+                self.v[2],
+                self.v[3],
+                self.v[4],
+            ],
+        }
+    }
+}
+
+impl From<M31Ext6> for M31Ext6x16 {
+    #[inline(always)]
+    fn from(x: M31Ext6) -> Self {
+        Self {
+            v: x.v.map(M31x16::pack_full),
+        }
+    }
+}
+
+impl Field for M31Ext6x16 {
+    const NAME: &'static str = "Vectorized Mersenne 31 Extension 6";
+
+    const SIZE: usize = 512 / 8 * 6;
+
+    const FIELD_SIZE: usize = 32 * 6;
+
+    const ZERO: Self = Self {
+        v: [M31x16::ZERO; 6],
+    };
+
+    const ONE: Self = Self {
+        v: [
+            M31x16::ONE,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+        ],
+    };
+
+    const INV_2: Self = Self {
+        v: [
+            M31x16::INV_2,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+            M31x16::ZERO,
+        ],
+    };
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    fn random_unsafe(mut rng: impl rand::RngCore) -> Self {
+        M31Ext6x16 {
+            v: std::array::from_fn(|_| M31x16::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl rand::RngCore) -> Self {
+        M31Ext6x16::from(M31x16::random_bool(&mut rng))
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t.square();
+            e >>= 1;
+        }
+        res
+    }
+
+    fn inv(&self) -> Option<Self> {
+        let inverses = self
+            .unpack()
+            .iter()
+            .map(|x| x.inv())
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::pack(&inverses))
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    fn from_uniform_bytes(_bytes: &[u8; 32]) -> Self {
+        unimplemented!("vec m31: cannot convert from 32 bytes")
+    }
+}
+
+impl Mul<M31Ext6> for M31Ext6x16 {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: M31Ext6) -> Self::Output {
+        // polynomial mod (x^6 - 5), see mul_internal
+        let mut lo = [M31x16::ZERO; 6];
+        let mut hi = [M31x16::ZERO; 5];
+        for (i, ai) in self.v.iter().enumerate() {
+            for (j, bj) in rhs.v.iter().enumerate() {
+                if i + j < 6 {
+                    lo[i + j] += *ai * *bj;
+                } else {
+                    hi[i + j - 6] += *ai * *bj;
+                }
+            }
+        }
+        lo.iter_mut().zip(hi).for_each(|(l, h)| *l += h.mul_by_5());
+        Self { v: lo }
+    }
+}
+
+impl Mul<M31> for M31Ext6x16 {
+    type Output = M31Ext6x16;
+    #[inline(always)]
+    fn mul(self, rhs: M31) -> Self::Output {
+        M31Ext6x16 {
+            // M31x16 * M31
+            v: self.v.map(|x| x * rhs),
+        }
+    }
+}
+
+impl Add<M31> for M31Ext6x16 {
+    type Output = M31Ext6x16;
+    #[inline(always)]
+    fn add(self, rhs: M31) -> Self::Output {
+        let mut res = self.v;
+        // M31x16 + M31
+        res[0] = res[0] + rhs;
+        Self { v: res }
+    }
+}
+
+impl Neg for M31Ext6x16 {
+    type Output = M31Ext6x16;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        M31Ext6x16 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+impl From<u32> for M31Ext6x16 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        M31Ext6x16::from(M31x16::from(x))
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &M31Ext6x16, b: &M31Ext6x16) -> M31Ext6x16 {
+    M31Ext6x16 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &M31Ext6x16, b: &M31Ext6x16) -> M31Ext6x16 {
+    M31Ext6x16 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+// polynomial mod (x^6 - 5)
+//
+//   (a0 + a1*x + ... + a5*x^5) * (b0 + b1*x + ... + b5*x^5) mod (x^6 - 5)
+// = sum_{k < 6} (sum_{i + j = k} ai*bj + 5 * sum_{i + j = k + 6} ai*bj) * x^k
+#[inline(always)]
+fn mul_internal(a: &M31Ext6x16, b: &M31Ext6x16) -> M31Ext6x16 {
+    let mut lo = [M31x16::ZERO; 6];
+    let mut hi = [M31x16::ZERO; 5];
+    for (i, ai) in a.v.iter().enumerate() {
+        for (j, bj) in b.v.iter().enumerate() {
+            if i + j < 6 {
+                lo[i + j] += *ai * *bj;
+            } else {
+                hi[i + j - 6] += *ai * *bj;
+            }
+        }
+    }
+    lo.iter_mut().zip(hi).for_each(|(l, h)| *l += h.mul_by_5());
+    M31Ext6x16 { v: lo }
+}
@@ -1,9 +1,13 @@
-mod bn254;
+mod babybear;
+pub(crate) mod bn254;
 mod gf2;
-mod m31;
+mod goldilocks;
+pub(crate) mod m31;
 
+pub use babybear::*;
 pub use bn254::*;
 pub use gf2::*;
+pub use goldilocks::*;
 pub use m31::*;
 use rand::RngCore;
 
@@ -132,6 +136,10 @@ pub trait Field:
     }
 }
 
+/// Fields whose elements are single values rather than packed lanes, so that every nonzero
+/// element has an inverse.
+pub trait ScalarField: Field {}
+
 pub trait FieldForECC: Field + Hash + Eq + PartialOrd + Ord {
     /// Modulus
     fn modulus() -> ethnum::U256;
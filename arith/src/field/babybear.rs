@@ -0,0 +1,227 @@
+mod babybearx16;
+pub use babybearx16::BabyBearx16;
+
+use rand::RngCore;
+
+use crate::{field_common, Field, FieldForECC, FieldSerde, FieldSerdeResult, ScalarField};
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// p = 15 * 2^27 + 1
+pub const BABYBEAR_MOD: u32 = 0x7800_0001;
+
+/// p - 1 = 15 * 2^27, so the multiplicative group has a subgroup of order 2^27
+pub const BABYBEAR_TWO_ADICITY: usize = 27;
+
+/// A primitive 2^27-th root of unity, 31^15 where 31 generates the multiplicative group
+const BABYBEAR_TWO_ADIC_GENERATOR: u32 = 440564289;
+
+#[inline(always)]
+fn mod_reduce_u64(x: u64) -> u32 {
+    (x % BABYBEAR_MOD as u64) as u32
+}
+
+/// The BabyBear field, whose elements are always stored in canonical form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BabyBear {
+    pub v: u32,
+}
+
+field_common!(BabyBear);
+
+impl FieldSerde for BabyBear {
+    const SERIALIZED_SIZE: usize = 32 / 8;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        writer.write_all(self.v.to_le_bytes().as_ref())?;
+        Ok(())
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut u = [0u8; Self::SERIALIZED_SIZE];
+        reader.read_exact(&mut u)?;
+        Ok(BabyBear::from(u32::from_le_bytes(u)))
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut buf = [0u8; 32];
+        reader.read_exact(&mut buf)?;
+        assert!(
+            buf.iter().skip(4).all(|&x| x == 0),
+            "non-zero byte found in witness byte"
+        );
+        Ok(Self::from(u32::from_le_bytes(buf[..4].try_into().unwrap())))
+    }
+}
+
+impl BabyBear {
+    /// A generator of the multiplicative subgroup of order 2^bits, for bits up to 27.
+    #[inline]
+    pub fn two_adic_generator(bits: usize) -> Self {
+        assert!(
+            bits <= BABYBEAR_TWO_ADICITY,
+            "BabyBear has no subgroup of order 2^{}",
+            bits
+        );
+        let mut res = BabyBear {
+            v: BABYBEAR_TWO_ADIC_GENERATOR,
+        };
+        for _ in bits..BABYBEAR_TWO_ADICITY {
+            res = res.square();
+        }
+        res
+    }
+}
+
+impl ScalarField for BabyBear {}
+
+impl Field for BabyBear {
+    const NAME: &'static str = "BabyBear";
+
+    const SIZE: usize = 32 / 8;
+
+    const ZERO: Self = BabyBear { v: 0 };
+
+    const ONE: Self = BabyBear { v: 1 };
+
+    const INV_2: BabyBear = BabyBear { v: 1006632961 };
+
+    const FIELD_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        BabyBear { v: 0 }
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        BabyBear { v: 1 }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v == 0
+    }
+
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        rng.next_u32().into()
+    }
+
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        (rng.next_u32() & 1).into()
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t.square();
+            e >>= 1;
+        }
+        res
+    }
+
+    // From Fermat's little theorem, the inverse of a is a^(p-2).
+    fn inv(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.exp(BABYBEAR_MOD as u128 - 2))
+        }
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        self.v
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        BabyBear::from(u32::from_le_bytes(bytes[..4].try_into().unwrap()))
+    }
+}
+
+impl FieldForECC for BabyBear {
+    fn modulus() -> ethnum::U256 {
+        ethnum::U256::from(BABYBEAR_MOD)
+    }
+    fn from_u256(x: ethnum::U256) -> Self {
+        BabyBear {
+            v: (x % ethnum::U256::from(BABYBEAR_MOD)).as_u32(),
+        }
+    }
+    fn to_u256(&self) -> ethnum::U256 {
+        ethnum::U256::from(self.v)
+    }
+}
+
+impl Neg for BabyBear {
+    type Output = BabyBear;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        BabyBear {
+            v: if self.v == 0 {
+                0
+            } else {
+                BABYBEAR_MOD - self.v
+            },
+        }
+    }
+}
+
+impl From<u32> for BabyBear {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        BabyBear {
+            v: if x < BABYBEAR_MOD {
+                x
+            } else {
+                x % BABYBEAR_MOD
+            },
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &BabyBear, b: &BabyBear) -> BabyBear {
+    // both are below p < 2^31, so the sum does not overflow
+    let sum = a.v + b.v;
+    BabyBear {
+        v: if sum >= BABYBEAR_MOD {
+            sum - BABYBEAR_MOD
+        } else {
+            sum
+        },
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &BabyBear, b: &BabyBear) -> BabyBear {
+    let (diff, borrow) = a.v.overflowing_sub(b.v);
+    BabyBear {
+        v: if borrow {
+            diff.wrapping_add(BABYBEAR_MOD)
+        } else {
+            diff
+        },
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &BabyBear, b: &BabyBear) -> BabyBear {
+    BabyBear {
+        v: mod_reduce_u64(a.v as u64 * b.v as u64),
+    }
+}
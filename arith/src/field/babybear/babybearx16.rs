@@ -0,0 +1,253 @@
+use std::{
+    fmt::Debug,
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::RngCore;
+
+use crate::{field_common, BabyBear, Field, FieldSerde, FieldSerdeResult, SimdField};
+
+const BABYBEAR_PACK_SIZE: usize = 16;
+
+/// BabyBearx16 packs 16 BabyBear elements, 512 bits as M31x16, and operates on them
+/// lane by lane, leaving the vectorization to the compiler.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct BabyBearx16 {
+    pub v: [BabyBear; BABYBEAR_PACK_SIZE],
+}
+
+field_common!(BabyBearx16);
+
+impl BabyBearx16 {
+    #[inline(always)]
+    pub fn pack_full(x: BabyBear) -> BabyBearx16 {
+        BabyBearx16 {
+            v: [x; BABYBEAR_PACK_SIZE],
+        }
+    }
+}
+
+impl FieldSerde for BabyBearx16 {
+    const SERIALIZED_SIZE: usize = 512 / 8;
+
+    #[inline(always)]
+    /// serialize self into bytes
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| writer.write_all(&x.v.to_le_bytes()))?;
+        Ok(())
+    }
+
+    /// deserialize bytes into field
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        reader.read_exact(&mut data)?;
+        let mut res = Self::zero();
+        res.v
+            .iter_mut()
+            .zip(data.chunks_exact(4))
+            .for_each(|(x, bytes)| {
+                *x = BabyBear::from(u32::from_le_bytes(bytes.try_into().unwrap()))
+            });
+        Ok(res)
+    }
+
+    #[inline(always)]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        BabyBear::try_deserialize_from_ecc_format(reader).map(Self::pack_full)
+    }
+}
+
+impl Field for BabyBearx16 {
+    const NAME: &'static str = "Packed BabyBear";
+
+    // size in bytes
+    const SIZE: usize = 512 / 8;
+
+    const ZERO: Self = Self {
+        v: [BabyBear::ZERO; BABYBEAR_PACK_SIZE],
+    };
+
+    const ONE: Self = Self {
+        v: [BabyBear::ONE; BABYBEAR_PACK_SIZE],
+    };
+
+    const INV_2: Self = Self {
+        v: [BabyBear::INV_2; BABYBEAR_PACK_SIZE],
+    };
+
+    const FIELD_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    // this function is for internal testing only. it is not
+    // a source for uniformly random field elements and
+    // should not be used in production.
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        BabyBearx16 {
+            v: std::array::from_fn(|_| BabyBear::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        BabyBearx16 {
+            v: std::array::from_fn(|_| BabyBear::random_bool(&mut rng)),
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        BabyBearx16 {
+            v: self.v.map(|x| x.exp(exponent)),
+        }
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.v.iter().any(|x| x.is_zero()) {
+            return None;
+        }
+        Some(BabyBearx16 {
+            v: self.v.map(|x| x.inv().unwrap()), // safe unwrap
+        })
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Self::pack_full(BabyBear::from_uniform_bytes(bytes))
+    }
+}
+
+impl SimdField for BabyBearx16 {
+    type Scalar = BabyBear;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        BABYBEAR_PACK_SIZE
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == BABYBEAR_PACK_SIZE);
+        BabyBearx16 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<BabyBear> for BabyBearx16 {
+    #[inline(always)]
+    fn from(x: BabyBear) -> Self {
+        BabyBearx16::pack_full(x)
+    }
+}
+
+impl Debug for BabyBearx16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data = self.v.map(|x| x.v);
+        // if all data is the same, print only one
+        if data.iter().all(|x| *x == data[0]) {
+            write!(f, "[BabyBear; 16]<16 x {}>", data[0])
+        } else {
+            write!(f, "[BabyBear; 16]<{:?}>", data)
+        }
+    }
+}
+
+impl Mul<&BabyBear> for BabyBearx16 {
+    type Output = BabyBearx16;
+    #[inline(always)]
+    fn mul(self, rhs: &BabyBear) -> Self::Output {
+        BabyBearx16 {
+            v: self.v.map(|x| x * rhs),
+        }
+    }
+}
+
+impl Mul<BabyBear> for BabyBearx16 {
+    type Output = BabyBearx16;
+    #[inline(always)]
+    fn mul(self, rhs: BabyBear) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Add<BabyBear> for BabyBearx16 {
+    type Output = BabyBearx16;
+    #[inline(always)]
+    fn add(self, rhs: BabyBear) -> Self::Output {
+        BabyBearx16 {
+            v: self.v.map(|x| x + rhs),
+        }
+    }
+}
+
+impl From<u32> for BabyBearx16 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        BabyBearx16::pack_full(BabyBear::from(x))
+    }
+}
+
+impl Neg for BabyBearx16 {
+    type Output = BabyBearx16;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        BabyBearx16 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &BabyBearx16, b: &BabyBearx16) -> BabyBearx16 {
+    BabyBearx16 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &BabyBearx16, b: &BabyBearx16) -> BabyBearx16 {
+    BabyBearx16 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &BabyBearx16, b: &BabyBearx16) -> BabyBearx16 {
+    BabyBearx16 {
+        v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+    }
+}
@@ -1,3 +1,6 @@
+pub(crate) mod frx8;
+pub use frx8::Frx8;
+
 use std::io::{Read, Write};
 
 use halo2curves::ff::{Field as Halo2Field, FromUniformBytes};
@@ -5,7 +8,7 @@ use halo2curves::{bn256::Fr, ff::PrimeField};
 use rand::RngCore;
 
 use crate::serde::{FieldSerdeError, FieldSerdeResult};
-use crate::{Field, FieldForECC, FieldSerde, SimdField};
+use crate::{Field, FieldForECC, FieldSerde, ScalarField, SimdField};
 
 const MODULUS: ethnum::U256 = ethnum::U256([
     0x2833e84879b9709143e1f593f0000001,
@@ -14,6 +17,8 @@ const MODULUS: ethnum::U256 = ethnum::U256([
 
 pub use halo2curves::bn256::Fr as BN254;
 
+impl ScalarField for Fr {}
+
 impl Field for Fr {
     /// name
     const NAME: &'static str = "bn254 scalar field";
@@ -0,0 +1,537 @@
+use std::{
+    fmt::Debug,
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use halo2curves::bn256::Fr;
+use rand::RngCore;
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+use crate::{bn254_backend, SimdBackend};
+use crate::{field_common, ExtensionField, Field, FieldSerde, FieldSerdeResult, SimdField};
+
+const FR_PACK_SIZE: usize = 8;
+
+/// Frx8 packs 8 BN254 scalar field elements.
+/// The multiplication runs the AVX-512 or AVX2 Montgomery kernels depending on the features
+/// of the host CPU, and the other operations go lane by lane through `Fr`.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Frx8 {
+    pub v: [Fr; FR_PACK_SIZE],
+}
+
+field_common!(Frx8);
+
+// The kernels hold the elements in Montgomery form, with R = 2^256 as `Fr`,
+// split into 8 limbs of 32 bits, one per 64-bit lane, so that the products of
+// two limbs and the carries fit in the lanes.
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod montgomery {
+    use std::mem::transmute;
+
+    use halo2curves::bn256::Fr;
+
+    pub(super) const NUM_LIMBS: usize = 8;
+
+    /// The modulus, in 32-bit limbs from the least significant.
+    const MODULUS: [u64; NUM_LIMBS] = [
+        0xf0000001, 0x43e1f593, 0x79b97091, 0x2833e848, 0x8181585d, 0xb85045b6, 0xe131a029,
+        0x30644e72,
+    ];
+
+    /// -p^-1 mod 2^32
+    const INV: u64 = 0xefffffff;
+
+    /// Operations on the 64-bit lanes of a vector register.
+    pub(super) trait Lanes: Copy {
+        unsafe fn splat(x: u64) -> Self;
+        unsafe fn add(self, b: Self) -> Self;
+        unsafe fn sub(self, b: Self) -> Self;
+        /// Product of the low 32 bits of the lanes.
+        unsafe fn mul_lo(self, b: Self) -> Self;
+        unsafe fn lo32(self) -> Self;
+        unsafe fn hi32(self) -> Self;
+        unsafe fn msb(self) -> Self;
+        /// The lanes of `a` where `self` is non-zero, of `b` elsewhere.
+        unsafe fn select(self, a: Self, b: Self) -> Self;
+    }
+
+    // `Fr` is a single array of 4 u64 limbs in Montgomery form. halo2curves does not promise
+    // this layout: the size is checked here, and the limbs are pinned by a test against
+    // the canonical representation of `Fr`.
+    const _: () = assert!(
+        std::mem::size_of::<Fr>() == std::mem::size_of::<[u64; 4]>()
+            && std::mem::align_of::<Fr>() == std::mem::align_of::<[u64; 4]>()
+    );
+
+    #[inline(always)]
+    pub(crate) fn to_limbs<const L: usize>(x: &[Fr; L]) -> [[u64; L]; NUM_LIMBS] {
+        let x: [[u64; 4]; L] = x.map(|e| unsafe { transmute::<Fr, [u64; 4]>(e) });
+        std::array::from_fn(|j| {
+            std::array::from_fn(|i| (x[i][j / 2] >> (32 * (j % 2))) & 0xffffffff)
+        })
+    }
+
+    #[inline(always)]
+    pub(crate) fn from_limbs<const L: usize>(x: &[[u64; L]; NUM_LIMBS]) -> [Fr; L] {
+        std::array::from_fn(|i| {
+            let limbs: [u64; 4] = std::array::from_fn(|k| x[2 * k][i] | (x[2 * k + 1][i] << 32));
+            unsafe { transmute::<[u64; 4], Fr>(limbs) }
+        })
+    }
+
+    /// a * b * 2^-256 mod p, for a, b < p, with the CIOS method.
+    #[inline(always)]
+    #[allow(clippy::needless_range_loop)]
+    pub(super) unsafe fn mul<V: Lanes>(a: &[V; NUM_LIMBS], b: &[V; NUM_LIMBS]) -> [V; NUM_LIMBS] {
+        let p: [V; NUM_LIMBS] = MODULUS.map(|x| V::splat(x));
+        let inv = V::splat(INV);
+
+        let mut t = [V::splat(0); NUM_LIMBS + 1];
+        for i in 0..NUM_LIMBS {
+            // t += a * b[i]
+            let mut carry = V::splat(0);
+            for j in 0..NUM_LIMBS {
+                let s = t[j].add(a[j].mul_lo(b[i])).add(carry);
+                t[j] = s.lo32();
+                carry = s.hi32();
+            }
+            t[NUM_LIMBS] = t[NUM_LIMBS].add(carry);
+
+            // t = (t + m * p) / 2^32, where m zeroes the lowest limb
+            let m = t[0].mul_lo(inv).lo32();
+            let mut carry = t[0].add(m.mul_lo(p[0])).hi32();
+            for j in 1..NUM_LIMBS {
+                let s = t[j].add(m.mul_lo(p[j])).add(carry);
+                t[j - 1] = s.lo32();
+                carry = s.hi32();
+            }
+            let s = t[NUM_LIMBS].add(carry);
+            t[NUM_LIMBS - 1] = s.lo32();
+            t[NUM_LIMBS] = s.hi32();
+        }
+
+        // t < 2p, subtract p unless it borrows
+        let mut d = [V::splat(0); NUM_LIMBS];
+        let mut borrow = V::splat(0);
+        for j in 0..NUM_LIMBS {
+            let s = t[j].sub(p[j]).sub(borrow);
+            d[j] = s.lo32();
+            borrow = s.msb();
+        }
+        std::array::from_fn(|j| borrow.select(t[j], d[j]))
+    }
+}
+
+// The kernels are compiled for their instruction sets, whatever the target CPU of the crate,
+// and must only be called once the CPU is known to support them.
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod avx512 {
+    use std::{arch::x86_64::*, mem::transmute};
+
+    use super::{
+        montgomery::{self, from_limbs, to_limbs, Lanes},
+        Frx8,
+    };
+
+    impl Lanes for __m512i {
+        #[inline(always)]
+        unsafe fn splat(x: u64) -> Self {
+            _mm512_set1_epi64(x as i64)
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, b: Self) -> Self {
+            _mm512_add_epi64(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, b: Self) -> Self {
+            _mm512_sub_epi64(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn mul_lo(self, b: Self) -> Self {
+            _mm512_mul_epu32(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn lo32(self) -> Self {
+            _mm512_and_si512(self, _mm512_set1_epi64(0xffffffff))
+        }
+
+        #[inline(always)]
+        unsafe fn hi32(self) -> Self {
+            _mm512_srli_epi64::<32>(self)
+        }
+
+        #[inline(always)]
+        unsafe fn msb(self) -> Self {
+            _mm512_srli_epi64::<63>(self)
+        }
+
+        #[inline(always)]
+        unsafe fn select(self, a: Self, b: Self) -> Self {
+            let is_zero = _mm512_cmpeq_epi64_mask(self, _mm512_setzero_si512());
+            _mm512_mask_blend_epi64(is_zero, a, b)
+        }
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(crate) unsafe fn mul(a: &Frx8, b: &Frx8) -> Frx8 {
+        let a: [__m512i; 8] = transmute(to_limbs(&a.v));
+        let b: [__m512i; 8] = transmute(to_limbs(&b.v));
+        Frx8 {
+            v: from_limbs::<8>(&transmute(montgomery::mul(&a, &b))),
+        }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod avx2 {
+    use std::{arch::x86_64::*, mem::transmute};
+
+    use super::{
+        montgomery::{self, from_limbs, to_limbs, Lanes},
+        Frx8,
+    };
+
+    impl Lanes for __m256i {
+        #[inline(always)]
+        unsafe fn splat(x: u64) -> Self {
+            _mm256_set1_epi64x(x as i64)
+        }
+
+        #[inline(always)]
+        unsafe fn add(self, b: Self) -> Self {
+            _mm256_add_epi64(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn sub(self, b: Self) -> Self {
+            _mm256_sub_epi64(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn mul_lo(self, b: Self) -> Self {
+            _mm256_mul_epu32(self, b)
+        }
+
+        #[inline(always)]
+        unsafe fn lo32(self) -> Self {
+            _mm256_and_si256(self, _mm256_set1_epi64x(0xffffffff))
+        }
+
+        #[inline(always)]
+        unsafe fn hi32(self) -> Self {
+            _mm256_srli_epi64::<32>(self)
+        }
+
+        #[inline(always)]
+        unsafe fn msb(self) -> Self {
+            _mm256_srli_epi64::<63>(self)
+        }
+
+        #[inline(always)]
+        unsafe fn select(self, a: Self, b: Self) -> Self {
+            let is_zero = _mm256_cmpeq_epi64(self, _mm256_setzero_si256());
+            _mm256_blendv_epi8(a, b, is_zero)
+        }
+    }
+
+    // two halves of 4 lanes
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn mul(a: &Frx8, b: &Frx8) -> Frx8 {
+        let mut res = Frx8::default();
+        for ((r, x), y) in res
+            .v
+            .chunks_exact_mut(4)
+            .zip(a.v.chunks_exact(4))
+            .zip(b.v.chunks_exact(4))
+        {
+            let x: [__m256i; 8] = transmute(to_limbs::<4>(x.try_into().unwrap()));
+            let y: [__m256i; 8] = transmute(to_limbs::<4>(y.try_into().unwrap()));
+            r.copy_from_slice(&from_limbs::<4>(&transmute(montgomery::mul(&x, &y))));
+        }
+        res
+    }
+}
+
+impl Frx8 {
+    #[inline(always)]
+    pub fn pack_full(x: Fr) -> Frx8 {
+        Frx8 {
+            v: [x; FR_PACK_SIZE],
+        }
+    }
+}
+
+impl FieldSerde for Frx8 {
+    const SERIALIZED_SIZE: usize = 32 * FR_PACK_SIZE;
+
+    #[inline(always)]
+    /// serialize self into bytes
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| x.serialize_into(&mut writer))
+    }
+
+    /// deserialize bytes into field
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut res = Self::zero();
+        for x in res.v.iter_mut() {
+            *x = Fr::deserialize_from(&mut reader)?;
+        }
+        Ok(res)
+    }
+
+    #[inline(always)]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Fr::try_deserialize_from_ecc_format(reader).map(Self::pack_full)
+    }
+}
+
+impl Field for Frx8 {
+    const NAME: &'static str = "Packed bn254 scalar field";
+
+    // size in bytes
+    const SIZE: usize = 32 * FR_PACK_SIZE;
+
+    const ZERO: Self = Self {
+        v: [<Fr as Field>::ZERO; FR_PACK_SIZE],
+    };
+
+    const ONE: Self = Self {
+        v: [<Fr as Field>::ONE; FR_PACK_SIZE],
+    };
+
+    const INV_2: Self = Self {
+        v: [<Fr as Field>::INV_2; FR_PACK_SIZE],
+    };
+
+    const FIELD_SIZE: usize = 256;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| *x == <Fr as Field>::ZERO)
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    // this function is for internal testing only. it is not
+    // a source for uniformly random field elements and
+    // should not be used in production.
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        Frx8 {
+            v: std::array::from_fn(|_| <Fr as Field>::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        Frx8 {
+            v: std::array::from_fn(|_| <Fr as Field>::random_bool(&mut rng)),
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        let inverses = self
+            .v
+            .iter()
+            .map(<Fr as Field>::inv)
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::pack(&inverses))
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Self::pack_full(<Fr as Field>::from_uniform_bytes(bytes))
+    }
+}
+
+impl SimdField for Frx8 {
+    type Scalar = Fr;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        FR_PACK_SIZE
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == FR_PACK_SIZE);
+        Frx8 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl ExtensionField for Frx8 {
+    const DEGREE: usize = 1;
+
+    /// Extension Field over X-1 which is self
+    const W: u32 = 1;
+
+    // placeholder, doesn't make sense for Frx8
+    const X: Self = Frx8::ZERO;
+
+    /// Base field for the extension
+    type BaseField = Self;
+
+    /// Multiply the extension field with the base field
+    fn mul_by_base_field(&self, base: &Self::BaseField) -> Self {
+        *self * base
+    }
+
+    /// Add the extension field with the base field
+    fn add_by_base_field(&self, base: &Self::BaseField) -> Self {
+        *self + base
+    }
+
+    /// Multiply the extension field by x, i.e, 0 + x + 0 x^2 + 0 x^3 + ...
+    fn mul_by_x(&self) -> Self {
+        unimplemented!("mul_by_x for Frx8 doesn't make sense")
+    }
+}
+
+impl From<Fr> for Frx8 {
+    #[inline(always)]
+    fn from(x: Fr) -> Self {
+        Frx8::pack_full(x)
+    }
+}
+
+impl Debug for Frx8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // if all data is the same, print only one
+        if self.v.iter().all(|x| *x == self.v[0]) {
+            write!(f, "[Fr; 8]<8 x {:?}>", self.v[0])
+        } else {
+            write!(f, "[Fr; 8]<{:?}>", self.v)
+        }
+    }
+}
+
+impl Mul<&Fr> for Frx8 {
+    type Output = Frx8;
+    #[inline(always)]
+    fn mul(self, rhs: &Fr) -> Self::Output {
+        self * Frx8::pack_full(*rhs)
+    }
+}
+
+impl Mul<Fr> for Frx8 {
+    type Output = Frx8;
+    #[inline(always)]
+    fn mul(self, rhs: Fr) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Add<Fr> for Frx8 {
+    type Output = Frx8;
+    #[inline(always)]
+    fn add(self, rhs: Fr) -> Self::Output {
+        Frx8 {
+            v: self.v.map(|x| x + rhs),
+        }
+    }
+}
+
+impl From<u32> for Frx8 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        Frx8::pack_full(Fr::from(x as u64))
+    }
+}
+
+impl Neg for Frx8 {
+    type Output = Frx8;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Frx8 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &Frx8, b: &Frx8) -> Frx8 {
+    Frx8 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &Frx8, b: &Frx8) -> Frx8 {
+    Frx8 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn mul_lanes(a: &Frx8, b: &Frx8) -> Frx8 {
+    Frx8 {
+        v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &Frx8, b: &Frx8) -> Frx8 {
+    cfg_if::cfg_if! {
+        if #[cfg(all(target_arch = "x86_64", not(feature = "portable")))] {
+            // safety: the backend is only selected when the CPU supports it
+            unsafe {
+                match bn254_backend() {
+                    SimdBackend::Avx512 => avx512::mul(a, b),
+                    SimdBackend::Avx2 => avx2::mul(a, b),
+                    _ => mul_lanes(a, b),
+                }
+            }
+        } else {
+            mul_lanes(a, b)
+        }
+    }
+}
@@ -12,7 +12,7 @@ pub use gf2x8::GF2x8;
 
 use crate::{field_common, FieldSerde, FieldSerdeResult};
 
-use super::{Field, FieldForECC};
+use super::{Field, FieldForECC, ScalarField};
 
 pub const MOD: u32 = 2;
 
@@ -52,6 +52,8 @@ impl FieldSerde for GF2 {
     }
 }
 
+impl ScalarField for GF2 {}
+
 impl Field for GF2 {
     // still will pack 8 bits into a u8
 
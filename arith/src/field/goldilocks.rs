@@ -0,0 +1,268 @@
+mod goldilocksx8;
+pub use goldilocksx8::Goldilocksx8;
+
+use rand::RngCore;
+
+use crate::{field_common, Field, FieldForECC, FieldSerde, FieldSerdeResult, ScalarField};
+use std::{
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// p = 2^64 - 2^32 + 1
+pub const GOLDILOCKS_MOD: u64 = 0xFFFF_FFFF_0000_0001;
+
+/// 2^64 mod p = 2^32 - 1
+const EPSILON: u64 = 0xFFFF_FFFF;
+
+#[inline(always)]
+fn mod_reduce_u64(x: u64) -> u64 {
+    if x >= GOLDILOCKS_MOD {
+        x - GOLDILOCKS_MOD
+    } else {
+        x
+    }
+}
+
+/// Reduce a 128 bits integer with 2^64 = 2^32 - 1 and 2^96 = -1 mod p.
+/// credit: https://github.com/0xPolygonZero/plonky2/blob/main/field/src/goldilocks_field.rs
+#[inline(always)]
+fn mod_reduce_u128(x: u128) -> u64 {
+    let x_lo = x as u64;
+    let x_hi = (x >> 64) as u64;
+    let x_hi_hi = x_hi >> 32;
+    let x_hi_lo = x_hi & EPSILON;
+
+    // x_lo - x_hi_hi, with the borrow of 2^64 replaced by 2^32 - 1
+    let (mut t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+    if borrow {
+        t0 -= EPSILON;
+    }
+    // x_hi_lo * (2^32 - 1) fits in 64 bits
+    let t1 = x_hi_lo * EPSILON;
+
+    // t0 + t1, with the carry of 2^64 replaced by 2^32 - 1; this cannot overflow again
+    let (t2, carry) = t0.overflowing_add(t1);
+    mod_reduce_u64(t2 + EPSILON * carry as u64)
+}
+
+/// The Goldilocks field, whose elements are always stored in canonical form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Goldilocks {
+    pub v: u64,
+}
+
+field_common!(Goldilocks);
+
+impl FieldSerde for Goldilocks {
+    const SERIALIZED_SIZE: usize = 64 / 8;
+
+    #[inline(always)]
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        writer.write_all(self.v.to_le_bytes().as_ref())?;
+        Ok(())
+    }
+
+    // FIXME: this deserialization function auto corrects invalid inputs.
+    // We should use separate APIs for this and for the actual deserialization.
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut u = [0u8; Self::SERIALIZED_SIZE];
+        reader.read_exact(&mut u)?;
+        Ok(Goldilocks::from_u64(u64::from_le_bytes(u)))
+    }
+
+    #[inline]
+    fn try_deserialize_from_ecc_format<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut buf = [0u8; 32];
+        reader.read_exact(&mut buf)?;
+        assert!(
+            buf.iter().skip(8).all(|&x| x == 0),
+            "non-zero byte found in witness byte"
+        );
+        Ok(Goldilocks::from_u64(u64::from_le_bytes(
+            buf[..8].try_into().unwrap(),
+        )))
+    }
+}
+
+impl Goldilocks {
+    /// The element congruent to x
+    #[inline(always)]
+    pub fn from_u64(x: u64) -> Self {
+        Goldilocks {
+            v: mod_reduce_u64(x),
+        }
+    }
+
+    #[inline(always)]
+    fn exp_power_of_2(&self, power_log: usize) -> Self {
+        let mut res = *self;
+        for _ in 0..power_log {
+            res = res.square();
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn try_inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // From Fermat's little theorem, in a prime field `F_p`, the inverse of `a` is `a^(p-2)`.
+        // Here p-2 = 2^64 - 2^32 - 1, i.e., 31 ones, a zero and 32 ones in binary,
+        // and a^(p-2) = (a^(2^31 - 1))^(2^33) * a^(2^32 - 1).
+        // Uses 64 Squares + 9 Multiplications => 73 Operations total.
+
+        let p1 = *self;
+        let p11 = p1.square() * p1;
+        let p111 = p11.square() * p1;
+        let p111111 = p111.exp_power_of_2(3) * p111;
+        let p1_12 = p111111.exp_power_of_2(6) * p111111;
+        let p1_24 = p1_12.exp_power_of_2(12) * p1_12;
+        let p1_30 = p1_24.exp_power_of_2(6) * p111111;
+        let p1_31 = p1_30.square() * p1;
+        let p1_32 = p1_31.square() * p1;
+        Some(p1_31.exp_power_of_2(33) * p1_32)
+    }
+}
+
+impl ScalarField for Goldilocks {}
+
+impl Field for Goldilocks {
+    const NAME: &'static str = "Goldilocks";
+
+    const SIZE: usize = 64 / 8;
+
+    const ZERO: Self = Goldilocks { v: 0 };
+
+    const ONE: Self = Goldilocks { v: 1 };
+
+    const INV_2: Goldilocks = Goldilocks {
+        v: 0x7FFF_FFFF_8000_0001,
+    };
+
+    const FIELD_SIZE: usize = 64;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Goldilocks { v: 0 }
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Goldilocks { v: 1 }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v == 0
+    }
+
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        Goldilocks::from_u64(rng.next_u64())
+    }
+
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        (rng.next_u32() & 1).into()
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e != 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t.square();
+            e >>= 1;
+        }
+        res
+    }
+
+    fn inv(&self) -> Option<Self> {
+        self.try_inverse()
+    }
+
+    #[inline(always)]
+    fn as_u32_unchecked(&self) -> u32 {
+        self.v as u32
+    }
+
+    #[inline(always)]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Goldilocks::from_u64(u64::from_le_bytes(bytes[..8].try_into().unwrap()))
+    }
+}
+
+impl FieldForECC for Goldilocks {
+    fn modulus() -> ethnum::U256 {
+        ethnum::U256::from(GOLDILOCKS_MOD)
+    }
+    fn from_u256(x: ethnum::U256) -> Self {
+        Goldilocks {
+            v: (x % ethnum::U256::from(GOLDILOCKS_MOD)).as_u64(),
+        }
+    }
+    fn to_u256(&self) -> ethnum::U256 {
+        ethnum::U256::from(self.v)
+    }
+}
+
+impl Neg for Goldilocks {
+    type Output = Goldilocks;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Goldilocks {
+            v: if self.v == 0 {
+                0
+            } else {
+                GOLDILOCKS_MOD - self.v
+            },
+        }
+    }
+}
+
+impl From<u32> for Goldilocks {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        Goldilocks { v: x as u64 }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &Goldilocks, b: &Goldilocks) -> Goldilocks {
+    // both are below p, so one subtraction of p is enough
+    let (sum, over) = a.v.overflowing_add(b.v);
+    Goldilocks {
+        v: if over {
+            // sum + 2^64 - p = sum + 2^32 - 1, which does not overflow
+            sum + EPSILON
+        } else {
+            mod_reduce_u64(sum)
+        },
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &Goldilocks, b: &Goldilocks) -> Goldilocks {
+    let (diff, borrow) = a.v.overflowing_sub(b.v);
+    Goldilocks {
+        v: if borrow {
+            // diff - 2^64 + p = diff - (2^32 - 1), which does not underflow
+            diff - EPSILON
+        } else {
+            diff
+        },
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &Goldilocks, b: &Goldilocks) -> Goldilocks {
+    Goldilocks {
+        v: mod_reduce_u128(a.v as u128 * b.v as u128),
+    }
+}
@@ -0,0 +1,253 @@
+use std::{
+    fmt::Debug,
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::RngCore;
+
+use crate::{field_common, Field, FieldSerde, FieldSerdeResult, Goldilocks, SimdField};
+
+const GOLDILOCKS_PACK_SIZE: usize = 8;
+
+/// Goldilocksx8 packs 8 Goldilocks elements, 512 bits as M31x16, and operates on them
+/// lane by lane, leaving the vectorization to the compiler.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct Goldilocksx8 {
+    pub v: [Goldilocks; GOLDILOCKS_PACK_SIZE],
+}
+
+field_common!(Goldilocksx8);
+
+impl Goldilocksx8 {
+    #[inline(always)]
+    pub fn pack_full(x: Goldilocks) -> Goldilocksx8 {
+        Goldilocksx8 {
+            v: [x; GOLDILOCKS_PACK_SIZE],
+        }
+    }
+}
+
+impl FieldSerde for Goldilocksx8 {
+    const SERIALIZED_SIZE: usize = 512 / 8;
+
+    #[inline(always)]
+    /// serialize self into bytes
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| writer.write_all(&x.v.to_le_bytes()))?;
+        Ok(())
+    }
+
+    /// deserialize bytes into field
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        reader.read_exact(&mut data)?;
+        let mut res = Self::zero();
+        res.v
+            .iter_mut()
+            .zip(data.chunks_exact(8))
+            .for_each(|(x, bytes)| {
+                *x = Goldilocks::from_u64(u64::from_le_bytes(bytes.try_into().unwrap()))
+            });
+        Ok(res)
+    }
+
+    #[inline(always)]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        Goldilocks::try_deserialize_from_ecc_format(reader).map(Self::pack_full)
+    }
+}
+
+impl Field for Goldilocksx8 {
+    const NAME: &'static str = "Packed Goldilocks";
+
+    // size in bytes
+    const SIZE: usize = 512 / 8;
+
+    const ZERO: Self = Self {
+        v: [Goldilocks::ZERO; GOLDILOCKS_PACK_SIZE],
+    };
+
+    const ONE: Self = Self {
+        v: [Goldilocks::ONE; GOLDILOCKS_PACK_SIZE],
+    };
+
+    const INV_2: Self = Self {
+        v: [Goldilocks::INV_2; GOLDILOCKS_PACK_SIZE],
+    };
+
+    const FIELD_SIZE: usize = 64;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    // this function is for internal testing only. it is not
+    // a source for uniformly random field elements and
+    // should not be used in production.
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        Goldilocksx8 {
+            v: std::array::from_fn(|_| Goldilocks::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        Goldilocksx8 {
+            v: std::array::from_fn(|_| Goldilocks::random_bool(&mut rng)),
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        Goldilocksx8 {
+            v: self.v.map(|x| x.exp(exponent)),
+        }
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.v.iter().any(|x| x.is_zero()) {
+            return None;
+        }
+        Some(Goldilocksx8 {
+            v: self.v.map(|x| x.inv().unwrap()), // safe unwrap
+        })
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Self::pack_full(Goldilocks::from_uniform_bytes(bytes))
+    }
+}
+
+impl SimdField for Goldilocksx8 {
+    type Scalar = Goldilocks;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        GOLDILOCKS_PACK_SIZE
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == GOLDILOCKS_PACK_SIZE);
+        Goldilocksx8 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<Goldilocks> for Goldilocksx8 {
+    #[inline(always)]
+    fn from(x: Goldilocks) -> Self {
+        Goldilocksx8::pack_full(x)
+    }
+}
+
+impl Debug for Goldilocksx8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data = self.v.map(|x| x.v);
+        // if all data is the same, print only one
+        if data.iter().all(|x| *x == data[0]) {
+            write!(f, "[Goldilocks; 8]<8 x {}>", data[0])
+        } else {
+            write!(f, "[Goldilocks; 8]<{:?}>", data)
+        }
+    }
+}
+
+impl Mul<&Goldilocks> for Goldilocksx8 {
+    type Output = Goldilocksx8;
+    #[inline(always)]
+    fn mul(self, rhs: &Goldilocks) -> Self::Output {
+        Goldilocksx8 {
+            v: self.v.map(|x| x * rhs),
+        }
+    }
+}
+
+impl Mul<Goldilocks> for Goldilocksx8 {
+    type Output = Goldilocksx8;
+    #[inline(always)]
+    fn mul(self, rhs: Goldilocks) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Add<Goldilocks> for Goldilocksx8 {
+    type Output = Goldilocksx8;
+    #[inline(always)]
+    fn add(self, rhs: Goldilocks) -> Self::Output {
+        Goldilocksx8 {
+            v: self.v.map(|x| x + rhs),
+        }
+    }
+}
+
+impl From<u32> for Goldilocksx8 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        Goldilocksx8::pack_full(Goldilocks::from(x))
+    }
+}
+
+impl Neg for Goldilocksx8 {
+    type Output = Goldilocksx8;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Goldilocksx8 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &Goldilocksx8, b: &Goldilocksx8) -> Goldilocksx8 {
+    Goldilocksx8 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &Goldilocksx8, b: &Goldilocksx8) -> Goldilocksx8 {
+    Goldilocksx8 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &Goldilocksx8, b: &Goldilocksx8) -> Goldilocksx8 {
+    Goldilocksx8 {
+        v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+    }
+}
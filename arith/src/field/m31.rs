@@ -1,20 +1,25 @@
 mod m31x16;
 pub use m31x16::M31x16;
 
-#[cfg(target_arch = "x86_64")]
+mod m31_portable;
+pub use m31_portable::PortableM31x16;
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub(crate) mod m31_avx;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub(crate) mod m31_avx256;
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod m31_dispatch;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 pub type M31x16_256 = m31_avx256::AVXM31;
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 pub mod m31_neon;
 
 use rand::RngCore;
 
-use crate::{field_common, Field, FieldForECC, FieldSerde, FieldSerdeResult};
+use crate::{field_common, Field, FieldForECC, FieldSerde, FieldSerdeResult, ScalarField};
 use std::{
     io::{Read, Write},
     iter::{Product, Sum},
@@ -88,6 +93,8 @@ impl M31 {
     }
 }
 
+impl ScalarField for M31 {}
+
 impl Field for M31 {
     const NAME: &'static str = "Mersenne 31";
 
@@ -298,7 +298,7 @@ impl PartialEq for AVXM31 {
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn mask_movehdup_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
     // The instruction is only available in the floating-point flavor; this distinction is only for
@@ -310,7 +310,7 @@ fn mask_movehdup_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn mask_moveldup_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
     // The instruction is only available in the floating-point flavor; this distinction is only for
@@ -322,7 +322,7 @@ fn mask_moveldup_epi32(src: __m512i, k: __mmask16, a: __m512i) -> __m512i {
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn add(lhs: __m512i, rhs: __m512i) -> __m512i {
     unsafe {
@@ -396,7 +396,7 @@ impl Neg for AVXM31 {
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn movehdup_epi32(x: __m512i) -> __m512i {
     // The instruction is only available in the floating-point flavor; this distinction is only for
@@ -426,7 +426,7 @@ fn sub_internal(a: &AVXM31, b: &AVXM31) -> AVXM31 {
     }
 }
 
-#[inline]
+#[inline(always)]
 fn mul_internal(a: &AVXM31, b: &AVXM31) -> AVXM31 {
     // credit: https://github.com/Plonky3/Plonky3/blob/eeb4e37b20127c4daa871b2bad0df30a7c7380db/mersenne-31/src/x86_64_avx2/packing.rs#L154
     unsafe {
@@ -110,11 +110,17 @@ impl Field for AVXM31 {
     fn is_zero(&self) -> bool {
         // value is either zero or 0x7FFFFFFF
         unsafe {
-            let pcmp = _mm256_cmpeq_epi32_mask(self.v[0], PACKED_0)
-                & _mm256_cmpeq_epi32_mask(self.v[1], PACKED_0);
-            let pcmp2 = _mm256_cmpeq_epi32_mask(self.v[0], PACKED_MOD)
-                & _mm256_cmpeq_epi32_mask(self.v[1], PACKED_MOD);
-            (pcmp | pcmp2) == 0xFF
+            let pcmp = [
+                _mm256_or_si256(
+                    _mm256_cmpeq_epi32(self.v[0], PACKED_0),
+                    _mm256_cmpeq_epi32(self.v[0], PACKED_MOD),
+                ),
+                _mm256_or_si256(
+                    _mm256_cmpeq_epi32(self.v[1], PACKED_0),
+                    _mm256_cmpeq_epi32(self.v[1], PACKED_MOD),
+                ),
+            ];
+            _mm256_movemask_epi8(_mm256_and_si256(pcmp[0], pcmp[1])) == -1
         }
     }
 
@@ -336,38 +342,32 @@ impl PartialEq for AVXM31 {
     #[inline(always)]
     fn eq(&self, other: &Self) -> bool {
         unsafe {
-            let cmp0 = _mm256_cmpeq_epi32_mask(self.v[0], other.v[0]);
-            let cmp1 = _mm256_cmpeq_epi32_mask(self.v[1], other.v[1]);
-            (cmp0 & cmp1) == 0xFF
+            let cmp0 = _mm256_cmpeq_epi32(self.v[0], other.v[0]);
+            let cmp1 = _mm256_cmpeq_epi32(self.v[1], other.v[1]);
+            _mm256_movemask_epi8(_mm256_and_si256(cmp0, cmp1)) == -1
         }
     }
 }
 
-#[inline]
+// AVX2 has no masked moves, blend with the moved values instead
+#[inline(always)]
 #[must_use]
-fn mask_movehdup_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
-    // The instruction is only available in the floating-point flavor; this distinction is only for
-    // historical reasons and no longer matters. We cast to floats, do the thing, and cast back.
-    unsafe {
-        let src = _mm256_castsi256_ps(src);
-        let a = _mm256_castsi256_ps(a);
-        _mm256_castps_si256(_mm256_mask_movehdup_ps(src, k, a))
-    }
+fn mask_movehdup_epi32<const K: i32>(src: __m256i, a: __m256i) -> __m256i {
+    unsafe { _mm256_blend_epi32::<K>(src, movehdup_epi32(a)) }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
-fn mask_moveldup_epi32(src: __m256i, k: __mmask8, a: __m256i) -> __m256i {
+fn mask_moveldup_epi32<const K: i32>(src: __m256i, a: __m256i) -> __m256i {
     // The instruction is only available in the floating-point flavor; this distinction is only for
-    // historical reasons and no longer matters. We cast to floats, do the thing, and cast back.
+    // historical reasons and no longer matters. We cast to floats, duplicate, and cast back.
     unsafe {
-        let src = _mm256_castsi256_ps(src);
-        let a = _mm256_castsi256_ps(a);
-        _mm256_castps_si256(_mm256_mask_moveldup_ps(src, k, a))
+        let a = _mm256_castps_si256(_mm256_moveldup_ps(_mm256_castsi256_ps(a)));
+        _mm256_blend_epi32::<K>(src, a)
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn add(lhs: __m256i, rhs: __m256i) -> __m256i {
     unsafe {
@@ -377,8 +377,8 @@ fn add(lhs: __m256i, rhs: __m256i) -> __m256i {
     }
 }
 
-const EVENS: __mmask8 = 0b01010101;
-const ODDS: __mmask8 = 0b10101010;
+const EVENS: i32 = 0b01010101;
+const ODDS: i32 = 0b10101010;
 
 impl Mul<&M31> for AVXM31 {
     type Output = AVXM31;
@@ -398,8 +398,8 @@ impl Mul<&M31> for AVXM31 {
                 let prod_odd_dbl = _mm256_mul_epu32(lhs_odd_dbl, rhs_odd);
                 let prod_evn_dbl = _mm256_mul_epu32(lhs_evn_dbl, rhs_evn);
 
-                let prod_lo_dbl = mask_moveldup_epi32(prod_evn_dbl, ODDS, prod_odd_dbl);
-                let prod_hi = mask_movehdup_epi32(prod_odd_dbl, EVENS, prod_evn_dbl);
+                let prod_lo_dbl = mask_moveldup_epi32::<ODDS>(prod_evn_dbl, prod_odd_dbl);
+                let prod_hi = mask_movehdup_epi32::<EVENS>(prod_odd_dbl, prod_evn_dbl);
                 // Right shift to undo the doubling.
                 let prod_lo = _mm256_srli_epi32::<1>(prod_lo_dbl);
 
@@ -442,15 +442,15 @@ impl Neg for AVXM31 {
         AVXM31 {
             v: unsafe {
                 [
-                    _mm256_xor_epi32(self.v[0], PACKED_MOD),
-                    _mm256_xor_epi32(self.v[1], PACKED_MOD),
+                    _mm256_xor_si256(self.v[0], PACKED_MOD),
+                    _mm256_xor_si256(self.v[1], PACKED_MOD),
                 ]
             },
         }
     }
 }
 
-#[inline]
+#[inline(always)]
 #[must_use]
 fn movehdup_epi32(x: __m256i) -> __m256i {
     // The instruction is only available in the floating-point flavor; this distinction is only for
@@ -498,7 +498,7 @@ fn sub_internal(a: &AVXM31, b: &AVXM31) -> AVXM31 {
     }
 }
 
-#[inline]
+#[inline(always)]
 fn mul_internal(a: &AVXM31, b: &AVXM31) -> AVXM31 {
     // credit: https://github.com/Plonky3/Plonky3/blob/eeb4e37b20127c4daa871b2bad0df30a7c7380db/mersenne-31/src/x86_64_avx2/packing.rs#L154
     unsafe {
@@ -513,8 +513,8 @@ fn mul_internal(a: &AVXM31, b: &AVXM31) -> AVXM31 {
             let prod_odd_dbl = _mm256_mul_epu32(lhs_odd_dbl, rhs_odd);
             let prod_evn_dbl = _mm256_mul_epu32(lhs_evn_dbl, rhs_evn);
 
-            let prod_lo_dbl = mask_moveldup_epi32(prod_evn_dbl, ODDS, prod_odd_dbl);
-            let prod_hi = mask_movehdup_epi32(prod_odd_dbl, EVENS, prod_evn_dbl);
+            let prod_lo_dbl = mask_moveldup_epi32::<ODDS>(prod_evn_dbl, prod_odd_dbl);
+            let prod_hi = mask_movehdup_epi32::<EVENS>(prod_odd_dbl, prod_evn_dbl);
             // Right shift to undo the doubling.
             let prod_lo = _mm256_srli_epi32::<1>(prod_lo_dbl);
 
@@ -0,0 +1,318 @@
+use std::{
+    fmt::Debug,
+    io::{Read, Write},
+    iter::{Product, Sum},
+    mem::transmute,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::RngCore;
+
+use crate::{
+    field_common, m31_backend, Field, FieldSerde, FieldSerdeResult, PortableM31x16, SimdBackend,
+    SimdField, M31,
+};
+
+const M31_PACK_SIZE: usize = 16;
+
+/// DispatchM31x16 packs 16 M31 elements, and runs the AVX-512, AVX2 or portable kernels
+/// depending on the features of the host CPU.
+/// It has the layout of the AVX types, which the kernels operate on in place.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+#[repr(C, align(64))]
+pub struct DispatchM31x16 {
+    pub v: [M31; M31_PACK_SIZE],
+}
+
+field_common!(DispatchM31x16);
+
+// The kernels are compiled for their instruction sets, whatever the target CPU of the crate,
+// and must only be called once the CPU is known to support them.
+pub(crate) mod avx512 {
+    use super::{transmute, DispatchM31x16};
+    use crate::field::m31::m31_avx::AVXM31;
+
+    #[target_feature(enable = "avx512f")]
+    pub(crate) unsafe fn add(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) + transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(crate) unsafe fn sub(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) - transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(crate) unsafe fn mul(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) * transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(crate) unsafe fn neg(a: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(-transmute::<_, AVXM31>(*a))
+    }
+}
+
+pub(crate) mod avx2 {
+    use super::{transmute, DispatchM31x16};
+    use crate::field::m31::m31_avx256::AVXM31;
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn add(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) + transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn sub(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) - transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn mul(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(transmute::<_, AVXM31>(*a) * transmute::<_, AVXM31>(*b))
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(crate) unsafe fn neg(a: &DispatchM31x16) -> DispatchM31x16 {
+        transmute(-transmute::<_, AVXM31>(*a))
+    }
+}
+
+impl DispatchM31x16 {
+    #[inline(always)]
+    pub fn pack_full(x: M31) -> DispatchM31x16 {
+        DispatchM31x16 {
+            v: [x; M31_PACK_SIZE],
+        }
+    }
+
+    #[inline(always)]
+    fn to_portable(self) -> PortableM31x16 {
+        PortableM31x16 { v: self.v }
+    }
+
+    #[inline(always)]
+    fn from_portable(x: PortableM31x16) -> DispatchM31x16 {
+        DispatchM31x16 { v: x.v }
+    }
+}
+
+impl FieldSerde for DispatchM31x16 {
+    const SERIALIZED_SIZE: usize = 512 / 8;
+
+    #[inline(always)]
+    /// serialize self into bytes
+    fn serialize_into<W: Write>(&self, writer: W) -> FieldSerdeResult<()> {
+        self.to_portable().serialize_into(writer)
+    }
+
+    /// deserialize bytes into field
+    #[inline(always)]
+    fn deserialize_from<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        PortableM31x16::deserialize_from(reader).map(Self::from_portable)
+    }
+
+    #[inline(always)]
+    fn try_deserialize_from_ecc_format<R: Read>(reader: R) -> FieldSerdeResult<Self> {
+        PortableM31x16::try_deserialize_from_ecc_format(reader).map(Self::from_portable)
+    }
+}
+
+impl Field for DispatchM31x16 {
+    const NAME: &'static str = "Dispatched Packed Mersenne 31";
+
+    // size in bytes
+    const SIZE: usize = 512 / 8;
+
+    const ZERO: Self = Self {
+        v: [M31::ZERO; M31_PACK_SIZE],
+    };
+
+    const ONE: Self = Self {
+        v: [M31::ONE; M31_PACK_SIZE],
+    };
+
+    const INV_2: Self = Self {
+        v: [M31::INV_2; M31_PACK_SIZE],
+    };
+
+    const FIELD_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    // this function is for internal testing only. it is not
+    // a source for uniformly random field elements and
+    // should not be used in production.
+    fn random_unsafe(rng: impl RngCore) -> Self {
+        Self::from_portable(PortableM31x16::random_unsafe(rng))
+    }
+
+    #[inline(always)]
+    fn random_bool(rng: impl RngCore) -> Self {
+        Self::from_portable(PortableM31x16::random_bool(rng))
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        let mut e = exponent;
+        let mut res = Self::one();
+        let mut t = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                res *= t;
+            }
+            t = t * t;
+            e >>= 1;
+        }
+        res
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        self.to_portable().inv().map(Self::from_portable)
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Self::pack_full(M31::from_uniform_bytes(bytes))
+    }
+}
+
+impl SimdField for DispatchM31x16 {
+    type Scalar = M31;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        M31_PACK_SIZE
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == M31_PACK_SIZE);
+        DispatchM31x16 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<M31> for DispatchM31x16 {
+    #[inline(always)]
+    fn from(x: M31) -> Self {
+        DispatchM31x16::pack_full(x)
+    }
+}
+
+impl Debug for DispatchM31x16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_portable().fmt(f)
+    }
+}
+
+impl Mul<&M31> for DispatchM31x16 {
+    type Output = DispatchM31x16;
+    #[inline(always)]
+    fn mul(self, rhs: &M31) -> Self::Output {
+        self * DispatchM31x16::pack_full(*rhs)
+    }
+}
+
+impl Mul<M31> for DispatchM31x16 {
+    type Output = DispatchM31x16;
+    #[inline(always)]
+    fn mul(self, rhs: M31) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Add<M31> for DispatchM31x16 {
+    type Output = DispatchM31x16;
+    #[inline(always)]
+    fn add(self, rhs: M31) -> Self::Output {
+        self + DispatchM31x16::pack_full(rhs)
+    }
+}
+
+impl From<u32> for DispatchM31x16 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        DispatchM31x16::pack_full(M31::from(x))
+    }
+}
+
+impl Neg for DispatchM31x16 {
+    type Output = DispatchM31x16;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        // safety: the backend is only selected when the CPU supports it
+        unsafe {
+            match m31_backend() {
+                SimdBackend::Avx512 => avx512::neg(&self),
+                SimdBackend::Avx2 => avx2::neg(&self),
+                _ => Self::from_portable(-self.to_portable()),
+            }
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+    // safety: the backend is only selected when the CPU supports it
+    unsafe {
+        match m31_backend() {
+            SimdBackend::Avx512 => avx512::add(a, b),
+            SimdBackend::Avx2 => avx2::add(a, b),
+            _ => DispatchM31x16::from_portable(a.to_portable() + b.to_portable()),
+        }
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+    // safety: the backend is only selected when the CPU supports it
+    unsafe {
+        match m31_backend() {
+            SimdBackend::Avx512 => avx512::sub(a, b),
+            SimdBackend::Avx2 => avx2::sub(a, b),
+            _ => DispatchM31x16::from_portable(a.to_portable() - b.to_portable()),
+        }
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &DispatchM31x16, b: &DispatchM31x16) -> DispatchM31x16 {
+    // safety: the backend is only selected when the CPU supports it
+    unsafe {
+        match m31_backend() {
+            SimdBackend::Avx512 => avx512::mul(a, b),
+            SimdBackend::Avx2 => avx2::mul(a, b),
+            _ => DispatchM31x16::from_portable(a.to_portable() * b.to_portable()),
+        }
+    }
+}
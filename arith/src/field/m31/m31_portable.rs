@@ -0,0 +1,259 @@
+use std::{
+    fmt::Debug,
+    io::{Read, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::RngCore;
+
+use crate::{field_common, Field, FieldSerde, FieldSerdeResult, SimdField, M31};
+
+const M31_PACK_SIZE: usize = 16;
+
+/// PortableM31x16 packs 16 M31 elements and operates on them one by one,
+/// without any SIMD instructions.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct PortableM31x16 {
+    pub v: [M31; M31_PACK_SIZE],
+}
+
+field_common!(PortableM31x16);
+
+impl PortableM31x16 {
+    #[inline(always)]
+    pub fn pack_full(x: M31) -> PortableM31x16 {
+        PortableM31x16 {
+            v: [x; M31_PACK_SIZE],
+        }
+    }
+}
+
+impl FieldSerde for PortableM31x16 {
+    const SERIALIZED_SIZE: usize = 512 / 8;
+
+    #[inline(always)]
+    /// serialize self into bytes
+    fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        self.v
+            .iter()
+            .try_for_each(|x| writer.write_all(&x.v.to_le_bytes()))?;
+        Ok(())
+    }
+
+    /// deserialize bytes into field
+    #[inline(always)]
+    fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut data = [0; Self::SERIALIZED_SIZE];
+        reader.read_exact(&mut data)?;
+        let mut res = Self::zero();
+        res.v
+            .iter_mut()
+            .zip(data.chunks_exact(4))
+            .for_each(|(x, bytes)| *x = u32::from_le_bytes(bytes.try_into().unwrap()).into());
+        Ok(res)
+    }
+
+    #[inline(always)]
+    fn try_deserialize_from_ecc_format<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let mut buf = [0u8; 32];
+        reader.read_exact(&mut buf)?;
+        assert!(
+            buf.iter().skip(4).all(|x| *x == 0),
+            "non-zero byte found in witness byte"
+        );
+        Ok(Self::pack_full(
+            u32::from_le_bytes(buf[..4].try_into().unwrap()).into(),
+        ))
+    }
+}
+
+impl Field for PortableM31x16 {
+    const NAME: &'static str = "Portable Packed Mersenne 31";
+
+    // size in bytes
+    const SIZE: usize = 512 / 8;
+
+    const ZERO: Self = Self {
+        v: [M31::ZERO; M31_PACK_SIZE],
+    };
+
+    const ONE: Self = Self {
+        v: [M31::ONE; M31_PACK_SIZE],
+    };
+
+    const INV_2: Self = Self {
+        v: [M31::INV_2; M31_PACK_SIZE],
+    };
+
+    const FIELD_SIZE: usize = 32;
+
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.iter().all(|x| x.is_zero())
+    }
+
+    #[inline(always)]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline(always)]
+    // this function is for internal testing only. it is not
+    // a source for uniformly random field elements and
+    // should not be used in production.
+    fn random_unsafe(mut rng: impl RngCore) -> Self {
+        PortableM31x16 {
+            v: std::array::from_fn(|_| M31::random_unsafe(&mut rng)),
+        }
+    }
+
+    #[inline(always)]
+    fn random_bool(mut rng: impl RngCore) -> Self {
+        PortableM31x16 {
+            v: std::array::from_fn(|_| M31::random_bool(&mut rng)),
+        }
+    }
+
+    fn exp(&self, exponent: u128) -> Self {
+        PortableM31x16 {
+            v: self.v.map(|x| x.exp(exponent)),
+        }
+    }
+
+    #[inline(always)]
+    fn inv(&self) -> Option<Self> {
+        if self.v.iter().any(|x| x.is_zero()) {
+            return None;
+        }
+        Some(PortableM31x16 {
+            v: self.v.map(|x| x.inv().unwrap()), // safe unwrap
+        })
+    }
+
+    fn as_u32_unchecked(&self) -> u32 {
+        unimplemented!("self is a vector, cannot convert to u32")
+    }
+
+    #[inline]
+    fn from_uniform_bytes(bytes: &[u8; 32]) -> Self {
+        Self::pack_full(M31::from_uniform_bytes(bytes))
+    }
+}
+
+impl SimdField for PortableM31x16 {
+    type Scalar = M31;
+
+    #[inline]
+    fn scale(&self, challenge: &Self::Scalar) -> Self {
+        *self * *challenge
+    }
+
+    #[inline(always)]
+    fn pack_size() -> usize {
+        M31_PACK_SIZE
+    }
+
+    #[inline(always)]
+    fn pack(base_vec: &[Self::Scalar]) -> Self {
+        debug_assert!(base_vec.len() == M31_PACK_SIZE);
+        PortableM31x16 {
+            v: base_vec.try_into().unwrap(),
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self) -> Vec<Self::Scalar> {
+        self.v.to_vec()
+    }
+}
+
+impl From<M31> for PortableM31x16 {
+    #[inline(always)]
+    fn from(x: M31) -> Self {
+        PortableM31x16::pack_full(x)
+    }
+}
+
+impl Debug for PortableM31x16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let data = self.v.map(|x| x.v);
+        // if all data is the same, print only one
+        if data.iter().all(|x| *x == data[0]) {
+            write!(f, "[M31; 16]<16 x {}>", data[0])
+        } else {
+            write!(f, "[M31; 16]<{:?}>", data)
+        }
+    }
+}
+
+impl Mul<&M31> for PortableM31x16 {
+    type Output = PortableM31x16;
+    #[inline(always)]
+    fn mul(self, rhs: &M31) -> Self::Output {
+        PortableM31x16 {
+            v: self.v.map(|x| x * rhs),
+        }
+    }
+}
+
+impl Mul<M31> for PortableM31x16 {
+    type Output = PortableM31x16;
+    #[inline(always)]
+    fn mul(self, rhs: M31) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl Add<M31> for PortableM31x16 {
+    type Output = PortableM31x16;
+    #[inline(always)]
+    fn add(self, rhs: M31) -> Self::Output {
+        PortableM31x16 {
+            v: self.v.map(|x| x + rhs),
+        }
+    }
+}
+
+impl From<u32> for PortableM31x16 {
+    #[inline(always)]
+    fn from(x: u32) -> Self {
+        PortableM31x16::pack_full(M31::from(x))
+    }
+}
+
+impl Neg for PortableM31x16 {
+    type Output = PortableM31x16;
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        PortableM31x16 {
+            v: self.v.map(|x| -x),
+        }
+    }
+}
+
+#[inline(always)]
+fn add_internal(a: &PortableM31x16, b: &PortableM31x16) -> PortableM31x16 {
+    PortableM31x16 {
+        v: std::array::from_fn(|i| a.v[i] + b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn sub_internal(a: &PortableM31x16, b: &PortableM31x16) -> PortableM31x16 {
+    PortableM31x16 {
+        v: std::array::from_fn(|i| a.v[i] - b.v[i]),
+    }
+}
+
+#[inline(always)]
+fn mul_internal(a: &PortableM31x16, b: &PortableM31x16) -> PortableM31x16 {
+    PortableM31x16 {
+        v: std::array::from_fn(|i| a.v[i] * b.v[i]),
+    }
+}
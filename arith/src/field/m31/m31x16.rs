@@ -1,14 +1,14 @@
 // A M31x16 stores 512 bits of data.
-// With AVX it stores a single __m512i element.
+// On x86_64 it stores sixteen M31 elements, which the AVX-512 or AVX2 kernels
+// selected at runtime operate on as a single __m512i or two __m256i elements.
 // With NEON it stores four uint32x4_t elements.
-#[cfg(target_arch = "x86_64")]
+// With the portable backend it stores sixteen M31 elements.
 cfg_if::cfg_if! {
-    if #[cfg(feature = "avx256")] {
-        pub type M31x16 = super::m31_avx256::AVXM31;
+    if #[cfg(any(feature = "portable", not(any(target_arch = "x86_64", target_arch = "aarch64"))))] {
+        pub type M31x16 = super::m31_portable::PortableM31x16;
+    } else if #[cfg(target_arch = "aarch64")] {
+        pub type M31x16 = super::m31_neon::NeonM31;
     } else {
-        pub type M31x16 = super::m31_avx::AVXM31;
+        pub type M31x16 = super::m31_dispatch::DispatchM31x16;
     }
 }
-
-#[cfg(target_arch = "aarch64")]
-pub type M31x16 = super::m31_neon::NeonM31;
@@ -9,9 +9,15 @@ pub use extension_field::*;
 mod simd_field;
 pub use simd_field::*;
 
+mod simd_backend;
+pub use simd_backend::*;
+
 mod serde;
 pub use serde::*;
 
+mod batch;
+pub use batch::*;
+
 mod macros;
 
 #[cfg(test)]
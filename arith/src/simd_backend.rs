@@ -0,0 +1,180 @@
+//! The kernels of the packed fields.
+//!
+//! On x86_64 they are selected from the features of the host CPU, detected once on first use,
+//! so that a single binary runs on any x86_64 host. The price is a load and a call to a kernel
+//! that cannot be inlined on every operation. When the crate is compiled with the target
+//! features of the kernels, e.g., with `-C target-cpu=native`, the best of them is selected at
+//! compile time instead, so the dispatch folds away and the kernels inline into their callers.
+//! The `avx256` feature caps the selection at AVX2, and the `portable` feature disables SIMD.
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The instruction set used by the kernels of a packed field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimdBackend {
+    /// AVX-512 on x86_64.
+    Avx512 = 1,
+    /// AVX2 on x86_64. For the GF2_128 types, the 128-bit carry-less multiplication
+    /// of PCLMULQDQ, which all the AVX2 CPUs have.
+    Avx2 = 2,
+    /// NEON on aarch64.
+    Neon = 3,
+    /// No SIMD instructions.
+    Portable = 4,
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+static M31_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+static GF2_128_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+static BN254_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+#[inline(always)]
+fn cached_backend(cache: &AtomicU8, name: &str, detect: fn() -> SimdBackend) -> SimdBackend {
+    match cache.load(Ordering::Relaxed) {
+        1 => SimdBackend::Avx512,
+        2 => SimdBackend::Avx2,
+        4 => SimdBackend::Portable,
+        _ => init_backend(cache, name, detect),
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+#[cold]
+fn init_backend(cache: &AtomicU8, name: &str, detect: fn() -> SimdBackend) -> SimdBackend {
+    let backend = detect();
+    log::debug!("{} kernels: {:?}", name, backend);
+    cache.store(backend as u8, Ordering::Relaxed);
+    backend
+}
+
+// The best backends the crate is compiled for, which the host CPU must support
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+const STATIC_M31_BACKEND: Option<SimdBackend> =
+    if cfg!(all(target_feature = "avx512f", not(feature = "avx256"))) {
+        Some(SimdBackend::Avx512)
+    } else if cfg!(target_feature = "avx2") {
+        Some(SimdBackend::Avx2)
+    } else {
+        None
+    };
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+const STATIC_GF2_128_BACKEND: Option<SimdBackend> = if cfg!(all(
+    target_feature = "pclmulqdq",
+    target_feature = "avx512f",
+    target_feature = "avx512bw",
+    target_feature = "vpclmulqdq",
+    not(feature = "avx256")
+)) {
+    Some(SimdBackend::Avx512)
+} else if cfg!(target_feature = "pclmulqdq") {
+    Some(SimdBackend::Avx2)
+} else {
+    None
+};
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+fn detect_m31_backend() -> SimdBackend {
+    if cfg!(not(feature = "avx256")) && is_x86_feature_detected!("avx512f") {
+        SimdBackend::Avx512
+    } else if is_x86_feature_detected!("avx2") {
+        SimdBackend::Avx2
+    } else {
+        SimdBackend::Portable
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+fn detect_gf2_128_backend() -> SimdBackend {
+    if !is_x86_feature_detected!("pclmulqdq") {
+        SimdBackend::Portable
+    } else if cfg!(not(feature = "avx256"))
+        && is_x86_feature_detected!("avx512f")
+        && is_x86_feature_detected!("avx512bw")
+        && is_x86_feature_detected!("vpclmulqdq")
+    {
+        SimdBackend::Avx512
+    } else {
+        SimdBackend::Avx2
+    }
+}
+
+// The Montgomery multiplication of Frx8 runs on 32-bit limbs in 64-bit lanes,
+// with the same instructions as the M31 kernels.
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+fn detect_bn254_backend() -> SimdBackend {
+    detect_m31_backend()
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "portable", not(any(target_arch = "x86_64", target_arch = "aarch64"))))] {
+        /// The kernels of `M31x16` and `M31Ext3x16`.
+        #[inline(always)]
+        pub fn m31_backend() -> SimdBackend {
+            SimdBackend::Portable
+        }
+
+        /// The kernels of `GF2_128` and `GF2_128x8`.
+        #[inline(always)]
+        pub fn gf2_128_backend() -> SimdBackend {
+            SimdBackend::Portable
+        }
+
+        /// The kernels of `Frx8`.
+        #[inline(always)]
+        pub fn bn254_backend() -> SimdBackend {
+            SimdBackend::Portable
+        }
+    } else if #[cfg(target_arch = "aarch64")] {
+        /// The kernels of `M31x16` and `M31Ext3x16`.
+        #[inline(always)]
+        pub fn m31_backend() -> SimdBackend {
+            SimdBackend::Neon
+        }
+
+        /// The kernels of `GF2_128` and `GF2_128x8`.
+        #[inline(always)]
+        pub fn gf2_128_backend() -> SimdBackend {
+            SimdBackend::Neon
+        }
+
+        /// The kernels of `Frx8`, which has no NEON kernel.
+        #[inline(always)]
+        pub fn bn254_backend() -> SimdBackend {
+            SimdBackend::Portable
+        }
+    } else {
+        /// The kernels of `M31x16` and `M31Ext3x16`.
+        #[inline(always)]
+        pub fn m31_backend() -> SimdBackend {
+            match STATIC_M31_BACKEND {
+                Some(backend) => backend,
+                None => cached_backend(&M31_BACKEND, "M31", detect_m31_backend),
+            }
+        }
+
+        /// The kernels of `GF2_128` and `GF2_128x8`.
+        #[inline(always)]
+        pub fn gf2_128_backend() -> SimdBackend {
+            match STATIC_GF2_128_BACKEND {
+                Some(backend) => backend,
+                None => cached_backend(&GF2_128_BACKEND, "GF2_128", detect_gf2_128_backend),
+            }
+        }
+
+        /// The kernels of `Frx8`.
+        #[inline(always)]
+        pub fn bn254_backend() -> SimdBackend {
+            match STATIC_M31_BACKEND {
+                Some(backend) => backend,
+                None => cached_backend(&BN254_BACKEND, "BN254", detect_bn254_backend),
+            }
+        }
+    }
+}
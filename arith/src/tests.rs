@@ -1,16 +1,23 @@
+mod babybear;
+mod babybear_ext;
+mod batch;
 mod bn254;
 mod extension_field;
 mod field;
 mod gf2;
 mod gf2_128;
 mod gf2_128x8;
+mod goldilocks;
+mod goldilocks_ext;
 mod m31;
 mod m31_ext;
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
 mod neon_gf2_128;
+mod portable;
+mod simd_backend;
 mod simd_field;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
 #[test]
 fn test_mm256_const_init() {
     use std::arch::x86_64::*;
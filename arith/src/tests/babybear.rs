@@ -0,0 +1,71 @@
+use std::io::Cursor;
+
+use ark_std::test_rng;
+
+use crate::{
+    BabyBear, BabyBearx16, Field, FieldForECC, FieldSerde, BABYBEAR_MOD, BABYBEAR_TWO_ADICITY,
+};
+
+use super::{
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
+
+#[test]
+fn test_field() {
+    random_field_tests::<BabyBear>("BabyBear".to_string());
+    random_field_tests::<BabyBearx16>("Vectorized BabyBear".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<BabyBear, _>(&mut rng, "BabyBear".to_string());
+    random_inversion_tests::<BabyBearx16, _>(&mut rng, "Vectorized BabyBear".to_string());
+
+    random_simd_field_tests::<BabyBearx16>("Vectorized BabyBear".to_string());
+}
+
+#[test]
+fn test_babybear_reduction() {
+    let minus_one = BabyBear {
+        v: BABYBEAR_MOD - 1,
+    };
+    assert_eq!(-BabyBear::ONE, minus_one);
+    assert_eq!(minus_one * minus_one, BabyBear::ONE);
+    assert_eq!(minus_one + BabyBear::ONE, BabyBear::ZERO);
+    assert_eq!(BabyBear::ZERO - BabyBear::ONE, minus_one);
+
+    assert_eq!(BabyBear::INV_2.double(), BabyBear::ONE);
+    assert_eq!(BabyBear::from(BABYBEAR_MOD + 5), BabyBear::from(5));
+    assert_eq!(
+        BabyBear::from(u32::MAX),
+        BabyBear::from(u32::MAX - 2 * BABYBEAR_MOD)
+    );
+    assert_eq!(
+        BabyBear::from_u256(BabyBear::modulus() + ethnum::U256::from(5u32)),
+        BabyBear::from(5)
+    );
+    assert_eq!(minus_one.to_u256(), BabyBear::modulus() - ethnum::U256::ONE);
+}
+
+#[test]
+fn test_babybear_two_adic_generator() {
+    for bits in 0..=BABYBEAR_TWO_ADICITY {
+        let g = BabyBear::two_adic_generator(bits);
+        // g has order exactly 2^bits
+        assert_eq!(g.exp(1 << bits), BabyBear::ONE);
+        if bits > 0 {
+            assert_eq!(g.exp(1 << (bits - 1)), -BabyBear::ONE);
+        }
+    }
+}
+
+#[test]
+fn test_custom_serde_vectorize_babybear() {
+    let a = BabyBearx16::from(256 + 2);
+    let mut buffer = vec![];
+    assert!(a.serialize_into(&mut buffer).is_ok());
+    let mut cursor = Cursor::new(buffer);
+    let b = BabyBearx16::deserialize_from(&mut cursor);
+    assert!(b.is_ok());
+    let b = b.unwrap();
+    assert_eq!(a, b);
+}
@@ -0,0 +1,30 @@
+use ark_std::test_rng;
+
+use crate::{BabyBearExt4, BabyBearExt4x16, ExtensionField, Field};
+
+use super::{
+    extension_field::random_extension_field_tests,
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
+
+#[test]
+fn test_field() {
+    random_field_tests::<BabyBearExt4>("BabyBear Ext4".to_string());
+    random_extension_field_tests::<BabyBearExt4>("BabyBear Ext4".to_string());
+    random_field_tests::<BabyBearExt4x16>("Simd BabyBear Ext4".to_string());
+    random_extension_field_tests::<BabyBearExt4x16>("Simd BabyBear Ext4".to_string());
+    random_simd_field_tests::<BabyBearExt4x16>("Simd BabyBear Ext4".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<BabyBearExt4, _>(&mut rng, "BabyBear Ext4".to_string());
+    random_inversion_tests::<BabyBearExt4x16, _>(&mut rng, "Simd BabyBear Ext4".to_string());
+}
+
+#[test]
+fn test_babybear_ext4_modulus() {
+    // x^4 = 11
+    let x = BabyBearExt4::X;
+    assert_eq!(x.square().square(), BabyBearExt4::from(BabyBearExt4::W));
+    assert_eq!(x.mul_by_x(), x * x);
+}
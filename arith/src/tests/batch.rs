@@ -0,0 +1,122 @@
+use ark_std::test_rng;
+use halo2curves::bn256::Fr;
+
+use crate::{
+    batch_exp, batch_inversion, batch_inversion_simd, powers, Field, Goldilocks, LagrangeDomain,
+    M31Ext3, M31x16, ScalarField, SimdField, GF2_128, M31,
+};
+
+fn random_batch_inversion_tests<F: ScalarField>() {
+    let mut rng = test_rng();
+    let mut v = (0..100)
+        .map(|i| {
+            if i % 7 == 0 {
+                F::zero()
+            } else {
+                F::random_unsafe(&mut rng)
+            }
+        })
+        .collect::<Vec<_>>();
+    let expected = v
+        .iter()
+        .map(|x| x.inv().unwrap_or(F::zero()))
+        .collect::<Vec<_>>();
+
+    batch_inversion(&mut v);
+    assert_eq!(v, expected);
+
+    let mut empty: Vec<F> = vec![];
+    batch_inversion(&mut empty);
+}
+
+#[test]
+fn test_batch_inversion() {
+    random_batch_inversion_tests::<M31>();
+    random_batch_inversion_tests::<M31Ext3>();
+    random_batch_inversion_tests::<Goldilocks>();
+    random_batch_inversion_tests::<Fr>();
+    random_batch_inversion_tests::<GF2_128>();
+}
+
+// Packed elements with a zero lane
+fn packed_with_zero_lane() -> Vec<M31x16> {
+    let mut rng = test_rng();
+    let mut v = (0..10)
+        .map(|_| M31x16::random_unsafe(&mut rng))
+        .collect::<Vec<_>>();
+    let mut lanes = v[3].unpack();
+    lanes[5] = M31::ZERO;
+    v[3] = M31x16::pack(&lanes);
+    v[7] = M31x16::ZERO;
+    v
+}
+
+#[test]
+fn test_batch_inversion_simd() {
+    let mut v = packed_with_zero_lane();
+    let expected = v
+        .iter()
+        .map(|x| {
+            let lanes = x
+                .unpack()
+                .iter()
+                .map(|l| l.inv().unwrap_or(M31::ZERO))
+                .collect::<Vec<_>>();
+            M31x16::pack(&lanes)
+        })
+        .collect::<Vec<_>>();
+    batch_inversion_simd(&mut v);
+    assert_eq!(v, expected);
+}
+
+#[test]
+fn test_batch_exp() {
+    let mut rng = test_rng();
+    let bases = (0..10)
+        .map(|_| M31Ext3::random_unsafe(&mut rng))
+        .collect::<Vec<_>>();
+    for exponent in [0, 1, 2, 5, 1 << 40, u128::MAX] {
+        let expected = bases.iter().map(|b| b.exp(exponent)).collect::<Vec<_>>();
+        assert_eq!(batch_exp(&bases, exponent), expected);
+    }
+
+    let base = Goldilocks::random_unsafe(&mut rng);
+    let table = powers(&base, 10);
+    assert_eq!(table.len(), 10);
+    table
+        .iter()
+        .enumerate()
+        .for_each(|(i, p)| assert_eq!(*p, base.exp(i as u128)));
+}
+
+#[test]
+fn test_lagrange_domain() {
+    let mut rng = test_rng();
+    for n in 1..8 {
+        let coefs = (0..n)
+            .map(|_| M31Ext3::random_unsafe(&mut rng))
+            .collect::<Vec<_>>();
+        let poly = |x: &M31Ext3| {
+            coefs
+                .iter()
+                .rev()
+                .fold(M31Ext3::zero(), |acc, c| acc * x + c)
+        };
+
+        let points = (0..n as u32).map(M31Ext3::from).collect::<Vec<_>>();
+        let domain = LagrangeDomain::new(&points);
+        let vals = points.iter().map(poly).collect::<Vec<_>>();
+
+        let x = M31Ext3::random_unsafe(&mut rng);
+        assert_eq!(domain.evaluate(&vals, &x), poly(&x));
+        for (p, v) in points.iter().zip(&vals) {
+            assert_eq!(domain.evaluate(&vals, p), *v);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_lagrange_domain_repeated_points() {
+    LagrangeDomain::new(&[M31::ZERO, M31::ONE, M31::ZERO]);
+}
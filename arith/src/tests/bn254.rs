@@ -1,12 +1,65 @@
 use ark_std::test_rng;
-use halo2curves::bn256::Fr;
+use halo2curves::{bn256::Fr, ff::PrimeField};
 
-use super::field::{random_field_tests, random_inversion_tests};
+use crate::{Field, Frx8, SimdField};
+
+use super::{
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
 
 #[test]
 fn test_field() {
     random_field_tests::<Fr>("bn254::Fr".to_string());
+    random_field_tests::<Frx8>("Vectorized bn254::Fr".to_string());
 
     let mut rng = test_rng();
     random_inversion_tests::<Fr, _>(&mut rng, "bn254::Fr".to_string());
+    random_inversion_tests::<Frx8, _>(&mut rng, "Vectorized bn254::Fr".to_string());
+
+    random_simd_field_tests::<Frx8>("Vectorized bn254::Fr".to_string());
+}
+
+#[test]
+fn test_frx8_mul() {
+    let mut rng = test_rng();
+    for _ in 0..100 {
+        let a = Frx8::random_unsafe(&mut rng);
+        let b = Frx8::random_unsafe(&mut rng);
+        let prod = (a * b).unpack();
+        for (i, (x, y)) in a.unpack().iter().zip(b.unpack()).enumerate() {
+            assert_eq!(prod[i], *x * y);
+        }
+    }
+
+    // the reduction boundaries
+    let minus_one = -Frx8::ONE;
+    assert_eq!(minus_one * minus_one, Frx8::ONE);
+    assert_eq!(minus_one * Frx8::ZERO, Frx8::ZERO);
+    assert_eq!(Frx8::INV_2 * Frx8::from(2), Frx8::ONE);
+}
+
+// The kernels reinterpret `Fr` as its 4 u64 limbs in Montgomery form
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+#[test]
+fn test_fr_layout() {
+    use crate::field::bn254::frx8::montgomery::{from_limbs, to_limbs};
+
+    // x is stored as x * 2^256 mod p
+    let r = Fr::from(2u64).exp(256);
+    let mut rng = test_rng();
+    for x in [
+        Fr::zero(),
+        Fr::one(),
+        -Fr::one(),
+        Fr::random_unsafe(&mut rng),
+    ] {
+        let limbs = to_limbs(&[x]);
+        let repr = (x * r).to_repr();
+        for (j, limb) in limbs.iter().enumerate() {
+            let expected = u32::from_le_bytes(repr.as_ref()[4 * j..4 * j + 4].try_into().unwrap());
+            assert_eq!(limb[0], expected as u64);
+        }
+        assert_eq!(from_limbs(&limbs), [x]);
+    }
 }
@@ -0,0 +1,65 @@
+use std::io::Cursor;
+
+use ark_std::test_rng;
+
+use crate::{Field, FieldForECC, FieldSerde, Goldilocks, Goldilocksx8, GOLDILOCKS_MOD};
+
+use super::{
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
+
+#[test]
+fn test_field() {
+    random_field_tests::<Goldilocks>("Goldilocks".to_string());
+    random_field_tests::<Goldilocksx8>("Vectorized Goldilocks".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<Goldilocks, _>(&mut rng, "Goldilocks".to_string());
+    random_inversion_tests::<Goldilocksx8, _>(&mut rng, "Vectorized Goldilocks".to_string());
+
+    random_simd_field_tests::<Goldilocksx8>("Vectorized Goldilocks".to_string());
+}
+
+#[test]
+fn test_goldilocks_reduction() {
+    let minus_one = Goldilocks {
+        v: GOLDILOCKS_MOD - 1,
+    };
+    assert_eq!(-Goldilocks::ONE, minus_one);
+    assert_eq!(minus_one * minus_one, Goldilocks::ONE);
+    assert_eq!(minus_one + Goldilocks::ONE, Goldilocks::ZERO);
+    assert_eq!(Goldilocks::ZERO - Goldilocks::ONE, minus_one);
+
+    // 2^64 = 2^32 - 1 and 2^96 = -1
+    let two_32 = Goldilocks { v: 1 << 32 };
+    let two_48 = Goldilocks { v: 1 << 48 };
+    assert_eq!(two_32 * two_32, Goldilocks { v: (1 << 32) - 1 });
+    assert_eq!(two_48 * two_48, minus_one);
+
+    assert_eq!(Goldilocks::INV_2.double(), Goldilocks::ONE);
+    assert_eq!(
+        Goldilocks::from_u64(GOLDILOCKS_MOD + 5),
+        Goldilocks::from(5)
+    );
+    assert_eq!(
+        Goldilocks::from_u256(Goldilocks::modulus() + ethnum::U256::from(5u32)),
+        Goldilocks::from(5)
+    );
+    assert_eq!(
+        minus_one.to_u256(),
+        Goldilocks::modulus() - ethnum::U256::ONE
+    );
+}
+
+#[test]
+fn test_custom_serde_vectorize_goldilocks() {
+    let a = Goldilocksx8::from(256 + 2);
+    let mut buffer = vec![];
+    assert!(a.serialize_into(&mut buffer).is_ok());
+    let mut cursor = Cursor::new(buffer);
+    let b = Goldilocksx8::deserialize_from(&mut cursor);
+    assert!(b.is_ok());
+    let b = b.unwrap();
+    assert_eq!(a, b);
+}
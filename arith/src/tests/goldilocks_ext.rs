@@ -0,0 +1,30 @@
+use ark_std::test_rng;
+
+use crate::{ExtensionField, Field, GoldilocksExt2, GoldilocksExt2x8};
+
+use super::{
+    extension_field::random_extension_field_tests,
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
+
+#[test]
+fn test_field() {
+    random_field_tests::<GoldilocksExt2>("Goldilocks Ext2".to_string());
+    random_extension_field_tests::<GoldilocksExt2>("Goldilocks Ext2".to_string());
+    random_field_tests::<GoldilocksExt2x8>("Simd Goldilocks Ext2".to_string());
+    random_extension_field_tests::<GoldilocksExt2x8>("Simd Goldilocks Ext2".to_string());
+    random_simd_field_tests::<GoldilocksExt2x8>("Simd Goldilocks Ext2".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<GoldilocksExt2, _>(&mut rng, "Goldilocks Ext2".to_string());
+    random_inversion_tests::<GoldilocksExt2x8, _>(&mut rng, "Simd Goldilocks Ext2".to_string());
+}
+
+#[test]
+fn test_goldilocks_ext2_modulus() {
+    // x^2 = 7
+    let x = GoldilocksExt2::X;
+    assert_eq!(x * x, GoldilocksExt2::from(GoldilocksExt2::W));
+    assert_eq!(x.mul_by_x(), x * x);
+}
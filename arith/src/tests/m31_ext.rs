@@ -1,8 +1,10 @@
-use crate::M31Ext3;
-use crate::M31Ext3x16;
+use ark_std::test_rng;
+
+use crate::{ExtensionField, Field, M31Ext3, M31Ext3x16, M31Ext6, M31Ext6x16};
 
 use super::{
-    extension_field::random_extension_field_tests, field::random_field_tests,
+    extension_field::random_extension_field_tests,
+    field::{random_field_tests, random_inversion_tests},
     simd_field::random_simd_field_tests,
 };
 #[test]
@@ -13,3 +15,24 @@ fn test_field() {
     random_extension_field_tests::<M31Ext3x16>("Simd M31 Ext3".to_string());
     random_simd_field_tests::<M31Ext3x16>("Simd M31 Ext3".to_string());
 }
+
+#[test]
+fn test_ext6_field() {
+    random_field_tests::<M31Ext6>("M31 Ext6".to_string());
+    random_extension_field_tests::<M31Ext6>("M31 Ext6".to_string());
+    random_field_tests::<M31Ext6x16>("Simd M31 Ext6".to_string());
+    random_extension_field_tests::<M31Ext6x16>("Simd M31 Ext6".to_string());
+    random_simd_field_tests::<M31Ext6x16>("Simd M31 Ext6".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<M31Ext6, _>(&mut rng, "M31 Ext6".to_string());
+    random_inversion_tests::<M31Ext6x16, _>(&mut rng, "Simd M31 Ext6".to_string());
+}
+
+#[test]
+fn test_m31_ext6_modulus() {
+    // x^6 = 5
+    let x = M31Ext6::X;
+    assert_eq!(x.square().square() * x.square(), M31Ext6::from(M31Ext6::W));
+    assert_eq!(x.mul_by_x(), x * x);
+}
@@ -0,0 +1,187 @@
+use ark_std::test_rng;
+
+use crate::{ExtensionField, Field, PortableGF2_128, PortableGF2_128x8, PortableM31x16, M31};
+
+use super::{
+    extension_field::random_extension_field_tests,
+    field::{random_field_tests, random_inversion_tests},
+    simd_field::random_simd_field_tests,
+};
+
+#[test]
+fn test_field() {
+    random_field_tests::<PortableM31x16>("Portable Vectorized M31".to_string());
+    random_simd_field_tests::<PortableM31x16>("Portable Vectorized M31".to_string());
+
+    random_field_tests::<PortableGF2_128>("Portable GF2_128".to_string());
+    random_extension_field_tests::<PortableGF2_128>("Portable GF2_128".to_string());
+    random_field_tests::<PortableGF2_128x8>("Portable Vectorized GF2_128".to_string());
+    random_extension_field_tests::<PortableGF2_128x8>("Portable Vectorized GF2_128".to_string());
+    random_simd_field_tests::<PortableGF2_128x8>("Portable Vectorized GF2_128".to_string());
+
+    let mut rng = test_rng();
+    random_inversion_tests::<PortableM31x16, _>(&mut rng, "Portable Vectorized M31".to_string());
+    random_inversion_tests::<PortableGF2_128, _>(&mut rng, "Portable GF2_128".to_string());
+}
+
+#[test]
+fn test_portable_gf2_128_reduction() {
+    // x^127 * x = x^128 = x^7 + x^2 + x + 1
+    let x_127 = PortableGF2_128 { v: 1 << 127 };
+    assert_eq!(x_127 * PortableGF2_128::X, PortableGF2_128 { v: 0x87 });
+    assert_eq!(x_127.mul_by_x(), PortableGF2_128 { v: 0x87 });
+
+    // (x^127 + x^64) * (x^127 + 1), reduced by hand
+    let a = PortableGF2_128 {
+        v: (1 << 127) | (1 << 64),
+    };
+    let b = PortableGF2_128 { v: (1 << 127) | 1 };
+    // x^254 + x^191 + x^127 + x^64, with x^254 = x^133 + x^128 + x^127 + x^126
+    // = x^12 + x^7 + x^6 + x^5 + x^7 + x^2 + x + 1 + x^127 + x^126
+    // and x^191 = x^70 + x^65 + x^64 + x^63
+    let expected = (1u128 << 12)
+        | (1 << 6)
+        | (1 << 5)
+        | (1 << 2)
+        | (1 << 1)
+        | 1
+        | (1 << 126)
+        | (1 << 70)
+        | (1 << 65)
+        | (1 << 63);
+    assert_eq!(a * b, PortableGF2_128 { v: expected });
+    assert_eq!(a * b * b.inv().unwrap(), a);
+}
+
+#[test]
+fn test_portable_m31x16_pack() {
+    let scalars = (0..16).map(|i| M31::from(i * 1000 + 7)).collect::<Vec<_>>();
+    let a = <PortableM31x16 as crate::SimdField>::pack(&scalars);
+    assert_eq!(a.v.to_vec(), scalars);
+    assert_eq!((a * a).v[3], scalars[3] * scalars[3]);
+}
+
+// The SIMD types are the portable ones with the `portable` feature,
+// otherwise check that both backends agree.
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "aarch64"),
+    not(feature = "portable")
+))]
+mod cross_check {
+    use ark_std::test_rng;
+
+    use crate::{
+        ExtensionField, Field, FieldSerde, GF2_128x8, M31x16, PortableGF2_128, PortableGF2_128x8,
+        PortableM31x16, SimdField, GF2_128, M31,
+    };
+
+    const NUM_TESTS: usize = 100;
+
+    // both backends share the serialization format
+    fn to_portable<F: FieldSerde, P: FieldSerde>(x: &F) -> P {
+        let mut buffer = vec![];
+        x.serialize_into(&mut buffer).unwrap();
+        P::deserialize_from(buffer.as_slice()).unwrap()
+    }
+
+    fn cross_check_field<F: Field + FieldSerde, P: Field + FieldSerde>() {
+        let mut rng = test_rng();
+
+        for _ in 0..NUM_TESTS {
+            let a = F::random_unsafe(&mut rng);
+            let b = F::random_unsafe(&mut rng);
+            let pa = to_portable::<F, P>(&a);
+            let pb = to_portable::<F, P>(&b);
+
+            assert_eq!(to_portable::<F, P>(&(a + b)), pa + pb);
+            assert_eq!(to_portable::<F, P>(&(a - b)), pa - pb);
+            assert_eq!(to_portable::<F, P>(&(a * b)), pa * pb);
+            assert_eq!(to_portable::<F, P>(&(-a)), -pa);
+            assert_eq!(to_portable::<F, P>(&a.square()), pa.square());
+            assert_eq!(to_portable::<F, P>(&a.double()), pa.double());
+            assert_eq!(to_portable::<F, P>(&a.mul_by_5()), pa.mul_by_5());
+            assert_eq!(a.is_zero(), pa.is_zero());
+        }
+    }
+
+    fn cross_check_extension_field<F: ExtensionField, P: ExtensionField>() {
+        let mut rng = test_rng();
+
+        for _ in 0..NUM_TESTS {
+            let a = F::random_unsafe(&mut rng);
+            let base = F::BaseField::random_unsafe(&mut rng);
+            let pa = to_portable::<F, P>(&a);
+            let pbase = to_portable::<F::BaseField, P::BaseField>(&base);
+
+            assert_eq!(to_portable::<F, P>(&a.mul_by_x()), pa.mul_by_x());
+            assert_eq!(
+                to_portable::<F, P>(&a.mul_by_base_field(&base)),
+                pa.mul_by_base_field(&pbase)
+            );
+            assert_eq!(
+                to_portable::<F, P>(&a.add_by_base_field(&base)),
+                pa.add_by_base_field(&pbase)
+            );
+            assert_eq!(to_portable::<F, P>(&F::from(base)), P::from(pbase));
+        }
+    }
+
+    fn cross_check_simd_field<F: SimdField, P: SimdField>() {
+        let mut rng = test_rng();
+
+        for _ in 0..NUM_TESTS {
+            let a = F::random_unsafe(&mut rng);
+            let s = F::Scalar::random_unsafe(&mut rng);
+            let pa = to_portable::<F, P>(&a);
+            let ps = to_portable::<F::Scalar, P::Scalar>(&s);
+
+            assert_eq!(to_portable::<F, P>(&a.scale(&s)), pa.scale(&ps));
+            assert_eq!(to_portable::<F, P>(&F::from(s)), P::from(ps));
+            assert_eq!(
+                a.unpack()
+                    .iter()
+                    .map(to_portable::<F::Scalar, P::Scalar>)
+                    .collect::<Vec<_>>(),
+                pa.unpack()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_check_m31x16() {
+        cross_check_field::<M31x16, PortableM31x16>();
+        cross_check_simd_field::<M31x16, PortableM31x16>();
+
+        // and with the scalars
+        let mut rng = test_rng();
+        for _ in 0..NUM_TESTS {
+            let a = M31x16::random_unsafe(&mut rng);
+            let s = M31::random_unsafe(&mut rng);
+            let pa = to_portable::<M31x16, PortableM31x16>(&a);
+            assert_eq!(to_portable::<M31x16, PortableM31x16>(&(a * s)), pa * s);
+            assert_eq!(to_portable::<M31x16, PortableM31x16>(&(a + s)), pa + s);
+        }
+    }
+
+    #[test]
+    fn test_cross_check_gf2_128() {
+        cross_check_field::<GF2_128, PortableGF2_128>();
+        cross_check_extension_field::<GF2_128, PortableGF2_128>();
+
+        let mut rng = test_rng();
+        for _ in 0..NUM_TESTS {
+            let a = GF2_128::random_unsafe(&mut rng);
+            assert_eq!(
+                a.inv().map(|x| to_portable::<GF2_128, PortableGF2_128>(&x)),
+                to_portable::<GF2_128, PortableGF2_128>(&a).inv()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cross_check_gf2_128x8() {
+        cross_check_field::<GF2_128x8, PortableGF2_128x8>();
+        cross_check_extension_field::<GF2_128x8, PortableGF2_128x8>();
+        cross_check_simd_field::<GF2_128x8, PortableGF2_128x8>();
+    }
+}
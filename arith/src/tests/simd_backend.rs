@@ -0,0 +1,152 @@
+use crate::{bn254_backend, gf2_128_backend, m31_backend, SimdBackend};
+
+#[test]
+fn test_backend_is_cached() {
+    assert_eq!(m31_backend(), m31_backend());
+    assert_eq!(gf2_128_backend(), gf2_128_backend());
+    assert_eq!(bn254_backend(), bn254_backend());
+    assert_ne!(bn254_backend(), SimdBackend::Neon);
+
+    #[cfg(any(
+        feature = "portable",
+        not(any(target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    assert_eq!(m31_backend(), SimdBackend::Portable);
+    #[cfg(all(target_arch = "aarch64", not(feature = "portable")))]
+    assert_eq!(m31_backend(), SimdBackend::Neon);
+    #[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+    assert_ne!(m31_backend(), SimdBackend::Neon);
+}
+
+// A build for the target features of the kernels selects them at compile time
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+#[test]
+fn test_static_backend() {
+    if cfg!(all(target_feature = "avx512f", not(feature = "avx256"))) {
+        assert_eq!(m31_backend(), SimdBackend::Avx512);
+        assert_eq!(bn254_backend(), SimdBackend::Avx512);
+    } else if cfg!(target_feature = "avx2") {
+        assert_eq!(m31_backend(), SimdBackend::Avx2);
+    }
+    if cfg!(target_feature = "pclmulqdq") {
+        assert_ne!(gf2_128_backend(), SimdBackend::Portable);
+    }
+}
+
+// Every kernel the host CPU supports must agree with the portable types,
+// not only the one selected at runtime.
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+mod kernels {
+    use ark_std::test_rng;
+
+    use crate::{
+        extension_field::gf2_128x8::dispatch::{self, DispatchGF2_128x8},
+        field::{
+            bn254::frx8,
+            m31::m31_dispatch::{avx2, avx512, DispatchM31x16},
+        },
+        Field, FieldSerde, Frx8, PortableGF2_128x8, PortableM31x16,
+    };
+
+    const NUM_TESTS: usize = 100;
+
+    fn convert<F: FieldSerde, P: FieldSerde>(x: &F) -> P {
+        let mut buffer = vec![];
+        x.serialize_into(&mut buffer).unwrap();
+        P::deserialize_from(buffer.as_slice()).unwrap()
+    }
+
+    type M31Kernel = unsafe fn(&DispatchM31x16, &DispatchM31x16) -> DispatchM31x16;
+
+    fn check_m31_kernels(add: M31Kernel, sub: M31Kernel, mul: M31Kernel) {
+        let mut rng = test_rng();
+        for _ in 0..NUM_TESTS {
+            let a = PortableM31x16::random_unsafe(&mut rng);
+            let b = PortableM31x16::random_unsafe(&mut rng);
+            let (da, db) = (convert(&a), convert(&b));
+
+            unsafe {
+                assert_eq!(convert::<_, PortableM31x16>(&add(&da, &db)), a + b);
+                assert_eq!(convert::<_, PortableM31x16>(&sub(&da, &db)), a - b);
+                assert_eq!(convert::<_, PortableM31x16>(&mul(&da, &db)), a * b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_m31_avx512_kernels() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        check_m31_kernels(avx512::add, avx512::sub, avx512::mul);
+
+        let mut rng = test_rng();
+        let a = PortableM31x16::random_unsafe(&mut rng);
+        let neg = unsafe { avx512::neg(&convert(&a)) };
+        assert_eq!(convert::<_, PortableM31x16>(&neg), -a);
+    }
+
+    #[test]
+    fn test_m31_avx2_kernels() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        check_m31_kernels(avx2::add, avx2::sub, avx2::mul);
+
+        let mut rng = test_rng();
+        let a = PortableM31x16::random_unsafe(&mut rng);
+        let neg = unsafe { avx2::neg(&convert(&a)) };
+        assert_eq!(convert::<_, PortableM31x16>(&neg), -a);
+    }
+
+    #[test]
+    fn test_gf2_128x8_avx512_kernel() {
+        if !(is_x86_feature_detected!("avx512f")
+            && is_x86_feature_detected!("avx512bw")
+            && is_x86_feature_detected!("vpclmulqdq"))
+        {
+            return;
+        }
+
+        let mut rng = test_rng();
+        for _ in 0..NUM_TESTS {
+            let a = PortableGF2_128x8::random_unsafe(&mut rng);
+            let b = PortableGF2_128x8::random_unsafe(&mut rng);
+            let (da, db): (DispatchGF2_128x8, DispatchGF2_128x8) = (convert(&a), convert(&b));
+            let prod = unsafe { dispatch::avx512::mul(&da, &db) };
+            assert_eq!(convert::<_, PortableGF2_128x8>(&prod), a * b);
+        }
+    }
+
+    fn check_frx8_kernel(mul: unsafe fn(&Frx8, &Frx8) -> Frx8) {
+        let mut rng = test_rng();
+        for _ in 0..NUM_TESTS {
+            let a = Frx8::random_unsafe(&mut rng);
+            let b = Frx8::random_unsafe(&mut rng);
+            let prod = unsafe { mul(&a, &b) };
+            for ((p, x), y) in prod.v.iter().zip(a.v).zip(b.v) {
+                assert_eq!(*p, x * y);
+            }
+        }
+
+        let minus_one = -Frx8::ONE;
+        assert_eq!(unsafe { mul(&minus_one, &minus_one) }, Frx8::ONE);
+        assert_eq!(unsafe { mul(&minus_one, &Frx8::ZERO) }, Frx8::ZERO);
+    }
+
+    #[test]
+    fn test_frx8_avx512_kernel() {
+        if !is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        check_frx8_kernel(frx8::avx512::mul);
+    }
+
+    #[test]
+    fn test_frx8_avx2_kernel() {
+        if !is_x86_feature_detected!("avx2") {
+            return;
+        }
+        check_frx8_kernel(frx8::avx2::mul);
+    }
+}
@@ -3,7 +3,7 @@
 use ark_std::log2;
 use halo2curves::{
     ff::{Field, PrimeField},
-    fft::best_fft,
+    fft::{best_fft, FftGroup},
 };
 
 #[inline]
@@ -211,3 +211,45 @@ pub(crate) fn bi_fft_in_place<F: PrimeField>(coeffs: &mut [F], degree_n: usize,
     // outer layer of FFT over variable y
     best_fft_vec_in_place(coeffs, omega_1, log2(degree_n), log2(degree_m));
 }
+
+/// Convert the powers of tau in the group to the lagrange bases using a two layer inverse FFT,
+/// i.e., g^{\tau_0^i \tau_1^j} at i + j * degree_n to g^{L_i(\tau_0) L_j(\tau_1)}
+/// over the roots of unity for degree_n and degree_m.
+pub(crate) fn bi_ifft_group_in_place<F: PrimeField, G: FftGroup<F>>(
+    bases: &mut [G],
+    degree_n: usize,
+    degree_m: usize,
+) {
+    assert_eq!(bases.len(), degree_n * degree_m);
+    assert!(degree_n.is_power_of_two());
+    assert!(degree_m.is_power_of_two());
+
+    // inverses of the roots of unity for supported_n and supported_m
+    let (omega_0_inv, omega_1_inv) = {
+        let omega = F::ROOT_OF_UNITY_INV;
+        let omega_0_inv = omega.pow_vartime([(1 << F::S) / degree_n as u64]);
+        let omega_1_inv = omega.pow_vartime([(1 << F::S) / degree_m as u64]);
+
+        (omega_0_inv, omega_1_inv)
+    };
+
+    // inner layer of inverse FFT over variable x
+    bases
+        .chunks_exact_mut(degree_n)
+        .for_each(|chunk| best_fft(chunk, omega_0_inv, log2(degree_n)));
+
+    // outer layer of inverse FFT over variable y, one column at a time
+    let mut column = Vec::with_capacity(degree_m);
+    for i in 0..degree_n {
+        column.clear();
+        column.extend((0..degree_m).map(|j| bases[i + j * degree_n]));
+        best_fft(&mut column, omega_1_inv, log2(degree_m));
+        column
+            .iter()
+            .enumerate()
+            .for_each(|(j, g)| bases[i + j * degree_n] = *g);
+    }
+
+    let size_inv = F::from((degree_n * degree_m) as u64).invert().unwrap();
+    bases.iter_mut().for_each(|g| *g = *g * size_inv);
+}
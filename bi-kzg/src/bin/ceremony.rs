@@ -0,0 +1,142 @@
+//! Run the Bi-KZG SRS ceremony as sequential local processes:
+//!
+//! - `init` writes the initial SRS, from scratch or from a powers-of-tau transcript;
+//! - `contribute`, run by each participant in turn, updates the SRS and appends a contribution;
+//! - `verify` checks the whole chain of contributions from the initial SRS to the final one.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufReader, BufWriter, Write},
+    process::exit,
+};
+
+use bi_kzg::{BiKZGContribution, BiKZGSRS};
+use clap::{Parser, Subcommand};
+use halo2curves::bn256::Bn256;
+use rand::rngs::OsRng;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about = "Bi-KZG SRS ceremony", long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Write the initial SRS for polynomials of degree `degree_0` in X and `degree_1` in Y
+    Init {
+        #[arg(long)]
+        degree_0: usize,
+        #[arg(long)]
+        degree_1: usize,
+        /// Take tau_0 from a powers-of-tau transcript in the .ptau format.
+        /// The importer samples tau_1, so another party must contribute before the SRS is used.
+        #[arg(long)]
+        ptau: Option<String>,
+        #[arg(long)]
+        output: String,
+    },
+    /// Update the SRS with fresh secrets, and append the contribution to the contribution file
+    Contribute {
+        #[arg(long)]
+        srs: String,
+        #[arg(long)]
+        output: String,
+        #[arg(long)]
+        contributions: String,
+    },
+    /// Verify the contributions from the initial SRS to the final one
+    Verify {
+        #[arg(long)]
+        initial: String,
+        #[arg(long)]
+        contributions: String,
+        #[arg(long)]
+        srs: String,
+    },
+}
+
+fn read_srs(path: &str) -> BiKZGSRS<Bn256> {
+    let file = File::open(path).expect("Unable to open the SRS file.");
+    BiKZGSRS::deserialize_from(BufReader::new(file)).expect("Unable to read the SRS.")
+}
+
+fn write_srs(srs: &BiKZGSRS<Bn256>, path: &str) {
+    let file = File::create(path).expect("Unable to create the SRS file.");
+    let mut writer = BufWriter::new(file);
+    srs.serialize_into(&mut writer)
+        .expect("Unable to write the SRS.");
+    writer.flush().expect("Unable to write the SRS.");
+}
+
+fn read_contributions(path: &str) -> Vec<BiKZGContribution<Bn256>> {
+    let bytes = fs::read(path).expect("Unable to read the contribution file.");
+    let mut reader = bytes.as_slice();
+    let mut contributions = vec![];
+    while !reader.is_empty() {
+        contributions.push(
+            BiKZGContribution::deserialize_from(&mut reader)
+                .expect("Unable to read the contributions."),
+        );
+    }
+    contributions
+}
+
+fn main() {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Init {
+            degree_0,
+            degree_1,
+            ptau,
+            output,
+        } => {
+            let srs = match ptau {
+                Some(ptau) => {
+                    let file = File::open(ptau).expect("Unable to open the ptau file.");
+                    BiKZGSRS::<Bn256>::from_powers_of_tau(
+                        BufReader::new(file),
+                        degree_0,
+                        degree_1,
+                        OsRng,
+                    )
+                    .expect("Unable to import the powers of tau.")
+                }
+                None => BiKZGSRS::<Bn256>::ceremony_start(degree_0, degree_1),
+            };
+            write_srs(&srs, &output);
+        }
+        Command::Contribute {
+            srs,
+            output,
+            contributions,
+        } => {
+            let (srs, contribution) = read_srs(&srs).contribute(OsRng);
+            write_srs(&srs, &output);
+
+            let mut buffer = vec![];
+            contribution.serialize_into(&mut buffer).unwrap();
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(contributions)
+                .and_then(|mut file| file.write_all(&buffer))
+                .expect("Unable to append the contribution.");
+        }
+        Command::Verify {
+            initial,
+            contributions,
+            srs,
+        } => {
+            let initial = read_srs(&initial);
+            let contributions = read_contributions(&contributions);
+            if !read_srs(&srs).verify_ceremony(&initial, &contributions, OsRng) {
+                println!("failure");
+                exit(1);
+            }
+            println!("success: {} contributions", contributions.len());
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! A multi-party ceremony for the Bi-KZG SRS.
+//!
+//! Starting from an SRS, e.g., `BiKZGSRS::ceremony_start` or one imported from powers of tau,
+//! each participant multiplies tau_0 and tau_1 by secrets s_0 and s_1 of their own,
+//! and publishes a `BiKZGContribution` proving the update.
+//! The final SRS is sound as long as one of the participants discarded their secrets.
+
+use std::io::{self, Read, Write};
+
+use ark_std::{end_timer, start_timer};
+use halo2curves::ff::Field;
+use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::group::{Curve, Group, GroupEncoding};
+use halo2curves::msm::best_multiexp;
+use halo2curves::pairing::MultiMillerLoop;
+use halo2curves::{CurveAffine, CurveExt};
+use rand::{CryptoRng, RngCore};
+
+use crate::srs::{read_point, write_point};
+use crate::util::{parallelize, powers_of_field_elements, tensor_product_parallel};
+use crate::{BiKZGContribution, BiKZGSRS};
+
+/// Domain of the hash to the curve for the proofs of knowledge.
+const CEREMONY_DOMAIN: &str = "Bi-KZG ceremony";
+
+impl<E: MultiMillerLoop> BiKZGContribution<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// The bases of the proofs of knowledge of s_0 and s_1,
+    /// hashed from g^{\tau_0} and g^{\tau_1} before and after the update and h^{s_0} and h^{s_1}.
+    fn knowledge_bases(
+        prev_tau_g: &(E::G1Affine, E::G1Affine),
+        tau_0_g: &E::G1Affine,
+        tau_1_g: &E::G1Affine,
+        s_0_h: &E::G2Affine,
+        s_1_h: &E::G2Affine,
+    ) -> (E::G1Affine, E::G1Affine) {
+        let mut message = vec![];
+        for g in [&prev_tau_g.0, &prev_tau_g.1, tau_0_g, tau_1_g] {
+            message.extend_from_slice(g.to_bytes().as_ref());
+        }
+        for h in [s_0_h, s_1_h] {
+            message.extend_from_slice(h.to_bytes().as_ref());
+        }
+
+        let hasher = <E::G1Affine as CurveAffine>::CurveExt::hash_to_curve(CEREMONY_DOMAIN);
+        let r_0 = hasher(&[message.as_slice(), &[0]].concat()).to_affine();
+        let r_1 = hasher(&[message.as_slice(), &[1]].concat()).to_affine();
+        (r_0, r_1)
+    }
+
+    /// Verify the update from the SRS with g^{\tau_0} and g^{\tau_1} in `prev_tau_g`,
+    /// with `h` the generator of G2 of the SRS.
+    pub fn verify(&self, prev_tau_g: &(E::G1Affine, E::G1Affine), h: &E::G2Affine) -> bool {
+        if bool::from(self.s_0_h.is_identity() | self.s_1_h.is_identity()) {
+            return false;
+        }
+
+        // tau_0 and tau_1 are multiplied by s_0 and s_1
+        if E::pairing(&self.tau_0_g, h) != E::pairing(&prev_tau_g.0, &self.s_0_h)
+            || E::pairing(&self.tau_1_g, h) != E::pairing(&prev_tau_g.1, &self.s_1_h)
+        {
+            return false;
+        }
+
+        // and the participant knows s_0 and s_1
+        let (r_0, r_1) = Self::knowledge_bases(
+            prev_tau_g,
+            &self.tau_0_g,
+            &self.tau_1_g,
+            &self.s_0_h,
+            &self.s_1_h,
+        );
+        E::pairing(&self.r_0_s_0, h) == E::pairing(&r_0, &self.s_0_h)
+            && E::pairing(&self.r_1_s_1, h) == E::pairing(&r_1, &self.s_1_h)
+    }
+
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write_point(&mut writer, &self.tau_0_g)?;
+        write_point(&mut writer, &self.tau_1_g)?;
+        write_point(&mut writer, &self.s_0_h)?;
+        write_point(&mut writer, &self.s_1_h)?;
+        write_point(&mut writer, &self.r_0_s_0)?;
+        write_point(&mut writer, &self.r_1_s_1)
+    }
+
+    pub fn deserialize_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        Ok(Self {
+            tau_0_g: read_point(&mut reader)?,
+            tau_1_g: read_point(&mut reader)?,
+            s_0_h: read_point(&mut reader)?,
+            s_1_h: read_point(&mut reader)?,
+            r_0_s_0: read_point(&mut reader)?,
+            r_1_s_1: read_point(&mut reader)?,
+        })
+    }
+}
+
+impl<E: MultiMillerLoop> BiKZGSRS<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// The SRS with tau_0 = tau_1 = 1, the starting point of a ceremony without powers of tau.
+    pub fn ceremony_start(supported_n: usize, supported_m: usize) -> Self {
+        assert!(supported_n.is_power_of_two() && supported_n > 1);
+        assert!(supported_m.is_power_of_two() && supported_m > 1);
+
+        let g = E::G1Affine::generator();
+        let h = E::G2Affine::generator();
+        Self::from_powers(
+            vec![g; supported_n * supported_m],
+            supported_n,
+            supported_m,
+            h,
+            h,
+            h,
+        )
+    }
+
+    /// g^{\tau_0} and g^{\tau_1}.
+    #[inline]
+    pub fn tau_g(&self) -> (E::G1Affine, E::G1Affine) {
+        (self.powers_of_g[1], self.powers_of_g[self.degree_0])
+    }
+
+    /// Multiply tau_0 and tau_1 by secrets sampled from `rng`,
+    /// return the updated SRS and the contribution proving the update.
+    /// The secrets are toxic waste, so `rng` must be cryptographically secure.
+    pub fn contribute(&self, mut rng: impl RngCore + CryptoRng) -> (Self, BiKZGContribution<E>) {
+        let timer = start_timer!(|| format!(
+            "Contributing to the SRS of degree {} {}",
+            self.degree_0, self.degree_1
+        ));
+        assert!(self.degree_0 > 1 && self.degree_1 > 1);
+
+        let s_0 = E::Fr::random(&mut rng);
+        let s_1 = E::Fr::random(&mut rng);
+
+        // g^{tau_0^i tau_1^j} to g^{(s_0 tau_0)^i (s_1 tau_1)^j}
+        let scalars = tensor_product_parallel(
+            &powers_of_field_elements(&s_0, self.degree_0),
+            &powers_of_field_elements(&s_1, self.degree_1),
+        );
+        let mut proj_bases = vec![E::G1::identity(); scalars.len()];
+        parallelize(&mut proj_bases, |g, start| {
+            for (idx, g) in g.iter_mut().enumerate() {
+                let offset = start + idx;
+                *g = self.powers_of_g[offset] * scalars[offset];
+            }
+        });
+        let mut powers_of_g = vec![E::G1Affine::identity(); proj_bases.len()];
+        parallelize(&mut powers_of_g, |g, starts| {
+            E::G1::batch_normalize(&proj_bases[starts..(starts + g.len())], g);
+        });
+        drop(proj_bases);
+
+        let srs = Self::from_powers(
+            powers_of_g,
+            self.degree_0,
+            self.degree_1,
+            self.h,
+            (self.tau_0_h * s_0).into(),
+            (self.tau_1_h * s_1).into(),
+        );
+
+        let (tau_0_g, tau_1_g) = srs.tau_g();
+        let s_0_h = (self.h * s_0).into();
+        let s_1_h = (self.h * s_1).into();
+        let (r_0, r_1) = BiKZGContribution::<E>::knowledge_bases(
+            &self.tau_g(),
+            &tau_0_g,
+            &tau_1_g,
+            &s_0_h,
+            &s_1_h,
+        );
+        let contribution = BiKZGContribution {
+            tau_0_g,
+            tau_1_g,
+            s_0_h,
+            s_1_h,
+            r_0_s_0: (r_0 * s_0).into(),
+            r_1_s_1: (r_1 * s_1).into(),
+        };
+        end_timer!(timer);
+
+        (srs, contribution)
+    }
+
+    /// Check that the SRS is well formed, i.e., it is the SRS for some tau_0 and tau_1:
+    /// g_1^{\tau_0^i\tau_1^j} is consistent along X and Y with tau_0_h and tau_1_h,
+    /// checked with random coefficients from `rng`, and the lagrange bases are the ones of the powers.
+    pub fn verify_structure(&self, mut rng: impl RngCore) -> bool {
+        let timer = start_timer!(|| format!(
+            "Verifying the SRS of degree {} {}",
+            self.degree_0, self.degree_1
+        ));
+        let (degree_0, degree_1) = (self.degree_0, self.degree_1);
+        if degree_0 < 2
+            || degree_1 < 2
+            || self.powers_of_g.len() != degree_0 * degree_1
+            || self.powers_of_g[0] != E::G1Affine::generator()
+            || self.h != E::G2Affine::generator()
+        {
+            return false;
+        }
+
+        let (tau_0_g, tau_1_g) = self.tau_g();
+        let g = self.powers_of_g[0];
+        if E::pairing(&tau_0_g, &self.h) != E::pairing(&g, &self.tau_0_h)
+            || E::pairing(&tau_1_g, &self.h) != E::pairing(&g, &self.tau_1_h)
+        {
+            return false;
+        }
+
+        // e(\sum c_{ij} g^{tau_0^i tau_1^j}, tau_0 h) = e(\sum c_{ij} g^{tau_0^{i+1} tau_1^j}, h)
+        let (lower, upper): (Vec<_>, Vec<_>) = (0..degree_1)
+            .flat_map(|j| (0..degree_0 - 1).map(move |i| (i + j * degree_0, i + 1 + j * degree_0)))
+            .map(|(k, k_next)| (self.powers_of_g[k], self.powers_of_g[k_next]))
+            .unzip();
+        if !self.consistent_powers(&lower, &upper, &self.tau_0_h, &mut rng) {
+            return false;
+        }
+        // and similarly along Y
+        let len = self.powers_of_g.len();
+        if !self.consistent_powers(
+            &self.powers_of_g[..len - degree_0],
+            &self.powers_of_g[degree_0..],
+            &self.tau_1_h,
+            &mut rng,
+        ) {
+            return false;
+        }
+
+        let expected = Self::from_powers(
+            self.powers_of_g.clone(),
+            degree_0,
+            degree_1,
+            self.h,
+            self.tau_0_h,
+            self.tau_1_h,
+        );
+        let res = expected.powers_of_g_lagrange_over_both_roots
+            == self.powers_of_g_lagrange_over_both_roots;
+        end_timer!(timer);
+        res
+    }
+
+    // e(\sum c_k lower_k, tau_h) = e(\sum c_k upper_k, h) for random c_k
+    fn consistent_powers(
+        &self,
+        lower: &[E::G1Affine],
+        upper: &[E::G1Affine],
+        tau_h: &E::G2Affine,
+        mut rng: impl RngCore,
+    ) -> bool {
+        let coeffs = (0..lower.len())
+            .map(|_| E::Fr::random(&mut rng))
+            .collect::<Vec<_>>();
+        let lhs = best_multiexp(&coeffs, lower).to_affine();
+        let rhs = best_multiexp(&coeffs, upper).to_affine();
+        E::pairing(&lhs, tau_h) == E::pairing(&rhs, &self.h)
+    }
+
+    /// Verify the ceremony from the SRS `initial` to this one through `contributions`.
+    /// `initial` is trusted to be well formed, e.g., `BiKZGSRS::ceremony_start`.
+    pub fn verify_ceremony(
+        &self,
+        initial: &Self,
+        contributions: &[BiKZGContribution<E>],
+        rng: impl RngCore,
+    ) -> bool {
+        if self.degree_0 != initial.degree_0
+            || self.degree_1 != initial.degree_1
+            || self.h != initial.h
+        {
+            return false;
+        }
+
+        let mut tau_g = initial.tau_g();
+        for contribution in contributions.iter() {
+            if !contribution.verify(&tau_g, &self.h) {
+                return false;
+            }
+            tau_g = (contribution.tau_0_g, contribution.tau_1_g);
+        }
+        self.tau_g() == tau_g && self.verify_structure(rng)
+    }
+}
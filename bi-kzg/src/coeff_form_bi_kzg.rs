@@ -6,11 +6,13 @@ use halo2curves::ff::PrimeField;
 use halo2curves::group::prime::PrimeCurveAffine;
 use halo2curves::group::Curve;
 use halo2curves::group::Group;
+use halo2curves::group::GroupEncoding;
 use halo2curves::msm::best_multiexp;
 use halo2curves::pairing::{MillerLoopResult, MultiMillerLoop};
 use halo2curves::CurveAffine;
 use itertools::Itertools;
 use rand::RngCore;
+use sha2::{Digest, Sha256};
 
 use crate::poly::{lagrange_coefficients, univariate_quotient};
 use crate::structs::BivariateLagrangePolynomial;
@@ -19,7 +21,7 @@ use crate::util::parallelize;
 use crate::{
     pcs::PolynomialCommitmentScheme,
     util::{powers_of_field_elements, tensor_product_parallel},
-    BiKZGCommitment, BiKZGProof, BiKZGSRS, BiKZGVerifierParam,
+    BiKZGBatchProof, BiKZGCommitment, BiKZGProof, BiKZGSRS, BiKZGVerifierParam,
 };
 
 /// Commit to the bi-variate polynomial in its coefficient form.
@@ -28,6 +30,84 @@ pub struct CoeffFormBiKZG<E: MultiMillerLoop> {
     _phantom: PhantomData<E>,
 }
 
+impl<E: MultiMillerLoop> CoeffFormBiKZG<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// The distinct points, in the order they first appear, with the indices of the openings
+    /// at each of them.
+    pub(crate) fn group_by_point(points: &[(E::Fr, E::Fr)]) -> Vec<((E::Fr, E::Fr), Vec<usize>)> {
+        let mut groups: Vec<((E::Fr, E::Fr), Vec<usize>)> = vec![];
+        for (i, point) in points.iter().enumerate() {
+            match groups.iter_mut().find(|(p, _)| p == point) {
+                Some((_, indices)) => indices.push(i),
+                None => groups.push((*point, vec![i])),
+            }
+        }
+        groups
+    }
+
+    /// The coefficients of the random linear combination of the openings at `indices`,
+    /// all at `point`, derived from their commitments and claimed evaluations.
+    pub(crate) fn point_challenges(
+        commitments: &[BiKZGCommitment<E>],
+        point: &(E::Fr, E::Fr),
+        evals: &[E::Fr],
+        indices: &[usize],
+    ) -> Vec<E::Fr> {
+        let mut hasher = Sha256::new();
+        hasher.update(point.0.to_repr());
+        hasher.update(point.1.to_repr());
+        for i in indices.iter() {
+            hasher.update(commitments[*i].com.to_bytes());
+            hasher.update(evals[*i].to_repr());
+        }
+        let digest = hasher.finalize();
+        let gamma = E::Fr::from_u128(u128::from_le_bytes(digest[..16].try_into().unwrap()));
+        powers_of_field_elements(&gamma, indices.len())
+    }
+
+    /// sum_i scalars[i] * vectors[i]
+    pub(crate) fn linear_combination<'a>(
+        vectors: impl Iterator<Item = &'a [E::Fr]>,
+        scalars: &[E::Fr],
+        len: usize,
+    ) -> Vec<E::Fr> {
+        let mut res = vec![E::Fr::ZERO; len];
+        for (vector, scalar) in vectors.zip_eq(scalars.iter()) {
+            res.iter_mut()
+                .zip_eq(vector.iter())
+                .for_each(|(r, v)| *r += *scalar * v);
+        }
+        res
+    }
+
+    /// The coefficient of the random linear combination in batch verification,
+    /// derived from everything the verifier is given.
+    fn batch_challenge(
+        commitments: &[BiKZGCommitment<E>],
+        points: &[(E::Fr, E::Fr)],
+        proofs: &[BiKZGProof<E>],
+        evals: &[E::Fr],
+    ) -> E::Fr {
+        let mut hasher = Sha256::new();
+        for ((commitment, point), (proof, eval)) in commitments
+            .iter()
+            .zip(points.iter())
+            .zip(proofs.iter().zip(evals.iter()))
+        {
+            hasher.update(commitment.com.to_bytes());
+            hasher.update(point.0.to_repr());
+            hasher.update(point.1.to_repr());
+            hasher.update(proof.pi0.to_bytes());
+            hasher.update(proof.pi1.to_bytes());
+            hasher.update(eval.to_repr());
+        }
+        let digest = hasher.finalize();
+        E::Fr::from_u128(u128::from_le_bytes(digest[..16].try_into().unwrap()))
+    }
+}
+
 impl<E: MultiMillerLoop> PolynomialCommitmentScheme for CoeffFormBiKZG<E>
 where
     E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
@@ -40,7 +120,7 @@ where
     type Proof = BiKZGProof<E>;
     type Evaluation = E::Fr;
     type Point = (E::Fr, E::Fr);
-    type BatchProof = Vec<Self::Proof>;
+    type BatchProof = BiKZGBatchProof<E>;
 
     fn gen_srs_for_testing(
         mut rng: impl RngCore,
@@ -124,6 +204,8 @@ where
         };
 
         BiKZGSRS {
+            degree_0: supported_n,
+            degree_1: supported_m,
             powers_of_g: coeff_bases,
             powers_of_g_lagrange_over_both_roots: lagrange_bases,
             h: E::G2Affine::generator(),
@@ -132,14 +214,6 @@ where
         }
     }
 
-    // fn trim(
-    //     srs: impl Borrow<Self::SRS>,
-    //     supported_degree: Option<usize>,
-    //     supported_num_vars: Option<usize>,
-    // ) -> (Self::ProverParam, Self::VerifierParam) {
-    //     unimplemented!()
-    // }
-
     fn commit(
         prover_param: impl Borrow<Self::ProverParam>,
         poly: &Self::Polynomial,
@@ -279,5 +353,137 @@ where
         res
     }
 
-    // TODO: implement multi-opening and batch verification
+    fn multi_open(
+        prover_param: impl Borrow<Self::ProverParam>,
+        polynomials: &[Self::Polynomial],
+        points: &[Self::Point],
+        evals: &[Self::Evaluation],
+    ) -> Self::BatchProof {
+        let timer = start_timer!(|| format!("Opening {} polynomials", polynomials.len()));
+        let prover_param = prover_param.borrow();
+        assert_eq!(polynomials.len(), points.len());
+        assert_eq!(polynomials.len(), evals.len());
+
+        // one proof per distinct point, for a random combination of the polynomials opened there
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| Self::commit(prover_param, polynomial))
+            .collect::<Vec<_>>();
+        let proofs = Self::group_by_point(points)
+            .iter()
+            .map(|(point, indices)| {
+                let (degree_0, degree_1) = (
+                    polynomials[indices[0]].degree_0,
+                    polynomials[indices[0]].degree_1,
+                );
+                let gammas = Self::point_challenges(&commitments, point, evals, indices);
+                let polynomial = BivariatePolynomial::new(
+                    Self::linear_combination(
+                        indices
+                            .iter()
+                            .map(|i| polynomials[*i].coefficients.as_slice()),
+                        &gammas,
+                        degree_0 * degree_1,
+                    ),
+                    degree_0,
+                    degree_1,
+                );
+                let (proof, u) = Self::open(prover_param, &polynomial, point);
+                let eval = gammas
+                    .iter()
+                    .zip(indices.iter())
+                    .map(|(g, i)| *g * evals[*i])
+                    .sum::<E::Fr>();
+                assert_eq!(u, eval, "wrong evaluation");
+                proof
+            })
+            .collect();
+        end_timer!(timer);
+
+        BiKZGBatchProof {
+            proofs,
+            evals: evals.to_vec(),
+        }
+    }
+
+    fn batch_verify(
+        verifier_param: &Self::VerifierParam,
+        commitments: &[Self::Commitment],
+        points: &[Self::Point],
+        batch_proof: &Self::BatchProof,
+    ) -> bool {
+        let timers = start_timer!(|| format!("Batch verifying {} openings", commitments.len()));
+        if points.len() != commitments.len() || batch_proof.evals.len() != commitments.len() {
+            return false;
+        }
+        let groups = Self::group_by_point(points);
+        if batch_proof.proofs.len() != groups.len() {
+            return false;
+        }
+
+        // one claim per distinct point, on the combination of the polynomials opened there
+        let (commitments, evals): (Vec<_>, Vec<_>) = groups
+            .iter()
+            .map(|(point, indices)| {
+                let gammas =
+                    Self::point_challenges(commitments, point, &batch_proof.evals, indices);
+                let coms = indices
+                    .iter()
+                    .map(|i| commitments[*i].com)
+                    .collect::<Vec<_>>();
+                let eval = gammas
+                    .iter()
+                    .zip(indices.iter())
+                    .map(|(g, i)| *g * batch_proof.evals[*i])
+                    .sum::<E::Fr>();
+                (
+                    BiKZGCommitment::<E> {
+                        com: best_multiexp(&gammas, &coms).to_affine(),
+                    },
+                    eval,
+                )
+            })
+            .unzip();
+        let points = groups.iter().map(|(point, _)| *point).collect::<Vec<_>>();
+        let num = groups.len();
+
+        // the checks e(pi0, tau_0 h) e(pi1, tau_1 h) e(-(a pi0 + b pi1 + C - u g), h) = 1
+        // combined with the powers of gamma
+        let gamma = Self::batch_challenge(&commitments, &points, &batch_proof.proofs, &evals);
+        let gammas = powers_of_field_elements(&gamma, num.max(1));
+
+        let pi0s = batch_proof.proofs.iter().map(|p| p.pi0).collect::<Vec<_>>();
+        let pi1s = batch_proof.proofs.iter().map(|p| p.pi1).collect::<Vec<_>>();
+        let combined_pi0 = best_multiexp(&gammas[..num], &pi0s).to_affine();
+        let combined_pi1 = best_multiexp(&gammas[..num], &pi1s).to_affine();
+
+        let combined_u = gammas
+            .iter()
+            .zip(evals.iter())
+            .map(|(g, u)| *g * u)
+            .sum::<E::Fr>();
+        let scalars = gammas
+            .iter()
+            .zip(points.iter())
+            .flat_map(|(g, point)| [*g * point.0, *g * point.1, *g])
+            .chain(std::iter::once(-combined_u))
+            .collect::<Vec<_>>();
+        let bases = pi0s
+            .iter()
+            .zip(pi1s.iter())
+            .zip(commitments.iter())
+            .flat_map(|((pi0, pi1), commitment)| [*pi0, *pi1, commitment.com])
+            .chain(std::iter::once(verifier_param.g))
+            .collect::<Vec<_>>();
+        let combined_rhs = (-best_multiexp(&scalars, &bases)).to_affine();
+
+        let res = E::multi_miller_loop(&[
+            (&combined_pi0, &verifier_param.tau_0_h.into()),
+            (&combined_pi1, &verifier_param.tau_1_h.into()),
+            (&combined_rhs, &verifier_param.h.into()),
+        ]);
+        let res = res.final_exponentiation().is_identity().into();
+        end_timer!(timers);
+        res
+    }
 }
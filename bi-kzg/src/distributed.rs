@@ -0,0 +1,284 @@
+//! Bi-KZG with the polynomial split across workers along Y.
+//!
+//! Each worker holds a block of rows of `f(X, Y) = \sum_j f_j(X) Y^j` and only gets the
+//! matching rows of the SRS, see `BiKZGSRS::row_block`. The commitment is the sum of the
+//! commitments to the blocks.
+//! For the opening at (a, b), each worker sends its rows folded at b, its part of pi0 and pi1,
+//! and the bases that carry the quotient of the later rows into its rows; the coordinator
+//! combines them with O(N) work per worker.
+//!
+//! Writing q_1(X, Y) = (f(X, Y) - f(X, b)) / (Y - b) = \sum_t Y^t \sum_{j > t} b^{j - 1 - t} f_j(X),
+//! the coefficient of Y^t for t in a block ending at row e is the sum over the rows j > t of the block,
+//! plus b^{e - 1 - t} R(X), with R(X) = \sum_{j >= e} b^{j - e} f_j(X) from the later blocks.
+
+use std::{
+    borrow::Borrow,
+    io::{self, Read, Write},
+    marker::PhantomData,
+};
+
+use ark_std::{end_timer, start_timer};
+use halo2curves::ff::Field;
+use halo2curves::group::{Curve, Group};
+use halo2curves::msm::best_multiexp;
+use halo2curves::pairing::MultiMillerLoop;
+use halo2curves::CurveAffine;
+use itertools::Itertools;
+
+use crate::poly::univariate_quotient;
+use crate::srs::{
+    invalid_data, read_point, read_points, read_scalar, read_u64, write_point, write_scalar,
+};
+use crate::util::powers_of_field_elements;
+use crate::{
+    BiKZGCommitment, BiKZGPartialProof, BiKZGProof, BiKZGRowBlockSRS, BivariatePolynomial,
+};
+
+/// Bi-KZG in the coefficient form, distributed across workers by blocks of rows.
+/// The commitments and proofs are the same as the ones of `CoeffFormBiKZG`.
+pub struct DistributedBiKZG<E: MultiMillerLoop> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: MultiMillerLoop> DistributedBiKZG<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// Split the polynomial into `num_workers` blocks of rows,
+    /// returned with the offset of their first row.
+    pub fn split_rows(
+        poly: &BivariatePolynomial<E::Fr>,
+        num_workers: usize,
+    ) -> Vec<(usize, BivariatePolynomial<E::Fr>)> {
+        assert!(num_workers > 0 && poly.degree_1 % num_workers == 0);
+        let num_rows = poly.degree_1 / num_workers;
+
+        poly.coefficients
+            .chunks_exact(poly.degree_0 * num_rows)
+            .enumerate()
+            .map(|(k, block)| {
+                (
+                    k * num_rows,
+                    BivariatePolynomial::new(block.to_vec(), poly.degree_0, num_rows),
+                )
+            })
+            .collect()
+    }
+
+    /// Commit to the rows of `block`, the rows of the polynomial covered by `block_param`.
+    pub fn worker_commit(
+        block_param: impl Borrow<BiKZGRowBlockSRS<E>>,
+        block: &BivariatePolynomial<E::Fr>,
+    ) -> BiKZGCommitment<E> {
+        let block_param = block_param.borrow();
+        assert_eq!(block.degree_0, block_param.degree_0);
+        assert_eq!(block.degree_1, block_param.num_rows);
+
+        let com = best_multiexp(&block.coefficients, &block_param.powers_of_g);
+
+        BiKZGCommitment { com: com.into() }
+    }
+
+    /// The commitment to the polynomial from the commitments to its blocks.
+    pub fn combine_commitments(commitments: &[BiKZGCommitment<E>]) -> BiKZGCommitment<E> {
+        let com = commitments
+            .iter()
+            .fold(E::G1::identity(), |acc, c| acc + c.com);
+
+        BiKZGCommitment { com: com.into() }
+    }
+
+    /// Partially open the rows of `block`, the rows of the polynomial covered by `block_param`,
+    /// at `point`.
+    pub fn worker_open(
+        block_param: impl Borrow<BiKZGRowBlockSRS<E>>,
+        block: &BivariatePolynomial<E::Fr>,
+        point: &(E::Fr, E::Fr),
+    ) -> BiKZGPartialProof<E> {
+        let block_param = block_param.borrow();
+        let row_offset = block_param.row_offset;
+        let timer = start_timer!(|| format!(
+            "Partially opening rows {}..{}",
+            row_offset,
+            row_offset + block.degree_1
+        ));
+        let degree_0 = block.degree_0;
+        let num_rows = block.degree_1;
+        assert_eq!(degree_0, block_param.degree_0);
+        assert_eq!(num_rows, block_param.num_rows);
+        let (a, b) = *point;
+        let block_bases = &block_param.powers_of_g;
+
+        // the rows folded at b, and its quotient by (X - a)
+        let folded_row = block.evaluate_at_y(&b);
+        let eval = folded_row
+            .iter()
+            .rev()
+            .fold(E::Fr::ZERO, |acc, c| acc * a + c);
+        let mut t = folded_row.clone();
+        t[0] -= eval;
+        let pi0 =
+            best_multiexp(&univariate_quotient(&t, &a), &block_param.powers_of_tau_0).to_affine();
+
+        // the coefficients of Y^t from the rows j > t of the block:
+        // q_t = f_{t+1} + b q_{t+1} with q_{num_rows-1} = 0
+        let mut q = vec![E::Fr::ZERO; block.coefficients.len()];
+        for t in (0..num_rows - 1).rev() {
+            let (q_t, q_next) = q.split_at_mut((t + 1) * degree_0);
+            q_t[t * degree_0..]
+                .iter_mut()
+                .zip_eq(q_next[..degree_0].iter())
+                .zip_eq(block.coefficients[(t + 1) * degree_0..(t + 2) * degree_0].iter())
+                .for_each(|((q, q_next), f)| *q = *f + b * q_next);
+        }
+        let pi1 = best_multiexp(&q, block_bases).to_affine();
+
+        // the bases for b^{num_rows - 1 - t} R(X) Y^t
+        let powers_of_b = powers_of_field_elements(&b, num_rows)
+            .into_iter()
+            .rev()
+            .collect::<Vec<_>>();
+        let carry_bases = (0..degree_0)
+            .map(|i| {
+                let column = block_bases
+                    .iter()
+                    .skip(i)
+                    .step_by(degree_0)
+                    .cloned()
+                    .collect::<Vec<_>>();
+                best_multiexp(&powers_of_b, &column).to_affine()
+            })
+            .collect();
+        end_timer!(timer);
+
+        BiKZGPartialProof {
+            row_offset,
+            num_rows,
+            folded_row,
+            eval,
+            pi0,
+            pi1,
+            carry_bases,
+        }
+    }
+
+    /// Combine the partial openings of all the blocks, in the order of the rows,
+    /// into the proof and the evaluation of the polynomial at `point`.
+    pub fn combine_openings(
+        partial_proofs: &[BiKZGPartialProof<E>],
+        point: &(E::Fr, E::Fr),
+    ) -> (BiKZGProof<E>, E::Fr) {
+        let timer = start_timer!(|| format!("Combining {} partial openings", partial_proofs.len()));
+        assert!(!partial_proofs.is_empty());
+        let b = point.1;
+        let degree_0 = partial_proofs[0].folded_row.len();
+
+        let mut row_offset = 0;
+        for partial_proof in partial_proofs.iter() {
+            assert_eq!(
+                partial_proof.row_offset, row_offset,
+                "the blocks are not contiguous"
+            );
+            assert_eq!(partial_proof.folded_row.len(), degree_0);
+            assert_eq!(partial_proof.carry_bases.len(), degree_0);
+            row_offset += partial_proof.num_rows;
+        }
+
+        // f(a, b) and pi0 are the sums over the blocks, scaled by b^{row_offset}
+        let block_scalars = partial_proofs
+            .iter()
+            .map(|p| b.pow_vartime([p.row_offset as u64]))
+            .collect::<Vec<_>>();
+        let eval = partial_proofs
+            .iter()
+            .zip(block_scalars.iter())
+            .map(|(p, s)| p.eval * s)
+            .sum::<E::Fr>();
+        let pi0s = partial_proofs.iter().map(|p| p.pi0).collect::<Vec<_>>();
+        let pi0 = best_multiexp(&block_scalars, &pi0s).to_affine();
+
+        // pi1 is the sum of the parts of the blocks, and of R(X) from the later blocks
+        // carried into each block, with R = folded_row + b^{num_rows} R over the later blocks
+        let mut scalars = vec![E::Fr::ONE; partial_proofs.len()];
+        let mut bases = partial_proofs.iter().map(|p| p.pi1).collect::<Vec<_>>();
+        let mut carry = vec![E::Fr::ZERO; degree_0];
+        for partial_proof in partial_proofs.iter().rev() {
+            scalars.extend_from_slice(&carry);
+            bases.extend_from_slice(&partial_proof.carry_bases);
+
+            let b_pow = b.pow_vartime([partial_proof.num_rows as u64]);
+            carry
+                .iter_mut()
+                .zip(partial_proof.folded_row.iter())
+                .for_each(|(r, f)| *r = *f + b_pow * *r);
+        }
+        let pi1 = best_multiexp(&scalars, &bases).to_affine();
+        end_timer!(timer);
+
+        (BiKZGProof { pi0, pi1 }, eval)
+    }
+}
+
+impl<E: MultiMillerLoop> BiKZGCommitment<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    pub fn serialize_into<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_point(writer, &self.com)
+    }
+
+    pub fn deserialize_from<R: Read>(reader: R) -> io::Result<Self> {
+        Ok(Self {
+            com: read_point(reader)?,
+        })
+    }
+}
+
+impl<E: MultiMillerLoop> BiKZGPartialProof<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// Serialize the partial proof with the points compressed.
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        assert_eq!(self.carry_bases.len(), self.folded_row.len());
+
+        writer.write_all(&(self.row_offset as u64).to_le_bytes())?;
+        writer.write_all(&(self.num_rows as u64).to_le_bytes())?;
+        writer.write_all(&(self.folded_row.len() as u64).to_le_bytes())?;
+        for c in self.folded_row.iter() {
+            write_scalar(&mut writer, c)?;
+        }
+        write_scalar(&mut writer, &self.eval)?;
+        write_point(&mut writer, &self.pi0)?;
+        write_point(&mut writer, &self.pi1)?;
+        for g in self.carry_bases.iter() {
+            write_point(&mut writer, g)?;
+        }
+        Ok(())
+    }
+
+    pub fn deserialize_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let row_offset = read_u64(&mut reader)? as usize;
+        let num_rows = read_u64(&mut reader)? as usize;
+        let degree_0 = read_u64(&mut reader)? as usize;
+        if num_rows == 0 || row_offset.checked_add(num_rows).is_none() {
+            return Err(invalid_data("invalid row block"));
+        }
+
+        // not preallocated, the length is not trusted
+        let mut folded_row = vec![];
+        for _ in 0..degree_0 {
+            folded_row.push(read_scalar(&mut reader)?);
+        }
+
+        Ok(Self {
+            row_offset,
+            num_rows,
+            folded_row,
+            eval: read_scalar(&mut reader)?,
+            pi0: read_point(&mut reader)?,
+            pi1: read_point(&mut reader)?,
+            carry_bases: read_points(&mut reader, degree_0)?,
+        })
+    }
+}
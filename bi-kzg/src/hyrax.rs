@@ -0,0 +1,158 @@
+use std::{borrow::Borrow, marker::PhantomData};
+
+use ark_std::{end_timer, start_timer};
+use halo2curves::ff::Field;
+use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::group::Curve;
+use halo2curves::msm::best_multiexp;
+use halo2curves::{CurveAffine, CurveExt};
+use rand::RngCore;
+
+use crate::util::eq_evals;
+use crate::{pcs::PolynomialCommitmentScheme, HyraxCommitment, HyraxProof, HyraxSRS};
+
+/// Domain of the hash to the curve that derives the generators.
+const HYRAX_DOMAIN: &str = "Hyrax generators";
+
+/// Hyrax, a transparent commitment to multilinear polynomials in their evaluation form.
+///
+/// The evaluations are laid out as a matrix, the first variables indexing the columns
+/// and the remaining ones the rows, with the first variable the least significant bit.
+/// Each row is committed with Pedersen over the generators of the SRS.
+/// Writing the evaluation at `z` as `L^T M R`, with `L` and `R` the evaluations of eq
+/// at the row and column variables of `z`, the proof is the combined row `L^T M`,
+/// which the verifier checks against the row commitments combined by `L`.
+pub struct Hyrax<C: CurveAffine> {
+    _phantom: PhantomData<C>,
+}
+
+impl<C: CurveAffine> Hyrax<C> {
+    /// The SRS for polynomials with `num_vars` variables, with the generators derived
+    /// from `seed` by hashing to the curve. Larger polynomials are supported with more rows.
+    pub fn setup(num_vars: usize, seed: &[u8]) -> HyraxSRS<C> {
+        let timer = start_timer!(|| format!("Hyrax setup for {} vars", num_vars));
+        let hasher = C::CurveExt::hash_to_curve(HYRAX_DOMAIN);
+        let proj_generators = (0..1u64 << ((num_vars + 1) / 2))
+            .map(|i| hasher(&[seed, &i.to_le_bytes()].concat()))
+            .collect::<Vec<_>>();
+
+        let mut generators = vec![C::identity(); proj_generators.len()];
+        C::CurveExt::batch_normalize(&proj_generators, &mut generators);
+        end_timer!(timer);
+        HyraxSRS { generators }
+    }
+}
+
+impl<C: CurveAffine> PolynomialCommitmentScheme for Hyrax<C> {
+    type SRS = HyraxSRS<C>;
+    type ProverParam = HyraxSRS<C>;
+    type VerifierParam = HyraxSRS<C>;
+    type Polynomial = Vec<C::Scalar>;
+    type Commitment = HyraxCommitment<C>;
+    type Proof = HyraxProof<C>;
+    type Evaluation = C::Scalar;
+    type Point = Vec<C::Scalar>;
+    type BatchProof = Vec<Self::Proof>;
+
+    /// The SRS supports polynomials with `supported_n * supported_m` evaluations
+    /// as a square matrix; the seed is sampled from `rng`.
+    fn gen_srs_for_testing(
+        mut rng: impl RngCore,
+        supported_n: usize,
+        supported_m: usize,
+    ) -> Self::SRS {
+        assert!(supported_n.is_power_of_two());
+        assert!(supported_m.is_power_of_two());
+        let num_vars = (supported_n * supported_m).trailing_zeros() as usize;
+
+        let mut seed = [0u8; 32];
+        rng.fill_bytes(&mut seed);
+        Self::setup(num_vars, &seed)
+    }
+
+    fn commit(
+        prover_param: impl Borrow<Self::ProverParam>,
+        poly: &Self::Polynomial,
+    ) -> Self::Commitment {
+        let timer = start_timer!(|| format!("Committing to multilinear polynomial {}", poly.len()));
+        let prover_param = prover_param.borrow();
+        assert!(poly.len().is_power_of_two());
+        let num_vars = poly.len().trailing_zeros() as usize;
+        let col_num = 1 << prover_param.col_var_num(num_vars);
+
+        let proj_row_comms = poly
+            .chunks(col_num)
+            .map(|row| best_multiexp(row, &prover_param.generators[..col_num]))
+            .collect::<Vec<_>>();
+        let mut row_comms = vec![C::identity(); proj_row_comms.len()];
+        C::CurveExt::batch_normalize(&proj_row_comms, &mut row_comms);
+        end_timer!(timer);
+
+        Self::Commitment { row_comms }
+    }
+
+    fn open(
+        prover_param: impl Borrow<Self::ProverParam>,
+        polynomial: &Self::Polynomial,
+        point: &Self::Point,
+    ) -> (Self::Proof, Self::Evaluation) {
+        let timer = start_timer!(|| format!("Opening multilinear polynomial {}", polynomial.len()));
+        let prover_param = prover_param.borrow();
+        assert_eq!(polynomial.len(), 1 << point.len());
+        let col_var_num = prover_param.col_var_num(point.len());
+
+        let row_eq = eq_evals(&point[col_var_num..]);
+        let col_eq = eq_evals(&point[..col_var_num]);
+        let mut combined_row = vec![C::Scalar::ZERO; col_eq.len()];
+        for (row, l) in polynomial.chunks(col_eq.len()).zip(row_eq.iter()) {
+            combined_row
+                .iter_mut()
+                .zip(row.iter())
+                .for_each(|(c, m)| *c += *m * l);
+        }
+        let eval = combined_row
+            .iter()
+            .zip(col_eq.iter())
+            .map(|(c, r)| *c * r)
+            .sum();
+
+        end_timer!(timer);
+        (Self::Proof { combined_row }, eval)
+    }
+
+    fn verify(
+        verifier_param: &Self::VerifierParam,
+        commitment: &Self::Commitment,
+        point: &Self::Point,
+        value: &Self::Evaluation,
+        proof: &Self::Proof,
+    ) -> bool {
+        let timer = start_timer!(|| "Verifying the proof");
+        let col_var_num = verifier_param.col_var_num(point.len());
+        if commitment.row_comms.len() != 1 << (point.len() - col_var_num)
+            || proof.combined_row.len() != 1 << col_var_num
+        {
+            return false;
+        }
+
+        // the combined row is committed to by the combined row commitments,
+        // and evaluates to the value at the column variables
+        let row_eq = eq_evals(&point[col_var_num..]);
+        let col_eq = eq_evals(&point[..col_var_num]);
+        let combined_comm = best_multiexp(&row_eq, &commitment.row_comms);
+        let combined_row_comm = best_multiexp(
+            &proof.combined_row,
+            &verifier_param.generators[..proof.combined_row.len()],
+        );
+        let eval = proof
+            .combined_row
+            .iter()
+            .zip(col_eq.iter())
+            .map(|(c, r)| *c * r)
+            .sum::<C::Scalar>();
+
+        let res = combined_comm == combined_row_comm && eval == *value;
+        end_timer!(timer);
+        res
+    }
+}
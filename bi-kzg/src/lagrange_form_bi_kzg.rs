@@ -1,30 +1,28 @@
-//! We don't need this file for now. We will use the `CoeffFormBiKZG`.
-
 use std::{borrow::Borrow, marker::PhantomData};
 
 use ark_std::{end_timer, start_timer};
-use halo2curves::ff::Field;
-use halo2curves::ff::PrimeField;
-use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::ff::BatchInvert;
 use halo2curves::group::Curve;
-use halo2curves::group::Group;
 use halo2curves::msm::best_multiexp;
-use halo2curves::pairing::{MillerLoopResult, MultiMillerLoop};
+use halo2curves::pairing::MultiMillerLoop;
 use halo2curves::CurveAffine;
 use itertools::Itertools;
 use rand::RngCore;
 
-use crate::poly::{lagrange_coefficients, univariate_quotient};
+use crate::poly::{lagrange_coefficients_over_roots_of_unity, root_of_unity};
 use crate::structs::BivariateLagrangePolynomial;
-use crate::util::parallelize;
+use crate::util::powers_of_field_elements;
 use crate::{
-    pcs::PolynomialCommitmentScheme,
-    util::{powers_of_field_elements, tensor_product_parallel},
-    BiKZGCommitment, BiKZGProof, BiKZGSRS, BiKZGVerifierParam,
+    pcs::PolynomialCommitmentScheme, BiKZGBatchProof, BiKZGCommitment, BiKZGProof, BiKZGSRS,
+    BiKZGVerifierParam, CoeffFormBiKZG,
 };
 
 /// Commit to the bi-variate polynomial in its lagrange form.
 /// this should be the preferred form for commitment.
+///
+/// The commitments and proofs are the same as the ones of `CoeffFormBiKZG` for the same polynomial,
+/// computed from the evaluations over the roots of unity without interpolation.
+/// The opening points must not be roots of unity.
 pub struct LagrangeFormBiKZG<E: MultiMillerLoop> {
     _phantom: PhantomData<E>,
 }
@@ -41,106 +39,12 @@ where
     type Proof = BiKZGProof<E>;
     type Evaluation = E::Fr;
     type Point = (E::Fr, E::Fr);
-    type BatchProof = Vec<Self::Proof>;
-
-    fn gen_srs_for_testing(
-        mut rng: impl RngCore,
-        supported_n: usize,
-        supported_m: usize,
-    ) -> Self::SRS {
-        assert!(supported_n.is_power_of_two());
-        assert!(supported_m.is_power_of_two());
-
-        let tau_0 = E::Fr::random(&mut rng);
-        let tau_1 = E::Fr::random(&mut rng);
-        let g1 = E::G1Affine::generator();
-
-        // roots of unity for supported_n and supported_m
-        let (omega_0, omega_1) = {
-            let omega = E::Fr::ROOT_OF_UNITY;
-            let omega_0 = omega.pow_vartime(&[(1 << E::Fr::S) / supported_n as u64]);
-            let omega_1 = omega.pow_vartime(&[(1 << E::Fr::S) / supported_m as u64]);
-
-            assert!(
-                omega_0.pow_vartime(&[supported_n as u64]) == E::Fr::ONE,
-                "omega_0 is not root of unity for supported_n"
-            );
-            assert!(
-                omega_1.pow_vartime(&[supported_m as u64]) == E::Fr::ONE,
-                "omega_1 is not root of unity for supported_m"
-            );
-            (omega_0, omega_1)
-        };
+    type BatchProof = BiKZGBatchProof<E>;
 
-        // computes the vector of L_i^N(tau_0) * L_j^M(tau_1) for i in 0..supported_n and j in 0..supported_m
-        let (scalars, lagrange_scalars) = {
-            let powers_of_omega_0 = powers_of_field_elements(&omega_0, supported_n);
-            let powers_of_tau_0 = powers_of_field_elements(&tau_0, supported_n);
-            let lagrange_tau_0 = lagrange_coefficients(&powers_of_omega_0, &tau_0);
-            let powers_of_omega_1 = powers_of_field_elements(&omega_1, supported_m);
-            let powers_of_tau_1 = powers_of_field_elements(&tau_1, supported_m);
-            let lagrange_tau_1 = lagrange_coefficients(&powers_of_omega_1, &tau_1);
-            let scalars = tensor_product_parallel(&powers_of_tau_0, &powers_of_tau_1);
-            let lagrange_scalars = tensor_product_parallel(&lagrange_tau_0, &lagrange_tau_1);
-
-            (scalars, lagrange_scalars)
-        };
-
-        let g1_prog = g1.to_curve();
-        let coeff_bases = {
-            let mut proj_bases = vec![E::G1::identity(); supported_n * supported_m];
-            parallelize(&mut proj_bases, |g, start| {
-                for (idx, g) in g.iter_mut().enumerate() {
-                    let offset = start + idx;
-                    *g = g1_prog * scalars[offset];
-                }
-            });
-
-            let mut g_bases = vec![E::G1Affine::identity(); supported_n * supported_m];
-            parallelize(&mut g_bases, |g, starts| {
-                E::G1::batch_normalize(&proj_bases[starts..(starts + g.len())], g);
-            });
-            drop(proj_bases);
-            g_bases
-        };
-
-        let lagrange_bases = {
-            let mut proj_bases = vec![E::G1::identity(); supported_n * supported_m];
-            parallelize(&mut proj_bases, |g, start| {
-                for (idx, g) in g.iter_mut().enumerate() {
-                    let offset = start + idx;
-                    *g = g1 * lagrange_scalars[offset];
-                }
-            });
-
-            let mut affine_bases = vec![E::G1Affine::identity(); supported_n * supported_m];
-            parallelize(&mut affine_bases, |affine_bases, starts| {
-                E::G1::batch_normalize(
-                    &proj_bases[starts..(starts + affine_bases.len())],
-                    affine_bases,
-                );
-            });
-            drop(proj_bases);
-            affine_bases
-        };
-
-        BiKZGSRS {
-            powers_of_g: coeff_bases,
-            powers_of_g_lagrange_over_both_roots: lagrange_bases,
-            h: E::G2Affine::generator(),
-            tau_0_h: (E::G2Affine::generator() * tau_0).into(),
-            tau_1_h: (E::G2Affine::generator() * tau_1).into(),
-        }
+    fn gen_srs_for_testing(rng: impl RngCore, supported_n: usize, supported_m: usize) -> Self::SRS {
+        CoeffFormBiKZG::<E>::gen_srs_for_testing(rng, supported_n, supported_m)
     }
 
-    // fn trim(
-    //     srs: impl Borrow<Self::SRS>,
-    //     supported_degree: Option<usize>,
-    //     supported_num_vars: Option<usize>,
-    // ) -> (Self::ProverParam, Self::VerifierParam) {
-    //     unimplemented!()
-    // }
-
     fn commit(
         prover_param: impl Borrow<Self::ProverParam>,
         poly: &Self::Polynomial,
@@ -169,72 +73,70 @@ where
         point: &Self::Point,
     ) -> (Self::Proof, Self::Evaluation) {
         let timer = start_timer!(|| format!(
-            "Opening polynomial of degree {} {}",
+            "Opening lagrange polynomial of degree {} {}",
             polynomial.degree_0, polynomial.degree_1
         ));
+        let prover_param = prover_param.borrow();
+        let degree_0 = polynomial.degree_0;
+        let degree_1 = polynomial.degree_1;
 
         let a = point.0;
         let b = point.1;
-        let u = polynomial.evaluate(&a, &b);
+        // f(x, b) over the roots of x, and u = f(a, b)
+        let f_x_b = polynomial.evaluate_at_y(&b);
+        let u = f_x_b
+            .iter()
+            .zip_eq(lagrange_coefficients_over_roots_of_unity(degree_0, &a).iter())
+            .map(|(f, l)| *f * l)
+            .sum::<E::Fr>();
 
         let timer2 = start_timer!(|| "Computing the proof pi0");
-        let (pi_0, f_x_b) = {
-            let f_x_b = polynomial.evaluate_y(&point.1);
-            let mut q_0_x_b = f_x_b.clone();
-            q_0_x_b[0] -= u;
-            let q_0_x_b = univariate_quotient(&q_0_x_b, &point.0);
+        let pi_0 = {
+            // q_0(x) = (f(x, b) - f(a, b)) / (x - a) over the roots of x
+            let mut x_minus_a_inv = powers_of_field_elements(&root_of_unity(degree_0), degree_0)
+                .iter()
+                .map(|omega_i| *omega_i - a)
+                .collect::<Vec<_>>();
+            x_minus_a_inv.iter_mut().batch_invert();
+            let q_0_x = f_x_b
+                .iter()
+                .zip_eq(x_minus_a_inv.iter())
+                .map(|(f, d)| (*f - u) * d)
+                .collect::<Vec<_>>();
 
-            let pi_0 = best_multiexp(
-                &q_0_x_b,
-                prover_param.borrow().powers_of_g[..polynomial.degree_0].as_ref(),
+            // constant in y, since the lagrange polynomials in y sum to one
+            let q_0_x_y = q_0_x.repeat(degree_1);
+            best_multiexp(
+                &q_0_x_y,
+                prover_param.powers_of_g_lagrange_over_both_roots.as_ref(),
             )
-            .to_affine();
-            (pi_0, f_x_b)
+            .to_affine()
         };
         end_timer!(timer2);
 
         let timer2 = start_timer!(|| "Computing the proof pi1");
         let pi_1 = {
-            let mut t = polynomial.clone();
-            t.coefficients
-                .iter_mut()
-                .take(polynomial.degree_0)
-                .zip_eq(f_x_b.iter())
-                .for_each(|(c, f)| *c -= f);
-            let coeffs = t.lagrange_coeffs();
-
-            let mut divisor = vec![E::Fr::from(0); polynomial.degree_0 * polynomial.degree_1];
-            divisor[0] = -point.1;
-            divisor[polynomial.degree_0] = E::Fr::ONE;
-            let divisor =
-                BivariatePolynomial::new(divisor, polynomial.degree_0, polynomial.degree_1);
-
-            let divisor = divisor.lagrange_coeffs();
-
-            // todo: batch invert
-            let y_minus_a_inv_lag = divisor
+            // q_1(x, y) = (f(x, y) - f(x, b)) / (y - b) over the roots of x and y
+            let mut y_minus_b_inv = powers_of_field_elements(&root_of_unity(degree_1), degree_1)
                 .iter()
-                .map(|o| {
-                    if o.is_zero_vartime() {
-                        panic!("not invertible")
-                    } else {
-                        o.invert().unwrap()
-                    }
-                })
+                .map(|omega_j| *omega_j - b)
                 .collect::<Vec<_>>();
-
-            let q_1_x_y = coeffs
-                .iter()
-                .zip_eq(y_minus_a_inv_lag.iter())
-                .map(|(c, y)| (*c) * *y)
+            y_minus_b_inv.iter_mut().batch_invert();
+            let q_1_x_y = polynomial
+                .coefficients
+                .chunks_exact(degree_0)
+                .zip_eq(y_minus_b_inv.iter())
+                .flat_map(|(chunk_j, d)| {
+                    chunk_j
+                        .iter()
+                        .zip_eq(f_x_b.iter())
+                        .map(move |(f, f_b)| (*f - f_b) * d)
+                })
                 .collect::<Vec<_>>();
 
             best_multiexp(
                 &q_1_x_y,
-                prover_param
-                    .borrow()
-                    .powers_of_g_lagrange_over_both_roots
-                    .as_ref(),
+                prover_param.powers_of_g_lagrange_over_both_roots.as_ref(),
             )
             .to_affine()
         };
@@ -248,36 +150,76 @@ where
         (proof, u)
     }
 
+    fn multi_open(
+        prover_param: impl Borrow<Self::ProverParam>,
+        polynomials: &[Self::Polynomial],
+        points: &[Self::Point],
+        evals: &[Self::Evaluation],
+    ) -> Self::BatchProof {
+        let prover_param = prover_param.borrow();
+        assert_eq!(polynomials.len(), points.len());
+        assert_eq!(polynomials.len(), evals.len());
+
+        // same as `CoeffFormBiKZG`, the combinations are taken over the lagrange bases
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| Self::commit(prover_param, polynomial))
+            .collect::<Vec<_>>();
+        let proofs = CoeffFormBiKZG::<E>::group_by_point(points)
+            .iter()
+            .map(|(point, indices)| {
+                let (degree_0, degree_1) = (
+                    polynomials[indices[0]].degree_0,
+                    polynomials[indices[0]].degree_1,
+                );
+                let gammas =
+                    CoeffFormBiKZG::<E>::point_challenges(&commitments, point, evals, indices);
+                let polynomial = BivariateLagrangePolynomial::new(
+                    CoeffFormBiKZG::<E>::linear_combination(
+                        indices
+                            .iter()
+                            .map(|i| polynomials[*i].coefficients.as_slice()),
+                        &gammas,
+                        degree_0 * degree_1,
+                    ),
+                    degree_0,
+                    degree_1,
+                );
+                let (proof, u) = Self::open(prover_param, &polynomial, point);
+                let eval = gammas
+                    .iter()
+                    .zip(indices.iter())
+                    .map(|(g, i)| *g * evals[*i])
+                    .sum::<E::Fr>();
+                assert_eq!(u, eval, "wrong evaluation");
+                proof
+            })
+            .collect();
+
+        BiKZGBatchProof {
+            proofs,
+            evals: evals.to_vec(),
+        }
+    }
+
+    /// Same as `CoeffFormBiKZG`.
     fn verify(
         verifier_param: &Self::VerifierParam,
         commitment: &Self::Commitment,
         point: &Self::Point,
         value: &Self::Evaluation,
         proof: &Self::Proof,
-    ) -> bool
-    where
-        E: MultiMillerLoop,
-    {
-        let pi0_a_pi1_b_g1_cmu = best_multiexp(
-            &[point.0, point.1, E::Fr::ONE, -*value],
-            &[
-                proof.pi0,
-                proof.pi1,
-                commitment.com.into(),
-                verifier_param.g.into(),
-            ],
-        );
-        let pi0_a_pi1_b_g1_cmu = (-pi0_a_pi1_b_g1_cmu).to_affine();
-        let res = E::multi_miller_loop(&[
-            (&proof.pi0, &verifier_param.tau_0_h.into()),
-            (&proof.pi1, &verifier_param.tau_1_h.into()),
-            (&pi0_a_pi1_b_g1_cmu, &verifier_param.h.into()),
-        ]);
-        let res = res.final_exponentiation().is_identity().into();
-
-        res
+    ) -> bool {
+        CoeffFormBiKZG::<E>::verify(verifier_param, commitment, point, value, proof)
     }
 
-    
-    // TODO: implement multi-opening and batch verification
+    /// Same as `CoeffFormBiKZG`.
+    fn batch_verify(
+        verifier_param: &Self::VerifierParam,
+        commitments: &[Self::Commitment],
+        points: &[Self::Point],
+        batch_proof: &Self::BatchProof,
+    ) -> bool {
+        CoeffFormBiKZG::<E>::batch_verify(verifier_param, commitments, points, batch_proof)
+    }
 }
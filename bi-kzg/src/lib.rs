@@ -1,18 +1,31 @@
 mod bi_fft;
+mod ceremony;
 mod coeff_form_bi_kzg;
+mod distributed;
+mod hyrax;
+mod lagrange_form_bi_kzg;
+mod multilinear_kzg;
 mod pcs;
 mod poly;
+mod srs;
 mod structs;
 mod util;
 
-// mod lagrange_form_bi_kzg;
-
 #[cfg(test)]
 mod tests;
 
 pub use coeff_form_bi_kzg::CoeffFormBiKZG;
+pub use distributed::DistributedBiKZG;
+pub use hyrax::Hyrax;
+pub use lagrange_form_bi_kzg::LagrangeFormBiKZG;
+pub use multilinear_kzg::MultilinearKZG;
 pub use pcs::PolynomialCommitmentScheme;
-pub use structs::BivariatePolynomial;
-pub use structs::{BiKZGCommitment, BiKZGProof, BiKZGSRS, BiKZGVerifierParam};
-
-// pub use lagrange_form_bi_kzg::LagrangeFormBiKZG;
+pub use structs::{
+    BiKZGBatchProof, BiKZGCommitment, BiKZGContribution, BiKZGPartialProof, BiKZGProof,
+    BiKZGRowBlockSRS, BiKZGSRS, BiKZGVerifierParam,
+};
+pub use structs::{BivariateLagrangePolynomial, BivariatePolynomial};
+pub use structs::{HyraxCommitment, HyraxProof, HyraxSRS};
+pub use structs::{
+    MultilinearKZGCommitment, MultilinearKZGProof, MultilinearKZGSRS, MultilinearKZGVerifierParam,
+};
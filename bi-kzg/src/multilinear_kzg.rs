@@ -0,0 +1,184 @@
+use std::{borrow::Borrow, marker::PhantomData};
+
+use ark_std::{end_timer, start_timer};
+use halo2curves::ff::Field;
+use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::group::Curve;
+use halo2curves::group::Group;
+use halo2curves::msm::best_multiexp;
+use halo2curves::pairing::{MillerLoopResult, MultiMillerLoop};
+use halo2curves::CurveAffine;
+use rand::RngCore;
+
+use crate::util::{eq_evals, parallelize};
+use crate::{
+    pcs::PolynomialCommitmentScheme, MultilinearKZGCommitment, MultilinearKZGProof,
+    MultilinearKZGSRS, MultilinearKZGVerifierParam,
+};
+
+/// Multilinear KZG (PST13), committing to the polynomial in its evaluation form.
+///
+/// The polynomial is given by its evaluations over the boolean hypercube,
+/// where the first variable is the least significant bit of the index.
+/// A polynomial with fewer variables than the SRS is committed over the last variables of the SRS.
+pub struct MultilinearKZG<E: MultiMillerLoop> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: MultiMillerLoop> PolynomialCommitmentScheme for MultilinearKZG<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    type SRS = MultilinearKZGSRS<E>;
+    type ProverParam = MultilinearKZGSRS<E>;
+    type VerifierParam = MultilinearKZGVerifierParam<E>;
+    type Polynomial = Vec<E::Fr>;
+    type Commitment = MultilinearKZGCommitment<E>;
+    type Proof = MultilinearKZGProof<E>;
+    type Evaluation = E::Fr;
+    type Point = Vec<E::Fr>;
+    type BatchProof = Vec<Self::Proof>;
+
+    /// The SRS supports polynomials with up to `supported_n * supported_m` evaluations.
+    fn gen_srs_for_testing(
+        mut rng: impl RngCore,
+        supported_n: usize,
+        supported_m: usize,
+    ) -> Self::SRS {
+        assert!(supported_n.is_power_of_two());
+        assert!(supported_m.is_power_of_two());
+        let num_vars = (supported_n * supported_m).trailing_zeros() as usize;
+
+        let tau = (0..num_vars)
+            .map(|_| E::Fr::random(&mut rng))
+            .collect::<Vec<_>>();
+        let g1 = E::G1Affine::generator();
+        let g1_prog = g1.to_curve();
+
+        // g_1^{eq(tau_{i..n}, b)} for every suffix of the variables
+        let lagrange_bases = (0..=num_vars)
+            .map(|i| {
+                let scalars = eq_evals(&tau[i..]);
+
+                let mut proj_bases = vec![E::G1::identity(); scalars.len()];
+                parallelize(&mut proj_bases, |g, start| {
+                    for (idx, g) in g.iter_mut().enumerate() {
+                        let offset = start + idx;
+                        *g = g1_prog * scalars[offset];
+                    }
+                });
+
+                let mut affine_bases = vec![E::G1Affine::identity(); scalars.len()];
+                parallelize(&mut affine_bases, |affine_bases, starts| {
+                    E::G1::batch_normalize(
+                        &proj_bases[starts..(starts + affine_bases.len())],
+                        affine_bases,
+                    );
+                });
+                drop(proj_bases);
+                affine_bases
+            })
+            .collect();
+
+        MultilinearKZGSRS {
+            lagrange_bases,
+            h: E::G2Affine::generator(),
+            tau_h: tau
+                .iter()
+                .map(|t| (E::G2Affine::generator() * t).into())
+                .collect(),
+        }
+    }
+
+    fn commit(
+        prover_param: impl Borrow<Self::ProverParam>,
+        poly: &Self::Polynomial,
+    ) -> Self::Commitment {
+        let timer = start_timer!(|| format!("Committing to multilinear polynomial {}", poly.len()));
+        let prover_param = prover_param.borrow();
+        assert!(poly.len().is_power_of_two());
+        let offset = prover_param.num_vars() - poly.len().trailing_zeros() as usize;
+
+        let com = best_multiexp(poly, &prover_param.lagrange_bases[offset]);
+        end_timer!(timer);
+
+        Self::Commitment { com: com.into() }
+    }
+
+    fn open(
+        prover_param: impl Borrow<Self::ProverParam>,
+        polynomial: &Self::Polynomial,
+        point: &Self::Point,
+    ) -> (Self::Proof, Self::Evaluation) {
+        let timer = start_timer!(|| format!("Opening multilinear polynomial {}", polynomial.len()));
+        let prover_param = prover_param.borrow();
+        assert_eq!(polynomial.len(), 1 << point.len());
+        let offset = prover_param.num_vars() - point.len();
+
+        // f(X) - f(z) = \sum_i (X_i - z_i) q_i(X_{i+1}, ..., X_n),
+        // with q_i the difference of f(z_0, ..., z_{i-1}, X_i, ...) at X_i = 1 and X_i = 0
+        let mut f = polynomial.clone();
+        let mut pi = vec![];
+        for (i, z_i) in point.iter().enumerate() {
+            let half = f.len() >> 1;
+            let q_i = (0..half)
+                .map(|j| f[2 * j + 1] - f[2 * j])
+                .collect::<Vec<_>>();
+            f = (0..half)
+                .map(|j| f[2 * j] + q_i[j] * z_i)
+                .collect::<Vec<_>>();
+
+            let timer2 = start_timer!(|| format!("Computing the msm for size {}", q_i.len()));
+            pi.push(best_multiexp(&q_i, &prover_param.lagrange_bases[offset + i + 1]).to_affine());
+            end_timer!(timer2);
+        }
+
+        end_timer!(timer);
+        (Self::Proof { pi }, f[0])
+    }
+
+    fn verify(
+        verifier_param: &Self::VerifierParam,
+        commitment: &Self::Commitment,
+        point: &Self::Point,
+        value: &Self::Evaluation,
+        proof: &Self::Proof,
+    ) -> bool
+    where
+        E: MultiMillerLoop,
+    {
+        let timers = start_timer!(|| "Verifying the proof");
+        if point.len() > verifier_param.tau_h.len() || proof.pi.len() != point.len() {
+            return false;
+        }
+        let offset = verifier_param.tau_h.len() - point.len();
+
+        // e(C - v g + \sum_i z_i pi_i, h) = \prod_i e(pi_i, tau_i h)
+        let scalars = [E::Fr::ONE, -*value]
+            .into_iter()
+            .chain(point.iter().cloned())
+            .collect::<Vec<_>>();
+        let bases = [commitment.com, verifier_param.g]
+            .into_iter()
+            .chain(proof.pi.iter().cloned())
+            .collect::<Vec<_>>();
+        let c_v_pi = best_multiexp(&scalars, &bases).to_affine();
+        let neg_pi = proof.pi.iter().map(|pi| -*pi).collect::<Vec<_>>();
+
+        let h_prepared = E::G2Prepared::from(verifier_param.h);
+        let tau_h_prepared = verifier_param.tau_h[offset..]
+            .iter()
+            .map(|tau_h| E::G2Prepared::from(*tau_h))
+            .collect::<Vec<_>>();
+        let terms = std::iter::once((&c_v_pi, &h_prepared))
+            .chain(neg_pi.iter().zip(tau_h_prepared.iter()))
+            .collect::<Vec<_>>();
+
+        let res = E::multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity()
+            .into();
+        end_timer!(timers);
+        res
+    }
+}
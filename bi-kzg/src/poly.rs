@@ -1,5 +1,5 @@
 use ark_std::{end_timer, start_timer};
-use halo2curves::ff::{Field, PrimeField};
+use halo2curves::ff::{BatchInvert, Field, PrimeField};
 use itertools::Itertools;
 use rand::RngCore;
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -132,6 +132,37 @@ pub(crate) fn lagrange_coefficients<F: Field + Send + Sync>(roots: &[F], points:
         .collect()
 }
 
+/// The primitive n-th root of unity, for n a power of two.
+pub(crate) fn root_of_unity<F: PrimeField>(n: usize) -> F {
+    F::ROOT_OF_UNITY.pow_vartime([(1 << F::S) / n as u64])
+}
+
+/// The coefficients of the Lagrange polynomials over the n-th roots of unity at a point
+/// outside of them, with the barycentric formula
+/// `L_{i}(x) = \frac{\omega^i (x^n - 1)}{n (x - \omega^i)}`
+pub(crate) fn lagrange_coefficients_over_roots_of_unity<F: PrimeField>(n: usize, x: &F) -> Vec<F> {
+    if n == 1 {
+        return vec![F::ONE];
+    }
+    let powers_of_omega = powers_of_field_elements(&root_of_unity::<F>(n), n);
+    let mut denominators = powers_of_omega
+        .iter()
+        .map(|omega_i| (*x - omega_i) * F::from(n as u64))
+        .collect::<Vec<_>>();
+    assert!(
+        denominators.iter().all(|d| !bool::from(d.is_zero())),
+        "the point is a root of unity"
+    );
+    denominators.iter_mut().batch_invert();
+
+    let x_n_minus_one = x.pow_vartime([n as u64]) - F::ONE;
+    powers_of_omega
+        .iter()
+        .zip(denominators.iter())
+        .map(|(omega_i, d)| *omega_i * x_n_minus_one * d)
+        .collect()
+}
+
 /// Compute poly / (x-point) using univariate division
 pub(crate) fn univariate_quotient<F: PrimeField>(poly: &[F], point: &F) -> Vec<F> {
     let timer = start_timer!(|| format!("Univariate quotient of degree {}", poly.len()));
@@ -165,7 +196,9 @@ pub(crate) fn univariate_quotient<F: PrimeField>(poly: &[F], point: &F) -> Vec<F
 }
 
 impl<F: Field> BivariateLagrangePolynomial<F> {
-    fn new(coeffs: Vec<F>, degree_0: usize, degree_1: usize) -> Self {
+    /// The evaluations at (omega_0^i, omega_1^j) are at coeffs[i + j * degree_0].
+    #[inline]
+    pub fn new(coeffs: Vec<F>, degree_0: usize, degree_1: usize) -> Self {
         assert_eq!(coeffs.len(), degree_0 * degree_1);
         Self {
             coefficients: coeffs,
@@ -173,6 +206,13 @@ impl<F: Field> BivariateLagrangePolynomial<F> {
             degree_1,
         }
     }
+
+    pub fn random(mut rng: impl RngCore, degree_0: usize, degree_1: usize) -> Self {
+        let coeffs = (0..degree_0 * degree_1)
+            .map(|_| F::random(&mut rng))
+            .collect();
+        Self::new(coeffs, degree_0, degree_1)
+    }
 }
 
 impl<F: PrimeField> From<BivariatePolynomial<F>> for BivariateLagrangePolynomial<F> {
@@ -189,6 +229,32 @@ impl<F: PrimeField> From<&BivariatePolynomial<F>> for BivariateLagrangePolynomia
 }
 
 impl<F: PrimeField> BivariateLagrangePolynomial<F> {
+    /// evaluate the polynomial at (x, y), neither of them a root of unity
+    pub fn evaluate(&self, x: &F, y: &F) -> F {
+        self.evaluate_at_y(y)
+            .iter()
+            .zip(lagrange_coefficients_over_roots_of_unity(self.degree_0, x).iter())
+            .map(|(f, l)| *f * l)
+            .sum()
+    }
+
+    /// evaluate the polynomial at y, not a root of unity,
+    /// return a univariate polynomial in x in its lagrange form
+    pub fn evaluate_at_y(&self, y: &F) -> Vec<F> {
+        let lagrange_y = lagrange_coefficients_over_roots_of_unity(self.degree_1, y);
+        let mut f_x_b = vec![F::ZERO; self.degree_0];
+        lagrange_y
+            .iter()
+            .zip_eq(self.coefficients.chunks_exact(self.degree_0))
+            .for_each(|(l_j, chunk_j)| {
+                f_x_b
+                    .iter_mut()
+                    .zip(chunk_j.iter())
+                    .for_each(|(f, c)| *f += *c * l_j)
+            });
+        f_x_b
+    }
+
     /// construct a bivariate lagrange polynomial from a monomial f(y) = y - b
     pub(crate) fn from_y_monomial(b: &F, n: usize, m: usize) -> Self {
         // roots of unity for supported_n and supported_m
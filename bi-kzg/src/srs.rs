@@ -0,0 +1,401 @@
+//! Persistence, trimming, and import of the Bi-KZG SRS.
+
+use std::io::{self, Read, Write};
+
+use ark_std::{end_timer, start_timer};
+use halo2curves::bn256::{Bn256, Fq, Fq2, Fr, G1Affine, G2Affine, G1};
+use halo2curves::ff::{Field, PrimeField};
+use halo2curves::group::prime::PrimeCurveAffine;
+use halo2curves::group::{Curve, Group, GroupEncoding};
+use halo2curves::msm::best_multiexp;
+use halo2curves::pairing::{Engine, MultiMillerLoop};
+use halo2curves::CurveAffine;
+use rand::{CryptoRng, RngCore};
+
+use crate::bi_fft::bi_ifft_group_in_place;
+use crate::util::{parallelize, powers_of_field_elements};
+use crate::{BiKZGRowBlockSRS, BiKZGSRS, BiKZGVerifierParam};
+
+/// Magic bytes of a powers-of-tau transcript.
+const PTAU_MAGIC: &[u8; 4] = b"ptau";
+const PTAU_HEADER_SECTION: u32 = 1;
+const PTAU_TAU_G1_SECTION: u32 = 2;
+const PTAU_TAU_G2_SECTION: u32 = 3;
+
+pub(crate) fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn read_u32<R: Read>(mut reader: R) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+pub(crate) fn read_u64<R: Read>(mut reader: R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Write a point in its compressed form.
+pub(crate) fn write_point<W: Write, G: GroupEncoding>(mut writer: W, point: &G) -> io::Result<()> {
+    writer.write_all(point.to_bytes().as_ref())
+}
+
+/// Read a compressed point, checking that it is in the subgroup.
+pub(crate) fn read_point<R: Read, G: GroupEncoding>(mut reader: R) -> io::Result<G> {
+    let mut repr = G::Repr::default();
+    reader.read_exact(repr.as_mut())?;
+    G::from_bytes(&repr)
+        .into_option()
+        .ok_or_else(|| invalid_data("invalid point"))
+}
+
+pub(crate) fn read_points<R: Read, G: GroupEncoding>(
+    mut reader: R,
+    num: usize,
+) -> io::Result<Vec<G>> {
+    // not preallocated, the length is not trusted
+    let mut points = vec![];
+    for _ in 0..num {
+        points.push(read_point(&mut reader)?);
+    }
+    Ok(points)
+}
+
+/// Write a scalar in its canonical form.
+pub(crate) fn write_scalar<W: Write, F: PrimeField>(mut writer: W, x: &F) -> io::Result<()> {
+    writer.write_all(x.to_repr().as_ref())
+}
+
+/// Read a scalar, checking that it is canonical.
+pub(crate) fn read_scalar<R: Read, F: PrimeField>(mut reader: R) -> io::Result<F> {
+    let mut repr = F::Repr::default();
+    reader.read_exact(repr.as_mut())?;
+    F::from_repr(repr)
+        .into_option()
+        .ok_or_else(|| invalid_data("invalid scalar"))
+}
+
+impl<E: MultiMillerLoop> BiKZGSRS<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// Build the SRS from g_1^{\tau_0^i\tau_1^j} in the layout of `powers_of_g`,
+    /// the lagrange bases are derived from them with an inverse FFT in the group.
+    pub fn from_powers(
+        powers_of_g: Vec<E::G1Affine>,
+        degree_0: usize,
+        degree_1: usize,
+        h: E::G2Affine,
+        tau_0_h: E::G2Affine,
+        tau_1_h: E::G2Affine,
+    ) -> Self {
+        let timer = start_timer!(|| format!("Lagrange bases of degree {} {}", degree_0, degree_1));
+        assert_eq!(powers_of_g.len(), degree_0 * degree_1);
+
+        let mut proj_bases = powers_of_g.iter().map(|g| g.to_curve()).collect::<Vec<_>>();
+        bi_ifft_group_in_place::<E::Fr, _>(&mut proj_bases, degree_0, degree_1);
+
+        let mut lagrange_bases = vec![E::G1Affine::identity(); proj_bases.len()];
+        parallelize(&mut lagrange_bases, |g, starts| {
+            E::G1::batch_normalize(&proj_bases[starts..(starts + g.len())], g);
+        });
+        end_timer!(timer);
+
+        Self {
+            degree_0,
+            degree_1,
+            powers_of_g,
+            powers_of_g_lagrange_over_both_roots: lagrange_bases,
+            h,
+            tau_0_h,
+            tau_1_h,
+        }
+    }
+
+    /// Derive the prover and verifier parameters for polynomials of smaller degrees
+    /// `supported_n` in X and `supported_m` in Y.
+    pub fn trim(
+        &self,
+        supported_n: usize,
+        supported_m: usize,
+    ) -> (BiKZGSRS<E>, BiKZGVerifierParam<E>) {
+        assert!(supported_n.is_power_of_two() && supported_n <= self.degree_0);
+        assert!(supported_m.is_power_of_two() && supported_m <= self.degree_1);
+
+        let powers_of_g = self
+            .powers_of_g
+            .chunks_exact(self.degree_0)
+            .take(supported_m)
+            .flat_map(|row| row[..supported_n].iter().cloned())
+            .collect();
+        let srs = Self::from_powers(
+            powers_of_g,
+            supported_n,
+            supported_m,
+            self.h,
+            self.tau_0_h,
+            self.tau_1_h,
+        );
+        let vk = BiKZGVerifierParam::from(&srs);
+        (srs, vk)
+    }
+
+    /// The rows `row_offset..row_offset + num_rows` in Y, what a distributed worker needs
+    /// for its block of rows of the polynomial.
+    pub fn row_block(&self, row_offset: usize, num_rows: usize) -> BiKZGRowBlockSRS<E> {
+        assert!(num_rows > 0 && row_offset + num_rows <= self.degree_1);
+
+        BiKZGRowBlockSRS {
+            degree_0: self.degree_0,
+            row_offset,
+            num_rows,
+            powers_of_g: self.powers_of_g
+                [row_offset * self.degree_0..(row_offset + num_rows) * self.degree_0]
+                .to_vec(),
+            powers_of_tau_0: self.powers_of_g[..self.degree_0].to_vec(),
+        }
+    }
+
+    /// Serialize the SRS with the points compressed.
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&(self.degree_0 as u64).to_le_bytes())?;
+        writer.write_all(&(self.degree_1 as u64).to_le_bytes())?;
+        for g in self
+            .powers_of_g
+            .iter()
+            .chain(self.powers_of_g_lagrange_over_both_roots.iter())
+        {
+            write_point(&mut writer, g)?;
+        }
+        write_point(&mut writer, &self.h)?;
+        write_point(&mut writer, &self.tau_0_h)?;
+        write_point(&mut writer, &self.tau_1_h)
+    }
+
+    pub fn deserialize_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let degree_0 = read_u64(&mut reader)? as usize;
+        let degree_1 = read_u64(&mut reader)? as usize;
+        if !degree_0.is_power_of_two() || !degree_1.is_power_of_two() {
+            return Err(invalid_data("the degrees are not powers of two"));
+        }
+        let size = degree_0
+            .checked_mul(degree_1)
+            .ok_or_else(|| invalid_data("the degrees are too large"))?;
+
+        Ok(Self {
+            degree_0,
+            degree_1,
+            powers_of_g: read_points(&mut reader, size)?,
+            powers_of_g_lagrange_over_both_roots: read_points(&mut reader, size)?,
+            h: read_point(&mut reader)?,
+            tau_0_h: read_point(&mut reader)?,
+            tau_1_h: read_point(&mut reader)?,
+        })
+    }
+}
+
+impl<E: MultiMillerLoop> BiKZGRowBlockSRS<E>
+where
+    E::G1Affine: CurveAffine<ScalarExt = E::Fr, CurveExt = E::G1>,
+{
+    /// Serialize the row block with the points compressed, to ship it to a worker.
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(&(self.degree_0 as u64).to_le_bytes())?;
+        writer.write_all(&(self.row_offset as u64).to_le_bytes())?;
+        writer.write_all(&(self.num_rows as u64).to_le_bytes())?;
+        for g in self.powers_of_g.iter().chain(self.powers_of_tau_0.iter()) {
+            write_point(&mut writer, g)?;
+        }
+        Ok(())
+    }
+
+    pub fn deserialize_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let degree_0 = read_u64(&mut reader)? as usize;
+        let row_offset = read_u64(&mut reader)? as usize;
+        let num_rows = read_u64(&mut reader)? as usize;
+        if !degree_0.is_power_of_two() || num_rows == 0 {
+            return Err(invalid_data("invalid row block"));
+        }
+        let size = degree_0
+            .checked_mul(num_rows)
+            .ok_or_else(|| invalid_data("the row block is too large"))?;
+
+        Ok(Self {
+            degree_0,
+            row_offset,
+            num_rows,
+            powers_of_g: read_points(&mut reader, size)?,
+            powers_of_tau_0: read_points(&mut reader, degree_0)?,
+        })
+    }
+}
+
+/// The element of Fq from its little-endian Montgomery form, as written in the transcript.
+fn read_fq_montgomery<R: Read>(mut reader: R, r_inv: &Fq) -> io::Result<Fq> {
+    let mut repr = <Fq as PrimeField>::Repr::default();
+    reader.read_exact(repr.as_mut())?;
+    Fq::from_repr(repr)
+        .into_option()
+        .map(|x| x * r_inv)
+        .ok_or_else(|| invalid_data("invalid field element"))
+}
+
+fn read_ptau_g1<R: Read>(mut reader: R, r_inv: &Fq) -> io::Result<G1Affine> {
+    let x = read_fq_montgomery(&mut reader, r_inv)?;
+    let y = read_fq_montgomery(&mut reader, r_inv)?;
+    if x.is_zero_vartime() && y.is_zero_vartime() {
+        return Ok(G1Affine::identity());
+    }
+    G1Affine::from_xy(x, y)
+        .into_option()
+        .ok_or_else(|| invalid_data("invalid G1 point"))
+}
+
+fn read_ptau_g2<R: Read>(mut reader: R, r_inv: &Fq) -> io::Result<G2Affine> {
+    let x = Fq2 {
+        c0: read_fq_montgomery(&mut reader, r_inv)?,
+        c1: read_fq_montgomery(&mut reader, r_inv)?,
+    };
+    let y = Fq2 {
+        c0: read_fq_montgomery(&mut reader, r_inv)?,
+        c1: read_fq_montgomery(&mut reader, r_inv)?,
+    };
+    if x.is_zero_vartime() && y.is_zero_vartime() {
+        return Ok(G2Affine::identity());
+    }
+    G2Affine::from_xy(x, y)
+        .into_option()
+        .ok_or_else(|| invalid_data("invalid G2 point"))
+}
+
+impl BiKZGSRS<Bn256> {
+    /// Build the SRS from a powers-of-tau transcript in the `.ptau` format of snarkjs,
+    /// with `tau_0` the tau of the transcript.
+    ///
+    /// The transcript only has the powers of a single tau, and `tau_1` can't be derived from it:
+    /// with `tau_1` a polynomial in `tau_0`, openings at points off that curve can be forged.
+    /// `tau_1` is sampled from `rng` and discarded instead, so its secrecy relies on this process;
+    /// the consistency of the powers of `tau_0` is checked with random coefficients from `rng`.
+    ///
+    /// Whoever ran the import may have kept `tau_1`: the imported SRS is only safe to use after
+    /// at least one `contribute` round by another party.
+    pub fn from_powers_of_tau<R: Read>(
+        mut reader: R,
+        supported_n: usize,
+        supported_m: usize,
+        mut rng: impl RngCore + CryptoRng,
+    ) -> io::Result<Self> {
+        let timer = start_timer!(|| format!(
+            "Importing powers of tau for degree {} {}",
+            supported_n, supported_m
+        ));
+        assert!(supported_n.is_power_of_two());
+        assert!(supported_m.is_power_of_two());
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        if &magic != PTAU_MAGIC {
+            return Err(invalid_data("not a powers of tau transcript"));
+        }
+        let _version = read_u32(&mut reader)?;
+        let num_sections = read_u32(&mut reader)?;
+
+        // Montgomery form is x * 2^256
+        let r_inv = Fq::from(2).pow_vartime([256]).invert().unwrap();
+        // at least two powers to check tau_0 h against
+        let num_g1 = supported_n.max(2);
+
+        let mut power = None;
+        let mut tau_g1 = None;
+        let mut tau_g2 = None;
+        for _ in 0..num_sections {
+            let section_id = read_u32(&mut reader)?;
+            let section_size = read_u64(&mut reader)?;
+            let mut section = (&mut reader).take(section_size);
+            match section_id {
+                PTAU_HEADER_SECTION => {
+                    let n8 = read_u32(&mut section)?;
+                    let mut q = <Fq as PrimeField>::Repr::default();
+                    if n8 as usize != q.as_ref().len() {
+                        return Err(invalid_data("not a transcript over BN254"));
+                    }
+                    section.read_exact(q.as_mut())?;
+                    // q - 1 is even, so q differs from it in the lowest byte only
+                    let mut q_minus_one = (-Fq::ONE).to_repr();
+                    q_minus_one.as_mut()[0] += 1;
+                    if q.as_ref() != q_minus_one.as_ref() {
+                        return Err(invalid_data("not a transcript over BN254"));
+                    }
+                    power = Some(read_u32(&mut section)?);
+                }
+                PTAU_TAU_G1_SECTION => {
+                    let power = power.ok_or_else(|| invalid_data("missing header"))?;
+                    if num_g1 > (1usize << (power + 1)) - 1 {
+                        return Err(invalid_data("not enough powers of tau"));
+                    }
+                    tau_g1 = Some(
+                        (0..num_g1)
+                            .map(|_| read_ptau_g1(&mut section, &r_inv))
+                            .collect::<io::Result<Vec<_>>>()?,
+                    );
+                }
+                PTAU_TAU_G2_SECTION => {
+                    if power.ok_or_else(|| invalid_data("missing header"))? == 0 {
+                        return Err(invalid_data("not enough powers of tau"));
+                    }
+                    tau_g2 = Some([
+                        read_ptau_g2(&mut section, &r_inv)?,
+                        read_ptau_g2(&mut section, &r_inv)?,
+                    ]);
+                }
+                _ => {}
+            }
+            // skip the rest of the section
+            io::copy(&mut section, &mut io::sink())?;
+        }
+        let tau_g1 = tau_g1.ok_or_else(|| invalid_data("missing tau G1 section"))?;
+        let [h, tau_0_h] = tau_g2.ok_or_else(|| invalid_data("missing tau G2 section"))?;
+
+        // the powers are consistent: e(\sum_i r_i g^{tau^i}, tau h) = e(\sum_i r_i g^{tau^{i+1}}, h)
+        if tau_g1[0] != G1Affine::generator() || h != G2Affine::generator() {
+            return Err(invalid_data("unexpected generators"));
+        }
+        let coeffs = (0..num_g1 - 1)
+            .map(|_| Fr::random(&mut rng))
+            .collect::<Vec<_>>();
+        let lhs = best_multiexp(&coeffs, &tau_g1[..num_g1 - 1]).to_affine();
+        let rhs = best_multiexp(&coeffs, &tau_g1[1..]).to_affine();
+        if Bn256::pairing(&lhs, &tau_0_h) != Bn256::pairing(&rhs, &h) {
+            return Err(invalid_data("inconsistent powers of tau"));
+        }
+
+        // g^{tau_0^i tau_1^j} = (g^{tau_0^i})^{tau_1^j}
+        let tau_1 = Fr::random(&mut rng);
+        let powers_of_tau_1 = powers_of_field_elements(&tau_1, supported_m);
+        let mut proj_bases = vec![G1::identity(); supported_n * supported_m];
+        parallelize(&mut proj_bases, |g, start| {
+            for (idx, g) in g.iter_mut().enumerate() {
+                let offset = start + idx;
+                *g = tau_g1[offset % supported_n] * powers_of_tau_1[offset / supported_n];
+            }
+        });
+        let mut powers_of_g = vec![G1Affine::identity(); proj_bases.len()];
+        parallelize(&mut powers_of_g, |g, starts| {
+            G1::batch_normalize(&proj_bases[starts..(starts + g.len())], g);
+        });
+        let tau_1_h = (h * tau_1).to_affine();
+        end_timer!(timer);
+
+        Ok(Self::from_powers(
+            powers_of_g,
+            supported_n,
+            supported_m,
+            h,
+            tau_0_h,
+            tau_1_h,
+        ))
+    }
+}
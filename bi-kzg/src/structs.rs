@@ -1,4 +1,4 @@
-use halo2curves::pairing::Engine;
+use halo2curves::{pairing::Engine, CurveAffine};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct BivariatePolynomial<F> {
@@ -17,6 +17,10 @@ pub struct BivariateLagrangePolynomial<F> {
 /// Structured reference string for Bi-KZG polynomial commitment scheme.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct BiKZGSRS<E: Engine> {
+    /// The supported degree in X, i.e., N.
+    pub degree_0: usize,
+    /// The supported degree in Y, i.e., M.
+    pub degree_1: usize,
     /// (g_1^{\tau_0^i\tau_1^j})_{i\in [0,N], j\in [0, M]} = \\
     /// (
     ///  g_1, g_1^{\tau_0}, g_1^{\tau_0^2}, ..., g_1^{\tau_0^N},
@@ -34,6 +38,23 @@ pub struct BiKZGSRS<E: Engine> {
     pub tau_1_h: E::G2Affine,
 }
 
+/// The part of the Bi-KZG SRS a distributed worker needs for its block of rows,
+/// O(N * num_rows) points instead of O(N * M).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BiKZGRowBlockSRS<E: Engine> {
+    /// The supported degree in X, i.e., N.
+    pub degree_0: usize,
+    /// The first row of the block.
+    pub row_offset: usize,
+    /// The number of rows of the block.
+    pub num_rows: usize,
+    /// (g_1^{\tau_0^i\tau_1^j})_{i\in [0,N), j\in [row_offset, row_offset + num_rows)},
+    /// in the layout of `BiKZGSRS::powers_of_g`.
+    pub powers_of_g: Vec<E::G1Affine>,
+    /// (g_1^{\tau_0^i})_{i\in [0,N)}, for the proof pi0.
+    pub powers_of_tau_0: Vec<E::G1Affine>,
+}
+
 /// `UnivariateVerifierParam` is used to check evaluation proofs for a given
 /// commitment.
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -63,6 +84,52 @@ pub struct BiKZGProof<E: Engine> {
     pub pi1: E::G1Affine,
 }
 
+/// Batch proof for Bi-KZG polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BiKZGBatchProof<E: Engine> {
+    /// one proof per distinct point, in the order the points first appear, for a random
+    /// combination of the polynomials opened at that point.
+    pub proofs: Vec<BiKZGProof<E>>,
+    /// the claimed evaluations, one per opening.
+    pub evals: Vec<E::Fr>,
+}
+
+/// The part of a Bi-KZG opening computed by a worker on its row block,
+/// i.e., the coefficients of Y^j for j in [row_offset, row_offset + num_rows).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BiKZGPartialProof<E: Engine> {
+    pub row_offset: usize,
+    pub num_rows: usize,
+    /// \sum_j b^j f_{row_offset + j}(X) over the rows of the block.
+    pub folded_row: Vec<E::Fr>,
+    /// The folded row at a.
+    pub eval: E::Fr,
+    /// The proof pi0 for the folded row.
+    pub pi0: E::G1Affine,
+    /// The part of the proof pi1 from the rows of the block.
+    pub pi1: E::G1Affine,
+    /// (g_1^{\sum_j b^{num_rows - 1 - j} \tau_0^i \tau_1^{row_offset + j}})_i, to carry the rows
+    /// of the later blocks over to the proof pi1.
+    pub carry_bases: Vec<E::G1Affine>,
+}
+
+/// A contribution to the Bi-KZG SRS ceremony, multiplying tau_0 and tau_1 by secrets s_0 and s_1.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BiKZGContribution<E: Engine> {
+    /// g^{\tau_0} after the update.
+    pub tau_0_g: E::G1Affine,
+    /// g^{\tau_1} after the update.
+    pub tau_1_g: E::G1Affine,
+    /// s_0 times the generator of G2.
+    pub s_0_h: E::G2Affine,
+    /// s_1 times the generator of G2.
+    pub s_1_h: E::G2Affine,
+    /// r_0^{s_0} and r_1^{s_1}, with r_0 and r_1 hashed to G1 from the update,
+    /// the proofs of knowledge of the secrets.
+    pub r_0_s_0: E::G1Affine,
+    pub r_1_s_1: E::G1Affine,
+}
+
 impl<E: Engine> From<&BiKZGSRS<E>> for BiKZGVerifierParam<E> {
     fn from(srs: &BiKZGSRS<E>) -> Self {
         Self {
@@ -73,3 +140,87 @@ impl<E: Engine> From<&BiKZGSRS<E>> for BiKZGVerifierParam<E> {
         }
     }
 }
+
+/// Structured reference string for multilinear KZG polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultilinearKZGSRS<E: Engine> {
+    /// (g_1^{eq(\tau_{i..n}, b)})_{b \in \{0, 1\}^{n - i}} for i \in [0, n],
+    /// i.e., the lagrange bases over the last n - i variables; the last one is (g_1).
+    pub lagrange_bases: Vec<Vec<E::G1Affine>>,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// tau_i times the above generator of G2, for i \in [0, n).
+    pub tau_h: Vec<E::G2Affine>,
+}
+
+impl<E: Engine> MultilinearKZGSRS<E> {
+    /// Number of variables supported by the SRS.
+    pub fn num_vars(&self) -> usize {
+        self.tau_h.len()
+    }
+}
+
+/// `MultilinearKZGVerifierParam` is used to check evaluation proofs for a given
+/// commitment.
+#[derive(Clone, Debug, Eq, PartialEq, Default)]
+pub struct MultilinearKZGVerifierParam<E: Engine> {
+    /// The generator of G1.
+    pub g: E::G1Affine,
+    /// The generator of G2.
+    pub h: E::G2Affine,
+    /// tau_i times the above generator of G2.
+    pub tau_h: Vec<E::G2Affine>,
+}
+
+/// Commitment for multilinear KZG polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MultilinearKZGCommitment<E: Engine> {
+    /// the actual commitment is an affine point.
+    pub com: E::G1Affine,
+}
+
+/// Proof for multilinear KZG polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MultilinearKZGProof<E: Engine> {
+    /// commitments to the quotients, one per variable.
+    pub pi: Vec<E::G1Affine>,
+}
+
+impl<E: Engine> From<&MultilinearKZGSRS<E>> for MultilinearKZGVerifierParam<E> {
+    fn from(srs: &MultilinearKZGSRS<E>) -> Self {
+        Self {
+            g: srs.lagrange_bases[srs.num_vars()][0],
+            h: srs.h,
+            tau_h: srs.tau_h.clone(),
+        }
+    }
+}
+
+/// Public parameters for the Hyrax polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HyraxSRS<C: CurveAffine> {
+    /// Pedersen generators, one per column, hashed to the curve so that nobody knows
+    /// their discrete logs.
+    pub generators: Vec<C>,
+}
+
+impl<C: CurveAffine> HyraxSRS<C> {
+    /// Number of variables of a `num_vars` variate polynomial that index the columns.
+    pub fn col_var_num(&self, num_vars: usize) -> usize {
+        ((num_vars + 1) / 2).min(self.generators.len().trailing_zeros() as usize)
+    }
+}
+
+/// Commitment for Hyrax polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HyraxCommitment<C: CurveAffine> {
+    /// Pedersen commitments to the rows.
+    pub row_comms: Vec<C>,
+}
+
+/// Proof for Hyrax polynomial commitment scheme.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HyraxProof<C: CurveAffine> {
+    /// the rows combined with the eq evaluations at the row variables.
+    pub combined_row: Vec<C::Scalar>,
+}
@@ -1,17 +1,24 @@
 use ark_std::test_rng;
 use halo2curves::{
-    bn256::{Bn256, Fr},
-    ff::Field,
+    bn256::{Bn256, Fq, Fr, G1Affine, G2Affine},
+    ff::{Field, PrimeField},
+    group::prime::PrimeCurveAffine,
+    CurveAffine,
 };
 
 use crate::{
     bi_fft::bi_fft_in_place,
     coeff_form_bi_kzg::CoeffFormBiKZG,
+    distributed::DistributedBiKZG,
+    hyrax::Hyrax,
+    lagrange_form_bi_kzg::LagrangeFormBiKZG,
+    multilinear_kzg::MultilinearKZG,
     pcs::PolynomialCommitmentScheme,
     poly::{lagrange_coefficients, univariate_quotient},
     structs::BivariateLagrangePolynomial,
-    util::tensor_product_parallel,
-    BiKZGVerifierParam, BivariatePolynomial,
+    util::{eq_evals, tensor_product_parallel},
+    BiKZGCommitment, BiKZGPartialProof, BiKZGRowBlockSRS, BiKZGSRS, BiKZGVerifierParam,
+    BivariatePolynomial, MultilinearKZGVerifierParam,
 };
 
 #[test]
@@ -39,6 +46,64 @@ fn test_bi_kzg_single_pass() {
     ));
 }
 
+#[test]
+fn test_bi_kzg_batch() {
+    let mut rng = test_rng();
+    let n = 16;
+    let m = 32;
+
+    let srs = CoeffFormBiKZG::<Bn256>::gen_srs_for_testing(&mut rng, n, m);
+    let vk = BiKZGVerifierParam::<Bn256>::from(&srs);
+
+    for num in [1, 2, 5] {
+        let polys = (0..num)
+            .map(|_| BivariatePolynomial::<Fr>::random(&mut rng, n, m))
+            .collect::<Vec<_>>();
+        // the same point may be opened several times
+        let mut points = (0..num)
+            .map(|_| (Fr::random(&mut rng), Fr::random(&mut rng)))
+            .collect::<Vec<_>>();
+        points[num - 1] = points[0];
+        let evals = polys
+            .iter()
+            .zip(points.iter())
+            .map(|(poly, (x, y))| poly.evaluate(x, y))
+            .collect::<Vec<_>>();
+
+        let commits = polys
+            .iter()
+            .map(|poly| CoeffFormBiKZG::<Bn256>::commit(&srs, poly))
+            .collect::<Vec<_>>();
+        let batch_proof = CoeffFormBiKZG::<Bn256>::multi_open(&srs, &polys, &points, &evals);
+        // one proof per distinct point
+        assert_eq!(batch_proof.proofs.len(), num.saturating_sub(1).max(1));
+        assert!(CoeffFormBiKZG::<Bn256>::batch_verify(
+            &vk,
+            &commits,
+            &points,
+            &batch_proof
+        ));
+
+        let mut bad_batch_proof = batch_proof.clone();
+        bad_batch_proof.evals[num - 1] += Fr::ONE;
+        assert!(!CoeffFormBiKZG::<Bn256>::batch_verify(
+            &vk,
+            &commits,
+            &points,
+            &bad_batch_proof
+        ));
+
+        let mut bad_points = points.clone();
+        bad_points[0].0 += Fr::ONE;
+        assert!(!CoeffFormBiKZG::<Bn256>::batch_verify(
+            &vk,
+            &commits,
+            &bad_points,
+            &batch_proof
+        ));
+    }
+}
+
 #[test]
 fn test_bi_kzg_e2e() {
     let mut rng = test_rng();
@@ -102,6 +167,383 @@ fn test_bi_kzg_e2e() {
     }
 }
 
+#[test]
+fn test_lagrange_form_bi_kzg() {
+    let mut rng = test_rng();
+
+    for (n, m) in [(2, 4), (4, 16), (16, 8), (8, 2)] {
+        let srs = LagrangeFormBiKZG::<Bn256>::gen_srs_for_testing(&mut rng, n, m);
+        let vk = BiKZGVerifierParam::<Bn256>::from(&srs);
+
+        let poly = BivariatePolynomial::<Fr>::random(&mut rng, n, m);
+        let lagrange_poly = BivariateLagrangePolynomial::from(&poly);
+
+        let x = Fr::random(&mut rng);
+        let y = Fr::random(&mut rng);
+        assert_eq!(lagrange_poly.evaluate(&x, &y), poly.evaluate(&x, &y));
+
+        // the same commitment and proof as the coefficient form
+        let commit = LagrangeFormBiKZG::<Bn256>::commit(&srs, &lagrange_poly);
+        assert_eq!(commit, CoeffFormBiKZG::<Bn256>::commit(&srs, &poly));
+
+        let (proof, eval) = LagrangeFormBiKZG::<Bn256>::open(&srs, &lagrange_poly, &(x, y));
+        let (coeff_proof, coeff_eval) = CoeffFormBiKZG::<Bn256>::open(&srs, &poly, &(x, y));
+        assert_eq!(eval, coeff_eval);
+        assert_eq!(proof, coeff_proof);
+
+        assert!(LagrangeFormBiKZG::<Bn256>::verify(
+            &vk,
+            &commit,
+            &(x, y),
+            &eval,
+            &proof
+        ));
+        assert!(!LagrangeFormBiKZG::<Bn256>::verify(
+            &vk,
+            &commit,
+            &(x, y),
+            &(eval + Fr::ONE),
+            &proof
+        ));
+    }
+}
+
+#[test]
+fn test_bi_kzg_srs_trim_and_serde() {
+    let mut rng = test_rng();
+    let srs = CoeffFormBiKZG::<Bn256>::gen_srs_for_testing(&mut rng, 16, 8);
+
+    // the lagrange bases from the inverse FFT are the ones of the setup
+    let (full_srs, full_vk) = srs.trim(16, 8);
+    assert_eq!(full_srs, srs);
+    assert_eq!(full_vk, BiKZGVerifierParam::from(&srs));
+
+    for (n, m) in [(2, 2), (4, 2), (16, 2), (2, 8)] {
+        let (trimmed_srs, trimmed_vk) = srs.trim(n, m);
+        let poly = BivariatePolynomial::<Fr>::random(&mut rng, n, m);
+        let commit = CoeffFormBiKZG::<Bn256>::commit(&trimmed_srs, &poly);
+        assert_eq!(
+            commit,
+            LagrangeFormBiKZG::<Bn256>::commit(
+                &trimmed_srs,
+                &BivariateLagrangePolynomial::from(&poly)
+            )
+        );
+
+        let point = (Fr::random(&mut rng), Fr::random(&mut rng));
+        let (proof, eval) = CoeffFormBiKZG::<Bn256>::open(&trimmed_srs, &poly, &point);
+        assert!(CoeffFormBiKZG::<Bn256>::verify(
+            &trimmed_vk,
+            &commit,
+            &point,
+            &eval,
+            &proof
+        ));
+    }
+
+    let mut buffer = vec![];
+    srs.serialize_into(&mut buffer).unwrap();
+    assert_eq!(
+        BiKZGSRS::<Bn256>::deserialize_from(buffer.as_slice()).unwrap(),
+        srs
+    );
+    assert!(BiKZGSRS::<Bn256>::deserialize_from(&buffer[..buffer.len() - 1]).is_err());
+}
+
+// A powers of tau transcript in the format of snarkjs, with an extra section to skip.
+fn write_ptau(tau_g1: &[G1Affine], tau_g2: &[G2Affine], power: u32) -> Vec<u8> {
+    let r = Fq::from(2).pow_vartime([256]);
+    let write_fq =
+        |buffer: &mut Vec<u8>, x: &Fq| buffer.extend_from_slice((*x * r).to_repr().as_ref());
+    let write_section = |buffer: &mut Vec<u8>, id: u32, section: &[u8]| {
+        buffer.extend_from_slice(&id.to_le_bytes());
+        buffer.extend_from_slice(&(section.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(section);
+    };
+
+    let mut header = vec![];
+    header.extend_from_slice(&32u32.to_le_bytes());
+    let mut q = (-Fq::ONE).to_repr();
+    q.as_mut()[0] += 1;
+    header.extend_from_slice(q.as_ref());
+    header.extend_from_slice(&power.to_le_bytes());
+    header.extend_from_slice(&power.to_le_bytes());
+
+    let mut g1_section = vec![];
+    for p in tau_g1.iter() {
+        let coordinates = p.coordinates().unwrap();
+        write_fq(&mut g1_section, coordinates.x());
+        write_fq(&mut g1_section, coordinates.y());
+    }
+    let mut g2_section = vec![];
+    for p in tau_g2.iter() {
+        let coordinates = p.coordinates().unwrap();
+        for c in [coordinates.x(), coordinates.y()] {
+            write_fq(&mut g2_section, &c.c0);
+            write_fq(&mut g2_section, &c.c1);
+        }
+    }
+
+    let mut buffer = b"ptau".to_vec();
+    buffer.extend_from_slice(&1u32.to_le_bytes());
+    buffer.extend_from_slice(&4u32.to_le_bytes());
+    write_section(&mut buffer, 1, &header);
+    write_section(&mut buffer, 2, &g1_section);
+    write_section(&mut buffer, 3, &g2_section);
+    write_section(&mut buffer, 4, &[0u8; 64]);
+    buffer
+}
+
+#[test]
+fn test_bi_kzg_srs_from_powers_of_tau() {
+    let mut rng = test_rng();
+    let power = 3;
+    let tau = Fr::random(&mut rng);
+    let powers_of_tau = (0..(1 << (power + 1)) - 1)
+        .map(|i| tau.pow_vartime([i as u64]))
+        .collect::<Vec<_>>();
+    let tau_g1 = powers_of_tau
+        .iter()
+        .map(|t| (G1Affine::generator() * t).into())
+        .collect::<Vec<G1Affine>>();
+    let tau_g2 = powers_of_tau[..1 << power]
+        .iter()
+        .map(|t| (G2Affine::generator() * t).into())
+        .collect::<Vec<G2Affine>>();
+    let ptau = write_ptau(&tau_g1, &tau_g2, power);
+
+    for (n, m) in [(2, 4), (8, 2), (4, 8)] {
+        let srs = BiKZGSRS::<Bn256>::from_powers_of_tau(ptau.as_slice(), n, m, &mut rng).unwrap();
+        let vk = BiKZGVerifierParam::from(&srs);
+        assert_eq!(srs.powers_of_g[..n], tau_g1[..n]);
+        assert_eq!(srs.tau_0_h, tau_g2[1]);
+
+        let poly = BivariatePolynomial::<Fr>::random(&mut rng, n, m);
+        let commit = CoeffFormBiKZG::<Bn256>::commit(&srs, &poly);
+        assert_eq!(
+            commit,
+            LagrangeFormBiKZG::<Bn256>::commit(&srs, &BivariateLagrangePolynomial::from(&poly))
+        );
+        let point = (Fr::random(&mut rng), Fr::random(&mut rng));
+        let (proof, eval) = CoeffFormBiKZG::<Bn256>::open(&srs, &poly, &point);
+        assert!(CoeffFormBiKZG::<Bn256>::verify(
+            &vk, &commit, &point, &eval, &proof
+        ));
+    }
+
+    // more powers than in the transcript
+    assert!(BiKZGSRS::<Bn256>::from_powers_of_tau(ptau.as_slice(), 16, 1, &mut rng).is_err());
+
+    // inconsistent powers
+    let mut bad_tau_g1 = tau_g1.clone();
+    bad_tau_g1[2] = tau_g1[3];
+    let bad_ptau = write_ptau(&bad_tau_g1, &tau_g2, power);
+    assert!(BiKZGSRS::<Bn256>::from_powers_of_tau(bad_ptau.as_slice(), 4, 4, &mut rng).is_err());
+
+    let mut bad_magic = ptau.clone();
+    bad_magic[0] ^= 1;
+    assert!(BiKZGSRS::<Bn256>::from_powers_of_tau(bad_magic.as_slice(), 4, 4, &mut rng).is_err());
+}
+
+#[test]
+fn test_bi_kzg_ceremony() {
+    let mut rng = test_rng();
+    let (n, m) = (8, 4);
+
+    let initial = BiKZGSRS::<Bn256>::ceremony_start(n, m);
+    assert!(initial.verify_structure(&mut rng));
+
+    let mut srs = initial.clone();
+    let mut contributions = vec![];
+    for _ in 0..3 {
+        let (next_srs, contribution) = srs.contribute(&mut rng);
+        assert!(contribution.verify(&srs.tau_g(), &srs.h));
+        srs = next_srs;
+        contributions.push(contribution);
+    }
+    assert!(srs.verify_ceremony(&initial, &contributions, &mut rng));
+
+    let vk = BiKZGVerifierParam::from(&srs);
+    let poly = BivariatePolynomial::<Fr>::random(&mut rng, n, m);
+    let commit = CoeffFormBiKZG::<Bn256>::commit(&srs, &poly);
+    let point = (Fr::random(&mut rng), Fr::random(&mut rng));
+    let (proof, eval) = CoeffFormBiKZG::<Bn256>::open(&srs, &poly, &point);
+    assert!(CoeffFormBiKZG::<Bn256>::verify(
+        &vk, &commit, &point, &eval, &proof
+    ));
+
+    let mut buffer = vec![];
+    contributions[0].serialize_into(&mut buffer).unwrap();
+    assert_eq!(
+        BiKZGContribution::<Bn256>::deserialize_from(buffer.as_slice()).unwrap(),
+        contributions[0]
+    );
+
+    // a missing contribution
+    assert!(!srs.verify_ceremony(&initial, &contributions[1..], &mut rng));
+    // a contribution without the proof of knowledge
+    let mut bad_contributions = contributions.clone();
+    bad_contributions[1].r_0_s_0 = bad_contributions[1].r_1_s_1;
+    assert!(!srs.verify_ceremony(&initial, &bad_contributions, &mut rng));
+    // an SRS that doesn't match the contributions
+    let mut bad_srs = srs.clone();
+    bad_srs.powers_of_g[n + 1] = bad_srs.powers_of_g[n + 2];
+    assert!(!bad_srs.verify_ceremony(&initial, &contributions, &mut rng));
+    let mut bad_srs = srs.clone();
+    bad_srs.powers_of_g_lagrange_over_both_roots[0] = srs.powers_of_g[0];
+    assert!(!bad_srs.verify_ceremony(&initial, &contributions, &mut rng));
+}
+
+#[test]
+fn test_distributed_bi_kzg() {
+    let mut rng = test_rng();
+    let (n, m) = (8, 16);
+    let srs = CoeffFormBiKZG::<Bn256>::gen_srs_for_testing(&mut rng, n, m);
+    let vk = BiKZGVerifierParam::<Bn256>::from(&srs);
+
+    for num_workers in [1, 2, 4, 16] {
+        let poly = BivariatePolynomial::<Fr>::random(&mut rng, n, m);
+        let point = (Fr::random(&mut rng), Fr::random(&mut rng));
+        let blocks = DistributedBiKZG::<Bn256>::split_rows(&poly, num_workers);
+
+        // one thread per worker, each with its own block and rows of the SRS only,
+        // exchanged as bytes
+        let (commitments, partial_proofs): (Vec<_>, Vec<_>) = std::thread::scope(|s| {
+            let handles = blocks
+                .iter()
+                .map(|(row_offset, block)| {
+                    let mut block_srs_bytes = vec![];
+                    srs.row_block(*row_offset, block.degree_1)
+                        .serialize_into(&mut block_srs_bytes)
+                        .unwrap();
+                    s.spawn(move || {
+                        let block_srs =
+                            BiKZGRowBlockSRS::<Bn256>::deserialize_from(&block_srs_bytes[..])
+                                .unwrap();
+                        let mut commitment_bytes = vec![];
+                        DistributedBiKZG::<Bn256>::worker_commit(&block_srs, block)
+                            .serialize_into(&mut commitment_bytes)
+                            .unwrap();
+                        let mut partial_proof_bytes = vec![];
+                        DistributedBiKZG::<Bn256>::worker_open(&block_srs, block, &point)
+                            .serialize_into(&mut partial_proof_bytes)
+                            .unwrap();
+                        (commitment_bytes, partial_proof_bytes)
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|h| {
+                    let (commitment_bytes, partial_proof_bytes) = h.join().unwrap();
+                    (
+                        BiKZGCommitment::<Bn256>::deserialize_from(&commitment_bytes[..]).unwrap(),
+                        BiKZGPartialProof::<Bn256>::deserialize_from(&partial_proof_bytes[..])
+                            .unwrap(),
+                    )
+                })
+                .unzip()
+        });
+
+        let commit = DistributedBiKZG::<Bn256>::combine_commitments(&commitments);
+        let (proof, eval) = DistributedBiKZG::<Bn256>::combine_openings(&partial_proofs, &point);
+
+        // the same as in a single process
+        assert_eq!(commit, CoeffFormBiKZG::<Bn256>::commit(&srs, &poly));
+        let (expected_proof, expected_eval) = CoeffFormBiKZG::<Bn256>::open(&srs, &poly, &point);
+        assert_eq!(eval, expected_eval);
+        assert_eq!(proof, expected_proof);
+        assert!(CoeffFormBiKZG::<Bn256>::verify(
+            &vk, &commit, &point, &eval, &proof
+        ));
+    }
+}
+
+#[test]
+fn test_multilinear_kzg() {
+    let mut rng = test_rng();
+
+    for num_vars in 1..8 {
+        let srs = MultilinearKZG::<Bn256>::gen_srs_for_testing(&mut rng, 1 << num_vars, 1);
+        let vk = MultilinearKZGVerifierParam::<Bn256>::from(&srs);
+
+        // polynomials with fewer variables than the srs use its last variables
+        for poly_num_vars in [num_vars, num_vars - 1] {
+            let poly = (0..1 << poly_num_vars)
+                .map(|_| Fr::random(&mut rng))
+                .collect::<Vec<_>>();
+            let point = (0..poly_num_vars)
+                .map(|_| Fr::random(&mut rng))
+                .collect::<Vec<_>>();
+
+            let commit = MultilinearKZG::<Bn256>::commit(&srs, &poly);
+            let (proof, eval) = MultilinearKZG::<Bn256>::open(&srs, &poly, &point);
+            let expected_eval = poly
+                .iter()
+                .zip(eq_evals(&point).iter())
+                .map(|(f, eq)| *f * eq)
+                .sum::<Fr>();
+            assert_eq!(eval, expected_eval);
+
+            assert!(MultilinearKZG::<Bn256>::verify(
+                &vk, &commit, &point, &eval, &proof
+            ));
+            assert!(!MultilinearKZG::<Bn256>::verify(
+                &vk,
+                &commit,
+                &point,
+                &(eval + Fr::ONE),
+                &proof
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_hyrax() {
+    let mut rng = test_rng();
+
+    for num_vars in 1..8 {
+        let srs = Hyrax::<G1Affine>::gen_srs_for_testing(&mut rng, 1 << num_vars, 1);
+
+        // polynomials with more variables than the srs get more rows
+        for poly_num_vars in [num_vars - 1, num_vars, num_vars + 2] {
+            let poly = (0..1 << poly_num_vars)
+                .map(|_| Fr::random(&mut rng))
+                .collect::<Vec<_>>();
+            let point = (0..poly_num_vars)
+                .map(|_| Fr::random(&mut rng))
+                .collect::<Vec<_>>();
+
+            let commit = Hyrax::<G1Affine>::commit(&srs, &poly);
+            let (proof, eval) = Hyrax::<G1Affine>::open(&srs, &poly, &point);
+            let expected_eval = poly
+                .iter()
+                .zip(eq_evals(&point).iter())
+                .map(|(f, eq)| *f * eq)
+                .sum::<Fr>();
+            assert_eq!(eval, expected_eval);
+
+            assert!(Hyrax::<G1Affine>::verify(
+                &srs, &commit, &point, &eval, &proof
+            ));
+            assert!(!Hyrax::<G1Affine>::verify(
+                &srs,
+                &commit,
+                &point,
+                &(eval + Fr::ONE),
+                &proof
+            ));
+
+            let mut bad_proof = proof.clone();
+            bad_proof.combined_row[0] += Fr::ONE;
+            assert!(!Hyrax::<G1Affine>::verify(
+                &srs, &commit, &point, &eval, &bad_proof
+            ));
+        }
+    }
+}
+
 #[test]
 fn test_tensor_product() {
     let vec1 = vec![Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
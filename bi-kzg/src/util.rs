@@ -11,6 +11,18 @@ pub(crate) fn powers_of_field_elements<F: Field>(x: &F, n: usize) -> Vec<F> {
     powers
 }
 
+/// The evaluations of eq(tau, b) over the hypercube, where tau_0 goes to the least significant bit.
+pub(crate) fn eq_evals<F: Field>(tau: &[F]) -> Vec<F> {
+    let mut evals = vec![F::ONE];
+    for t in tau.iter() {
+        let one_minus_t = F::ONE - t;
+        let high = evals.iter().map(|e| *e * t).collect::<Vec<_>>();
+        evals.iter_mut().for_each(|e| *e *= one_minus_t);
+        evals.extend(high);
+    }
+    evals
+}
+
 pub(crate) fn tensor_product_parallel<F: Field>(vec1: &[F], vec2: &[F]) -> Vec<F> {
     vec2.par_iter()
         .flat_map(|&i| vec1.iter().map(|&j| i * j).collect::<Vec<_>>())
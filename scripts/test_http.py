@@ -1,3 +1,5 @@
+import sys
+
 import requests
 
 
@@ -18,10 +20,13 @@ if __name__ == '__main__':
         f.write(proof)
 
     # verify
-    # add u64 length of witness and proof to the beginning of the file
-    witness_len = len(witness).to_bytes(8, byteorder='little')
+    # the verifier only sees the public inputs, i.e. the last 32-byte values of the witness
+    public_input_size = int(sys.argv[1]) if len(sys.argv) > 1 else 0
+    public_input = witness[len(witness) - 32 * public_input_size:]
+    # add u64 length of public input and proof to the beginning of the file
+    public_input_len = len(public_input).to_bytes(8, byteorder='little')
     proof_len = len(proof).to_bytes(8, byteorder='little')
-    verifier_input = witness_len + proof_len + witness + proof
+    verifier_input = public_input_len + proof_len + public_input + proof
     verify_headers = {
         'Content-Type': 'application/octet-stream',
         'Content-Length': str(len(proof)),
@@ -38,7 +43,7 @@ if __name__ == '__main__':
     random_byte_index = random.randint(0, len(proof) - 1)
     random_bit_index = random.randint(0, 7)
     tempered_proof = proof[:random_byte_index] + bytes([proof[random_byte_index] ^ (1 << random_bit_index)]) + proof[random_byte_index+1:]
-    tempered_input = witness_len + proof_len + witness + tempered_proof
+    tempered_input = public_input_len + proof_len + public_input + tempered_proof
     response = requests.post(url+"/verify", headers=verify_headers, data=tempered_input)
     # check failure message
     assert response.text == "failure", f"Failed to detect tempered proof: {response.text}"
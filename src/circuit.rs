@@ -8,7 +8,7 @@ use std::{
 };
 use thiserror::Error;
 
-use crate::{GKRConfig, Transcript};
+use crate::{FiatShamirHash, GKRConfig, Transcript};
 
 #[derive(Debug, Clone)]
 pub struct Gate<C: GKRConfig, const INPUT_NUM: usize> {
@@ -78,6 +78,35 @@ impl<C: GKRConfig> CircuitLayer<C> {
         }
     }
 
+    /// Serialize the wiring of the layer, i.e., everything but the values.
+    /// Coefficients of random gates are filled from the transcript, so only their flag is kept.
+    pub fn serialize_structure_into(&self, buffer: &mut Vec<u8>) {
+        fn serialize_gates<C: GKRConfig, const INPUT_NUM: usize>(
+            gates: &[Gate<C, INPUT_NUM>],
+            buffer: &mut Vec<u8>,
+        ) {
+            buffer.extend_from_slice(&(gates.len() as u64).to_le_bytes());
+            for gate in gates {
+                buffer.extend_from_slice(&(gate.gate_type as u64).to_le_bytes());
+                for i_id in gate.i_ids.iter() {
+                    buffer.extend_from_slice(&(*i_id as u64).to_le_bytes());
+                }
+                buffer.extend_from_slice(&(gate.o_id as u64).to_le_bytes());
+                buffer.push(gate.is_random as u8);
+                if !gate.is_random {
+                    gate.coef.serialize_into(&mut *buffer).unwrap(); // TODO: error propagation
+                }
+            }
+        }
+
+        buffer.extend_from_slice(&(self.input_var_num as u64).to_le_bytes());
+        buffer.extend_from_slice(&(self.output_var_num as u64).to_le_bytes());
+        serialize_gates(&self.mul, buffer);
+        serialize_gates(&self.add, buffer);
+        serialize_gates(&self.const_, buffer);
+        serialize_gates(&self.uni, buffer);
+    }
+
     pub fn identify_rnd_coefs(&mut self, rnd_coefs: &mut Vec<*mut C::CircuitField>) {
         for gate in &mut self.mul {
             if gate.is_random {
@@ -105,6 +134,9 @@ impl<C: GKRConfig> CircuitLayer<C> {
 #[derive(Debug, Default)]
 pub struct Circuit<C: GKRConfig> {
     pub layers: Vec<CircuitLayer<C>>,
+    /// The last `public_input_size` values of the input layer are public,
+    /// only the private part in front of them is committed.
+    pub public_input_size: usize,
 
     pub rnd_coefs_identified: bool,
     pub rnd_coefs: Vec<*mut C::CircuitField>, // unsafe
@@ -114,6 +146,7 @@ impl<C: GKRConfig> Clone for Circuit<C> {
     fn clone(&self) -> Circuit<C> {
         let mut ret = Circuit::<C> {
             layers: self.layers.clone(),
+            public_input_size: self.public_input_size,
             ..Default::default()
         };
 
@@ -126,16 +159,86 @@ impl<C: GKRConfig> Clone for Circuit<C> {
 
 unsafe impl<C> Send for Circuit<C> where C: GKRConfig {}
 
+fn is_valid_public_input_size(log_input_size: usize, public_input_size: usize) -> bool {
+    let input_size = 1usize << log_input_size;
+    public_input_size <= input_size && (input_size - public_input_size).is_power_of_two()
+}
+
 impl<C: GKRConfig> Circuit<C> {
     pub fn load_circuit(filename: &str) -> Self {
         let rc = RecursiveCircuit::<C>::load(filename).unwrap();
         rc.flatten()
     }
 
+    /// Digest of the circuit wiring under the Fiat-Shamir hash of the config.
+    /// Prover and verifier absorb it into the transcript, so a proof is bound to its circuit.
+    pub fn digest(&self) -> Vec<u8> {
+        let mut buffer = vec![];
+        buffer.extend_from_slice(&(self.public_input_size as u64).to_le_bytes());
+        buffer.extend_from_slice(&(self.layers.len() as u64).to_le_bytes());
+        for layer in &self.layers {
+            layer.serialize_structure_into(&mut buffer);
+        }
+
+        let mut hasher = C::FiatShamirHashType::new();
+        let mut digest = vec![0u8; C::FiatShamirHashType::DIGEST_SIZE];
+        hasher.hash(&mut digest, &buffer);
+        digest
+    }
+
     pub fn log_input_size(&self) -> usize {
         self.layers[0].input_var_num
     }
 
+    /// Whether the private inputs fill a power of two number of slots,
+    /// so that they form a multilinear polynomial on their own.
+    /// Padding the private region would take slots from the public region, which would be
+    /// committed as private data and never checked against the public inputs.
+    pub fn has_valid_public_input_size(&self) -> bool {
+        is_valid_public_input_size(self.log_input_size(), self.public_input_size)
+    }
+
+    /// The private inputs are the first 2^log_private_input_size values of the input layer
+    pub fn log_private_input_size(&self) -> usize {
+        assert!(
+            self.has_valid_public_input_size(),
+            "the private input size must be a power of two"
+        );
+        let input_size = 1usize << self.log_input_size();
+        (input_size - self.public_input_size).trailing_zeros() as usize
+    }
+
+    /// The part of the input layer the prover commits to
+    pub fn private_input(&self) -> &[C::SimdCircuitField] {
+        &self.layers[0].input_vals[..1 << self.log_private_input_size()]
+    }
+
+    pub fn public_input(&self) -> &[C::SimdCircuitField] {
+        let input_size = 1 << self.log_input_size();
+        &self.layers[0].input_vals[input_size - self.public_input_size..input_size]
+    }
+
+    /// Serialized public inputs, absorbed into the transcript by both parties
+    pub(crate) fn public_input_bytes(&self) -> Vec<u8> {
+        let mut buffer = vec![];
+        self.public_input()
+            .iter()
+            .for_each(|v| v.serialize_into(&mut buffer).unwrap()); // TODO: error propagation
+        buffer
+    }
+
+    /// Set the public region of the input layer, e.g., on the verifier side
+    /// where the private inputs are unknown.
+    pub fn set_public_input(&mut self, public_input: &[C::SimdCircuitField]) {
+        assert_eq!(public_input.len(), self.public_input_size);
+        let input_size = 1 << self.log_input_size();
+        self.layers[0]
+            .input_vals
+            .resize(input_size, C::SimdCircuitField::zero());
+        self.layers[0].input_vals[input_size - self.public_input_size..]
+            .copy_from_slice(public_input);
+    }
+
     // Build a random mock circuit with binary inputs
     pub fn set_random_input_for_test(&mut self) {
         let mut rng = test_rng();
@@ -251,6 +354,34 @@ impl<C: GKRConfig> Circuit<C> {
 
         Ok(())
     }
+
+    /// Load only the public inputs, as the verifier never sees the witness.
+    pub fn load_public_input_file(&mut self, filename: &str) {
+        let file_bytes = fs::read(filename).unwrap();
+        self.load_public_input_bytes(&file_bytes).unwrap();
+    }
+    pub fn load_public_input_bytes(
+        &mut self,
+        file_bytes: &[u8],
+    ) -> std::result::Result<(), CircuitError> {
+        log::trace!("public input file size: {} bytes", file_bytes.len());
+        log::trace!("expecting: {} bytes", 32 * self.public_input_size);
+        if file_bytes.len() != 32 * self.public_input_size {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "Invalid public input file size",
+            )
+            .into());
+        }
+
+        let mut cursor = Cursor::new(file_bytes);
+        let public_input = (0..self.public_input_size)
+            .map(|_| C::SimdCircuitField::try_deserialize_from_ecc_format(&mut cursor))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        self.set_public_input(&public_input);
+
+        Ok(())
+    }
 }
 
 impl<C: GKRConfig> Segment<C> {
@@ -413,6 +544,7 @@ impl<C: GKRConfig> Segment<C> {
 pub struct RecursiveCircuit<C: GKRConfig> {
     pub segments: Vec<Segment<C>>,
     pub layers: Vec<SegmentId>,
+    pub public_input_size: usize,
 }
 
 const MAGIC_NUM: u64 = 3770719418566461763; // b'CIRCUIT4'
@@ -445,11 +577,30 @@ impl<C: GKRConfig> RecursiveCircuit<C> {
 
             ret.layers.push(layer_id);
         }
+
+        // optional trailer: size of the public region at the end of the input layer
+        if (cursor.position() as usize) < cursor.get_ref().len() {
+            ret.public_input_size = u64::deserialize_from(&mut cursor)? as usize;
+            let input_var_num = ret
+                .layers
+                .first()
+                .map_or(1, |id| max(ret.segments[*id].i_var_num, 1));
+            if !is_valid_public_input_size(input_var_num, ret.public_input_size) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "The private input size must be a power of two",
+                )
+                .into());
+            }
+        }
         Ok(ret)
     }
 
     pub fn flatten(&self) -> Circuit<C> {
-        let mut ret = Circuit::default();
+        let mut ret = Circuit {
+            public_input_size: self.public_input_size,
+            ..Default::default()
+        };
         // layer-by-layer conversion
         for layer_id in &self.layers {
             let layer_seg = &self.segments[*layer_id];
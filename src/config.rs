@@ -1,20 +1,36 @@
+mod babybear_ext_keccak;
+mod babybear_ext_sha2;
 mod bn254_keccak;
 mod bn254_sha2;
+mod bn254x8_keccak;
+mod bn254x8_sha2;
 mod gf2_ext_keccak;
 mod gf2_ext_sha2;
+mod goldilocks_ext_keccak;
+mod goldilocks_ext_sha2;
+mod m31_ext6_keccak;
+mod m31_ext6_sha2;
 mod m31_ext_keccak;
 mod m31_ext_sha2;
 
+pub use babybear_ext_keccak::BabyBearExtConfigKeccak;
+pub use babybear_ext_sha2::BabyBearExtConfigSha2;
 pub use bn254_keccak::BN254ConfigKeccak;
 pub use bn254_sha2::BN254ConfigSha2;
+pub use bn254x8_keccak::BN254x8ConfigKeccak;
+pub use bn254x8_sha2::BN254x8ConfigSha2;
 pub use gf2_ext_keccak::GF2ExtConfigKeccak;
 pub use gf2_ext_sha2::GF2ExtConfigSha2;
+pub use goldilocks_ext_keccak::GoldilocksExtConfigKeccak;
+pub use goldilocks_ext_sha2::GoldilocksExtConfigSha2;
+pub use m31_ext6_keccak::M31Ext6ConfigKeccak;
+pub use m31_ext6_sha2::M31Ext6ConfigSha2;
 pub use m31_ext_keccak::M31ExtConfigKeccak;
 pub use m31_ext_sha2::M31ExtConfigSha2;
 
-use arith::{ExtensionField, Field, FieldSerde, SimdField};
+use arith::{ExtensionField, FieldSerde, ScalarField, SimdField};
 
-use crate::FiatShamirHash;
+use crate::{FiatShamirHash, BASEFOLD_LOG_BLOWUP};
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum PolynomialCommitmentType {
@@ -23,6 +39,7 @@ pub enum PolynomialCommitmentType {
     KZG,
     Orion,
     FRI,
+    Hyrax,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -30,6 +47,8 @@ pub enum FieldType {
     M31,
     BN254,
     GF2,
+    Goldilocks,
+    BabyBear,
 }
 
 pub const SENTINEL_M31: [u8; 32] = [
@@ -37,6 +56,16 @@ pub const SENTINEL_M31: [u8; 32] = [
     0, 0, 0,
 ];
 
+pub const SENTINEL_GOLDILOCKS: [u8; 32] = [
+    1, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0,
+];
+
+pub const SENTINEL_BABYBEAR: [u8; 32] = [
+    1, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0,
+];
+
 pub const SENTINEL_BN254: [u8; 32] = [
     1, 0, 0, 240, 147, 245, 225, 67, 145, 112, 185, 121, 72, 232, 51, 40, 93, 88, 129, 129, 182,
     69, 80, 184, 41, 160, 49, 225, 114, 78, 100, 48,
@@ -70,32 +99,80 @@ pub struct Config<C: GKRConfig> {
     pub grinding_bits: usize,
     // Polynomial commitment scheme
     pub polynomial_commitment_type: PolynomialCommitmentType,
+    // Log2 of the inverse rate of the code, for the code-based polynomial commitments
+    pub log_blowup: usize,
     // Field configuration for GKR
     pub gkr_config: C,
     // Whether to use GKR^2
     pub gkr_scheme: GKRScheme,
 }
 
+/// Version of the proof format, the first thing absorbed into the transcript
+pub const EXPANDER_TRANSCRIPT_VERSION: &[u8] = b"expander-gkr-v1";
+
 impl<C: GKRConfig> Config<C> {
     pub fn new(gkr_scheme: GKRScheme) -> Self {
+        // the degree 6 sumcheck of GKR^2 needs 7 distinct evaluation points
+        assert!(
+            gkr_scheme != GKRScheme::GkrSquare || C::FIELD_TYPE != FieldType::GF2,
+            "GKR^2 is not supported over GF2"
+        );
         Config {
             field_size: C::ChallengeField::FIELD_SIZE,
             security_bits: 100,
             #[cfg(feature = "grinding")]
             grinding_bits: 10,
             polynomial_commitment_type: PolynomialCommitmentType::Raw,
+            log_blowup: BASEFOLD_LOG_BLOWUP,
             gkr_config: C::default(),
             gkr_scheme,
         }
     }
+
+    /// Domain separator binding the transcript to the protocol version, the field,
+    /// its packing and challenge field, the hash function, the GKR scheme and the security
+    /// parameters.
+    pub fn domain_separator(&self) -> Vec<u8> {
+        let mut buffer = EXPANDER_TRANSCRIPT_VERSION.to_vec();
+        buffer.push(match C::FIELD_TYPE {
+            FieldType::M31 => 0,
+            FieldType::BN254 => 1,
+            FieldType::GF2 => 2,
+            FieldType::Goldilocks => 3,
+            FieldType::BabyBear => 4,
+        });
+        // e.g., BN254 and BN254x8 only differ in the pack size,
+        // M31Ext3 and M31Ext6 in the challenge field
+        buffer.extend_from_slice(&(C::get_field_pack_size() as u64).to_le_bytes());
+        buffer.extend_from_slice(&(C::ChallengeField::DEGREE as u64).to_le_bytes());
+        buffer.extend_from_slice(C::ChallengeField::NAME.as_bytes());
+        buffer.extend_from_slice(C::FiatShamirHashType::NAME.as_bytes());
+        buffer.push(match self.gkr_scheme {
+            GKRScheme::Vanilla => 0,
+            GKRScheme::GkrSquare => 1,
+        });
+        buffer.push(match self.polynomial_commitment_type {
+            PolynomialCommitmentType::Raw => 0,
+            PolynomialCommitmentType::KZG => 1,
+            PolynomialCommitmentType::Orion => 2,
+            PolynomialCommitmentType::FRI => 3,
+            PolynomialCommitmentType::Hyrax => 4,
+        });
+        buffer.extend_from_slice(&(self.field_size as u64).to_le_bytes());
+        buffer.extend_from_slice(&(self.security_bits as u64).to_le_bytes());
+        buffer.extend_from_slice(&(self.log_blowup as u64).to_le_bytes());
+        #[cfg(feature = "grinding")]
+        buffer.extend_from_slice(&(self.grinding_bits as u64).to_le_bytes());
+        buffer
+    }
 }
 
 pub trait GKRConfig: Default + Clone + Send + Sync + 'static {
     /// Field type for the circuit, e.g., M31
-    type CircuitField: Field + FieldSerde + Send;
+    type CircuitField: ScalarField + FieldSerde + Send;
 
     /// Field type for the challenge, e.g., M31Ext3
-    type ChallengeField: ExtensionField<BaseField = Self::CircuitField> + Send;
+    type ChallengeField: ExtensionField<BaseField = Self::CircuitField> + ScalarField + Send;
 
     /// Main field type for the scheme, e.g., M31Ext3x16
     type Field: ExtensionField<BaseField = Self::SimdCircuitField>
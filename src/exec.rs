@@ -5,30 +5,28 @@ use std::{
     sync::{Arc, Mutex},
 };
 
-use arith::{Field, FieldSerde};
+use arith::FieldSerde;
 use expander_rs::{
-    BN254ConfigSha2, Circuit, Config, FieldType, GKRConfig, GKRScheme, M31ExtConfigSha2, Proof,
-    Prover, Verifier, SENTINEL_BN254, SENTINEL_M31,
+    BN254ConfigSha2, BabyBearExtConfigSha2, Circuit, Config, ExpectedOutputs, FieldType, GKRConfig,
+    GKRScheme, GoldilocksExtConfigSha2, M31ExtConfigSha2, Proof, Prover, Verifier,
+    SENTINEL_BABYBEAR, SENTINEL_BN254, SENTINEL_GOLDILOCKS, SENTINEL_M31,
 };
 use log::{debug, info};
 use warp::{http::StatusCode, reply, Filter};
 
-fn dump_proof_and_claimed_v<F: Field + FieldSerde>(proof: &Proof, claimed_v: &F) -> Vec<u8> {
+// the verifier computes the output claim itself, so only the proof is dumped
+fn dump_proof(proof: &Proof) -> Vec<u8> {
     let mut bytes = Vec::new();
 
     proof.serialize_into(&mut bytes).unwrap(); // TODO: error propagation
-    claimed_v.serialize_into(&mut bytes).unwrap(); // TODO: error propagation
 
     bytes
 }
 
-fn load_proof_and_claimed_v<F: Field + FieldSerde>(bytes: &[u8]) -> (Proof, F) {
+fn load_proof(bytes: &[u8]) -> Proof {
     let mut cursor = Cursor::new(bytes);
 
-    let proof = Proof::deserialize_from(&mut cursor).unwrap(); // TODO: error propagation
-    let claimed_v = F::deserialize_from(&mut cursor).unwrap(); // TODO: error propagation
-
-    (proof, claimed_v)
+    Proof::deserialize_from(&mut cursor).unwrap() // TODO: error propagation
 }
 
 fn detect_field_type_from_circuit_file(circuit_file: &str) -> FieldType {
@@ -38,6 +36,8 @@ fn detect_field_type_from_circuit_file(circuit_file: &str) -> FieldType {
     match field_bytes.try_into().unwrap() {
         SENTINEL_M31 => FieldType::M31,
         SENTINEL_BN254 => FieldType::BN254,
+        SENTINEL_GOLDILOCKS => FieldType::Goldilocks,
+        SENTINEL_BABYBEAR => FieldType::BabyBear,
         _ => {
             println!("Unknown field type. Field byte value: {:?}", field_bytes);
             exit(1);
@@ -60,19 +60,20 @@ async fn run_command<C: GKRConfig>(
             circuit.evaluate();
             let mut prover = Prover::new(&config);
             prover.prepare_mem(&circuit);
-            let (claimed_v, proof) = prover.prove(&mut circuit);
-            let bytes = dump_proof_and_claimed_v(&proof, &claimed_v);
+            let (_claimed_v, proof) = prover.prove(&mut circuit);
+            let bytes = dump_proof(&proof);
             fs::write(output_file, bytes).expect("Unable to write proof to file.");
         }
         "verify" => {
-            let witness_file = &args[3];
+            let public_input_file = &args[3];
             let output_file = &args[4];
             let mut circuit = Circuit::<C>::load_circuit(circuit_file);
-            circuit.load_witness_file(witness_file);
+            circuit.load_public_input_file(public_input_file);
             let bytes = fs::read(output_file).expect("Unable to read proof from file.");
-            let (proof, claimed_v) = load_proof_and_claimed_v(&bytes);
+            let proof = load_proof(&bytes);
             let verifier = Verifier::new(&config);
-            assert!(verifier.verify(&mut circuit, &claimed_v, &proof));
+            // compiled circuits constrain all their outputs to zero
+            assert!(verifier.verify(&mut circuit, &ExpectedOutputs::Zero, &proof));
             println!("success");
         }
         "serve" => {
@@ -108,11 +109,8 @@ async fn run_command<C: GKRConfig>(
                             reply::with_status(vec![], StatusCode::BAD_REQUEST)
                         } else {
                             circuit.evaluate();
-                            let (claimed_v, proof) = prover.prove(&mut circuit);
-                            reply::with_status(
-                                dump_proof_and_claimed_v(&proof, &claimed_v),
-                                StatusCode::OK,
-                            )
+                            let (_claimed_v, proof) = prover.prove(&mut circuit);
+                            reply::with_status(dump_proof(&proof), StatusCode::OK)
                         }
                     });
             let verify =
@@ -120,25 +118,26 @@ async fn run_command<C: GKRConfig>(
                     .and(warp::body::bytes())
                     .map(move |bytes: bytes::Bytes| {
                         info!("Received verify request.");
-                        let witness_and_proof_bytes: Vec<u8> = bytes.to_vec();
-                        let length_of_witness_bytes =
-                            u64::from_le_bytes(witness_and_proof_bytes[0..8].try_into().unwrap())
-                                as usize;
-                        let length_of_proof_bytes =
-                            u64::from_le_bytes(witness_and_proof_bytes[8..16].try_into().unwrap())
-                                as usize;
-                        let witness_bytes =
-                            &witness_and_proof_bytes[16..16 + length_of_witness_bytes];
-                        let proof_bytes = &witness_and_proof_bytes[16 + length_of_witness_bytes
-                            ..16 + length_of_witness_bytes + length_of_proof_bytes];
+                        let public_input_and_proof_bytes: Vec<u8> = bytes.to_vec();
+                        let length_of_public_input_bytes = u64::from_le_bytes(
+                            public_input_and_proof_bytes[0..8].try_into().unwrap(),
+                        ) as usize;
+                        let length_of_proof_bytes = u64::from_le_bytes(
+                            public_input_and_proof_bytes[8..16].try_into().unwrap(),
+                        ) as usize;
+                        let public_input_bytes =
+                            &public_input_and_proof_bytes[16..16 + length_of_public_input_bytes];
+                        let proof_bytes = &public_input_and_proof_bytes[16
+                            + length_of_public_input_bytes
+                            ..16 + length_of_public_input_bytes + length_of_proof_bytes];
 
                         let mut circuit = circuit_clone_for_verifier.lock().unwrap();
                         let verifier = verifier.lock().unwrap();
-                        if circuit.load_witness_bytes(witness_bytes).is_err() {
+                        if circuit.load_public_input_bytes(public_input_bytes).is_err() {
                             "failure".to_string()
                         } else {
-                            let (proof, claimed_v) = load_proof_and_claimed_v(proof_bytes);
-                            if verifier.verify(&mut circuit, &claimed_v, &proof) {
+                            let proof = load_proof(proof_bytes);
+                            if verifier.verify(&mut circuit, &ExpectedOutputs::Zero, &proof) {
                                 "success".to_string()
                             } else {
                                 "failure".to_string()
@@ -163,7 +162,7 @@ async fn run_command<C: GKRConfig>(
 async fn main() {
     // examples:
     // expander-exec prove <input:circuit_file> <input:witness_file> <output:proof>
-    // expander-exec verify <input:circuit_file> <input:witness_file> <input:proof>
+    // expander-exec verify <input:circuit_file> <input:public_input_file> <input:proof>
     // expander-exec serve <input:circuit_file> <input:ip> <input:port>
     env_logger::init();
     let args = std::env::args().collect::<Vec<String>>();
@@ -172,7 +171,7 @@ async fn main() {
             "Usage: expander-exec prove <input:circuit_file> <input:witness_file> <output:proof>"
         );
         println!(
-            "Usage: expander-exec verify <input:circuit_file> <input:witness_file> <input:proof>"
+            "Usage: expander-exec verify <input:circuit_file> <input:public_input_file> <input:proof>"
         );
         println!("Usage: expander-exec serve <input:circuit_file> <input:host> <input:port>");
         return;
@@ -181,6 +180,12 @@ async fn main() {
     let circuit_file = &args[2];
     let field_type = detect_field_type_from_circuit_file(circuit_file);
     debug!("field type: {:?}", field_type);
+    // detect the packed field kernels once, before any proving work
+    let (m31_backend, gf2_128_backend) = (arith::m31_backend(), arith::gf2_128_backend());
+    debug!(
+        "simd kernels: M31 {:?}, GF2_128 {:?}",
+        m31_backend, gf2_128_backend
+    );
     match field_type {
         FieldType::M31 => {
             run_command::<M31ExtConfigSha2>(
@@ -200,6 +205,24 @@ async fn main() {
             )
             .await;
         }
+        FieldType::Goldilocks => {
+            run_command::<GoldilocksExtConfigSha2>(
+                command,
+                circuit_file,
+                Config::<GoldilocksExtConfigSha2>::new(GKRScheme::Vanilla),
+                &args,
+            )
+            .await;
+        }
+        FieldType::BabyBear => {
+            run_command::<BabyBearExtConfigSha2>(
+                command,
+                circuit_file,
+                Config::<BabyBearExtConfigSha2>::new(GKRScheme::Vanilla),
+                &args,
+            )
+            .await;
+        }
         _ => unreachable!(),
     }
 }
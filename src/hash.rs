@@ -5,6 +5,9 @@ pub mod keccak_256;
 pub use self::keccak_256::*;
 
 pub trait FiatShamirHash {
+    /// Name of the hash function, absorbed into the transcript as part of the domain separator
+    const NAME: &'static str;
+
     const DIGEST_SIZE: usize;
 
     fn new() -> Self;
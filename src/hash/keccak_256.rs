@@ -6,6 +6,8 @@ use super::FiatShamirHash;
 pub struct Keccak256hasher {}
 
 impl FiatShamirHash for Keccak256hasher {
+    const NAME: &'static str = "Keccak-256";
+
     const DIGEST_SIZE: usize = 32;
 
     #[inline]
@@ -9,6 +9,8 @@ pub struct SHA256hasher {
 }
 
 impl FiatShamirHash for SHA256hasher {
+    const NAME: &'static str = "SHA-256";
+
     const DIGEST_SIZE: usize = 32;
 
     #[inline]
@@ -6,7 +6,8 @@ use std::{
 use clap::Parser;
 use expander_rs::utils::{KECCAK_GF2_CIRCUIT, KECCAK_M31_CIRCUIT, POSEIDON_CIRCUIT};
 use expander_rs::{
-    BN254ConfigSha2, Circuit, Config, FieldType, GF2ExtConfigSha2, GKRConfig, GKRScheme,
+    BN254ConfigSha2, BN254x8ConfigSha2, BabyBearExtConfigSha2, Circuit, Config, FieldType,
+    GF2ExtConfigSha2, GKRConfig, GKRScheme, GoldilocksExtConfigSha2, M31Ext6ConfigSha2,
     M31ExtConfigSha2, Prover,
 };
 
@@ -14,7 +15,7 @@ use expander_rs::{
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    /// Field Identifier: fr, m31, m31ext3
+    /// Field Identifier: fr, frx8, m31ext3, m31ext6, gf2ext128, goldilocksext2, babybearext4
     #[arg(short, long,default_value_t = String::from("m31ext3"))]
     field: String,
 
@@ -47,6 +48,17 @@ fn main() {
             ),
             _ => unreachable!(),
         },
+        "m31ext6" => match args.scheme.as_str() {
+            "keccak" => run_benchmark::<M31Ext6ConfigSha2>(
+                &args,
+                Config::<M31Ext6ConfigSha2>::new(GKRScheme::Vanilla),
+            ),
+            "poseidon" => run_benchmark::<M31Ext6ConfigSha2>(
+                &args,
+                Config::<M31Ext6ConfigSha2>::new(GKRScheme::GkrSquare),
+            ),
+            _ => unreachable!(),
+        },
         "fr" => match args.scheme.as_str() {
             "keccak" => run_benchmark::<BN254ConfigSha2>(
                 &args,
@@ -58,14 +70,44 @@ fn main() {
             ),
             _ => unreachable!(),
         },
+        "frx8" => match args.scheme.as_str() {
+            "keccak" => run_benchmark::<BN254x8ConfigSha2>(
+                &args,
+                Config::<BN254x8ConfigSha2>::new(GKRScheme::Vanilla),
+            ),
+            "poseidon" => run_benchmark::<BN254x8ConfigSha2>(
+                &args,
+                Config::<BN254x8ConfigSha2>::new(GKRScheme::GkrSquare),
+            ),
+            _ => unreachable!(),
+        },
         "gf2ext128" => match args.scheme.as_str() {
             "keccak" => run_benchmark::<GF2ExtConfigSha2>(
                 &args,
                 Config::<GF2ExtConfigSha2>::new(GKRScheme::Vanilla),
             ),
-            "poseidon" => run_benchmark::<GF2ExtConfigSha2>(
+            "poseidon" => unreachable!("GKR^2 is not supported over GF2"),
+            _ => unreachable!(),
+        },
+        "goldilocksext2" => match args.scheme.as_str() {
+            "keccak" => run_benchmark::<GoldilocksExtConfigSha2>(
+                &args,
+                Config::<GoldilocksExtConfigSha2>::new(GKRScheme::Vanilla),
+            ),
+            "poseidon" => run_benchmark::<GoldilocksExtConfigSha2>(
+                &args,
+                Config::<GoldilocksExtConfigSha2>::new(GKRScheme::GkrSquare),
+            ),
+            _ => unreachable!(),
+        },
+        "babybearext4" => match args.scheme.as_str() {
+            "keccak" => run_benchmark::<BabyBearExtConfigSha2>(
+                &args,
+                Config::<BabyBearExtConfigSha2>::new(GKRScheme::Vanilla),
+            ),
+            "poseidon" => run_benchmark::<BabyBearExtConfigSha2>(
                 &args,
-                Config::<GF2ExtConfigSha2>::new(GKRScheme::GkrSquare),
+                Config::<BabyBearExtConfigSha2>::new(GKRScheme::GkrSquare),
             ),
             _ => unreachable!(),
         },
@@ -85,6 +127,8 @@ fn run_benchmark<C: GKRConfig>(args: &Args, config: Config<C>) {
             FieldType::GF2 => Circuit::<C>::load_circuit(KECCAK_GF2_CIRCUIT),
             FieldType::M31 => Circuit::<C>::load_circuit(KECCAK_M31_CIRCUIT),
             FieldType::BN254 => Circuit::<C>::load_circuit(KECCAK_M31_CIRCUIT),
+            FieldType::Goldilocks => Circuit::<C>::load_circuit(KECCAK_M31_CIRCUIT),
+            FieldType::BabyBear => Circuit::<C>::load_circuit(KECCAK_M31_CIRCUIT),
         },
         "poseidon" => Circuit::<C>::load_circuit(POSEIDON_CIRCUIT),
         _ => unreachable!(),
@@ -94,8 +138,12 @@ fn run_benchmark<C: GKRConfig>(args: &Args, config: Config<C>) {
         (FieldType::GF2, "keccak") => 1,
         (FieldType::M31, "keccak") => 2,
         (FieldType::BN254, "keccak") => 2,
+        (FieldType::Goldilocks, "keccak") => 2,
+        (FieldType::BabyBear, "keccak") => 2,
         (FieldType::M31, "poseidon") => 120,
         (FieldType::BN254, "poseidon") => 120,
+        (FieldType::Goldilocks, "poseidon") => 120,
+        (FieldType::BabyBear, "poseidon") => 120,
         _ => unreachable!(),
     };
 
@@ -162,5 +210,11 @@ fn print_info(args: &Args) {
     println!("#threads:       {}", args.threads);
     println!("#bench repeats: {}", args.repeats);
     println!("hash scheme:    {}", args.scheme);
+    println!(
+        "simd kernels:   M31 {:?}, GF2_128 {:?}, BN254 {:?}",
+        arith::m31_backend(),
+        arith::gf2_128_backend(),
+        arith::bn254_backend()
+    );
     println!("===============================")
 }
@@ -1,7 +1,87 @@
+use std::io::{Read, Write};
+
+use arith::FieldSerdeResult;
+use ark_std::rand::RngCore;
+
+use crate::{Config, GKRConfig, PolynomialCommitmentType, Transcript};
+
 pub mod raw;
 pub use self::raw::*;
 
 pub mod poly;
 pub use self::poly::*;
 
-pub trait PolynomialCommitment {}
+pub mod bi_kzg;
+pub use self::bi_kzg::*;
+
+pub mod hyrax;
+pub use self::hyrax::*;
+
+pub mod merkle;
+pub use self::merkle::*;
+
+pub mod orion;
+pub use self::orion::*;
+
+pub mod basefold;
+pub use self::basefold::*;
+
+/// Polynomial commitment scheme for the input layer of the circuit.
+///
+/// The committed polynomial is given by its evaluations over the boolean hypercube,
+/// one simd circuit field element per point, i.e., a multilinear polynomial
+/// in `num_vars` variables plus the simd variables.
+pub trait PolynomialCommitment<C: GKRConfig> {
+    /// The variant absorbed into the transcript as part of the domain separator
+    const TYPE: PolynomialCommitmentType;
+
+    /// Public parameters, e.g., the structured reference string
+    type Params: Clone + Send + Sync;
+
+    type Commitment;
+
+    type Opening;
+
+    /// Generate the public parameters for polynomials with up to `num_vars` variables,
+    /// at the security level of `config`
+    fn setup(config: &Config<C>, num_vars: usize, rng: impl RngCore) -> Self::Params;
+
+    fn commit(params: &Self::Params, poly: &[C::SimdCircuitField]) -> Self::Commitment;
+
+    /// Prove the evaluation of `poly` at `(x, r_simd)`
+    fn open(
+        params: &Self::Params,
+        poly: &[C::SimdCircuitField],
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening;
+
+    /// Check that the committed polynomial evaluates to `v` at `(x, r_simd)`
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        v: C::ChallengeField,
+        opening: &Self::Opening,
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool;
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        writer: W,
+    ) -> FieldSerdeResult<()>;
+
+    fn deserialize_commitment<R: Read>(
+        params: &Self::Params,
+        reader: R,
+    ) -> FieldSerdeResult<Self::Commitment>;
+
+    fn serialize_opening<W: Write>(opening: &Self::Opening, writer: W) -> FieldSerdeResult<()>;
+
+    fn deserialize_opening<R: Read>(
+        params: &Self::Params,
+        reader: R,
+    ) -> FieldSerdeResult<Self::Opening>;
+}
@@ -0,0 +1,592 @@
+//! Basefold-style multilinear commitment, for the M31 configurations.
+//!
+//! The simd lanes are flattened into the lowest variables, so the committed polynomial is the
+//! multilinear polynomial over the circuit field with evaluations `w`.
+//! `w` is encoded with a random foldable code: `Enc_0(c)` repeats `c`, and for `w = (w_l, w_r)`,
+//! `Enc_k(w)[j] = c_l[j] + t_k[j] c_d[j]` and `Enc_k(w)[j + half] = c_l[j] - t_k[j] c_d[j]`,
+//! with `c_l = Enc_{k-1}(w_l)`, `c_d = Enc_{k-1}(w_r - w_l)` and twiddles `t_k` derived from
+//! `BASEFOLD_SEED`.
+//! Folding a codeword at `r` then gives the codeword of `w_l + r (w_r - w_l)`, the same
+//! as binding the highest variable to `r`.
+//!
+//! The evaluation at `z` is proven with a sumcheck on `\sum_b w(b) eq(z, b)`, binding the
+//! variables from the highest down, with the codeword folded and committed at every round.
+//! The final codeword is the repetition of the claimed `w(r)`, and random queries check that
+//! the folds are consistent across all the committed codewords.
+
+use std::io::{Read, Write};
+
+use arith::{batch_inversion, Field, FieldSerde, FieldSerdeResult, ScalarField, SimdField};
+use ark_std::{end_timer, rand::RngCore, start_timer};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    eq_evals_at_primitive, Config, FieldType, GKRConfig, MerkleDigest, MerkleMultiPath, MerkleTree,
+    PolynomialCommitment, PolynomialCommitmentType, Transcript,
+};
+
+/// Default log2 of the inverse rate of the code
+pub const BASEFOLD_LOG_BLOWUP: usize = 2;
+
+/// Seed the Basefold twiddles are derived from.
+pub const BASEFOLD_SEED: &[u8] = b"Expander Basefold";
+
+#[derive(Clone, Debug)]
+pub struct BasefoldParams<F> {
+    /// Number of variables, including the simd variables
+    pub num_vars: usize,
+    pub log_blowup: usize,
+    pub num_queries: usize,
+    /// The twiddles t_k for k in 1..=num_vars, t_k having 2^(k - 1 + log_blowup) entries
+    pub twiddles: Vec<Vec<F>>,
+    /// Their inverses, used to fold
+    pub twiddle_invs: Vec<Vec<F>>,
+}
+
+impl<F: ScalarField> BasefoldParams<F> {
+    /// The twiddles are derived from `seed`, so that the code is public and fixed.
+    pub fn new(num_vars: usize, log_blowup: usize, security_bits: usize, seed: &[u8]) -> Self {
+        // a query catches a fold inconsistent with a far codeword with probability
+        // (1 - rate) / 2, the unique decoding radius.
+        // This assumes the code has relative distance about 1 - rate, as an MDS code would:
+        // the Basefold paper shows random foldable codes get close to it with high probability
+        // over the twiddles when the field is large, which is not checked for the fixed twiddles.
+        let rate = 1.0 / (1 << log_blowup) as f64;
+        let num_queries = (security_bits as f64 / -((1.0 + rate) / 2.0).log2()).ceil() as usize;
+
+        let twiddles = (1..=num_vars)
+            .map(|k| {
+                (0..1usize << (k - 1 + log_blowup))
+                    .map(|j| Self::twiddle(seed, k, j))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let twiddle_invs = twiddles
+            .iter()
+            .map(|t_k| {
+                let mut t_k_inv = t_k.clone();
+                batch_inversion(&mut t_k_inv);
+                t_k_inv
+            })
+            .collect();
+
+        Self {
+            num_vars,
+            log_blowup,
+            num_queries,
+            twiddles,
+            twiddle_invs,
+        }
+    }
+
+    // The nonzero twiddle t_k[j], hashed from the seed
+    fn twiddle(seed: &[u8], k: usize, j: usize) -> F {
+        (0u64..)
+            .map(|counter| {
+                let digest = Sha256::new()
+                    .chain_update(seed)
+                    .chain_update((k as u64).to_le_bytes())
+                    .chain_update((j as u64).to_le_bytes())
+                    .chain_update(counter.to_le_bytes())
+                    .finalize();
+                F::from_uniform_bytes(&digest.into())
+            })
+            .find(|t| !t.is_zero())
+            .unwrap()
+    }
+
+    /// Half the length of the codeword at the given round, i.e., its number of Merkle leaves
+    #[inline]
+    pub fn leaf_num(&self, round: usize) -> usize {
+        1 << (self.num_vars - round - 1 + self.log_blowup)
+    }
+
+    /// Number of queries: every leaf once if there are too few of them
+    #[inline]
+    pub fn query_num(&self) -> usize {
+        self.num_queries.min(self.leaf_num(0))
+    }
+
+    /// Encode the evaluations, whose length is 2^num_vars
+    pub fn encode(&self, evals: &[F]) -> Vec<F> {
+        let k = evals.len().trailing_zeros() as usize;
+        if k == 0 {
+            return vec![evals[0]; 1 << self.log_blowup];
+        }
+
+        let half = evals.len() >> 1;
+        let diff = evals[half..]
+            .iter()
+            .zip(evals[..half].iter())
+            .map(|(r, l)| *r - l)
+            .collect::<Vec<_>>();
+        let c_l = self.encode(&evals[..half]);
+        let c_d = self.encode(&diff);
+
+        let t_k = &self.twiddles[k - 1];
+        let mut codeword = vec![F::zero(); c_l.len() * 2];
+        for j in 0..c_l.len() {
+            let t_c_d = t_k[j] * c_d[j];
+            codeword[j] = c_l[j] + t_c_d;
+            codeword[j + c_l.len()] = c_l[j] - t_c_d;
+        }
+        codeword
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BasefoldCommitment {
+    pub root: MerkleDigest,
+}
+
+/// The pairs of entries opened by a query, one per round
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BasefoldQuery<C: GKRConfig> {
+    pub leaf: (C::CircuitField, C::CircuitField),
+    pub folded_leaves: Vec<(C::ChallengeField, C::ChallengeField)>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BasefoldOpening<C: GKRConfig> {
+    /// Evaluations of the sumcheck polynomial at 0, 1, 2, one triple per round
+    pub sumcheck_evals: Vec<[C::ChallengeField; 3]>,
+    /// Roots of the folded codewords, all but the last one
+    pub folded_roots: Vec<MerkleDigest>,
+    /// The value of the polynomial at the sumcheck challenges
+    pub final_value: C::ChallengeField,
+    pub queries: Vec<BasefoldQuery<C>>,
+    /// The Merkle paths of the queried pairs, one for the codeword and one per folded codeword
+    pub path: MerkleMultiPath,
+    pub folded_paths: Vec<MerkleMultiPath>,
+}
+
+/// Basefold polynomial commitment, for the M31 configurations.
+#[derive(Clone, Debug, Default)]
+pub struct Basefold;
+
+impl Basefold {
+    fn flatten<C: GKRConfig>(poly: &[C::SimdCircuitField]) -> Vec<C::CircuitField> {
+        poly.iter().flat_map(|v| v.unpack()).collect()
+    }
+
+    // The simd variables are the lowest ones
+    fn point<C: GKRConfig>(
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+    ) -> Vec<C::ChallengeField> {
+        r_simd.iter().chain(x.iter()).cloned().collect()
+    }
+
+    #[inline]
+    fn leaf_hash<C: GKRConfig, F: Field + FieldSerde>(a: &F, b: &F) -> MerkleDigest {
+        MerkleTree::<C::FiatShamirHashType>::hash_field_leaf(&[*a, *b])
+    }
+
+    fn merkle_tree<C: GKRConfig, F: Field + FieldSerde>(
+        codeword: &[F],
+    ) -> MerkleTree<C::FiatShamirHashType> {
+        let half = codeword.len() >> 1;
+        let leaves = (0..half)
+            .map(|j| [codeword[j], codeword[j + half]])
+            .collect::<Vec<_>>();
+        MerkleTree::from_field_leaves(&leaves)
+    }
+
+    // The entry of the folded codeword from the pair at j and j + half
+    #[inline]
+    fn fold<C: GKRConfig>(
+        a: &C::ChallengeField,
+        b: &C::ChallengeField,
+        twiddle_inv: &C::CircuitField,
+        r: &C::ChallengeField,
+    ) -> C::ChallengeField {
+        let d = C::challenge_mul_circuit_field(&(*a - b), twiddle_inv);
+        (*a + b + *r * d) * C::ChallengeField::INV_2
+    }
+
+    // The degree 2 sumcheck polynomial at r, from its evaluations at 0, 1, 2
+    #[inline]
+    fn degree_2_eval<F: Field>(evals: &[F; 3], r: &F) -> F {
+        let one = F::one();
+        let two = one.double();
+        (evals[0] * (*r - one) * (*r - two) + evals[2] * *r * (*r - one)) * F::INV_2
+            - evals[1] * *r * (*r - two)
+    }
+
+    fn absorb<C: GKRConfig, F: FieldSerde>(
+        values: &[F],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) {
+        let mut buffer = vec![];
+        values
+            .iter()
+            .for_each(|v| v.serialize_into(&mut buffer).unwrap());
+        transcript.append_public_u8_slice(&buffer);
+    }
+
+    fn query_indices<C: GKRConfig>(
+        params: &BasefoldParams<C::CircuitField>,
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Vec<usize> {
+        let leaf_num = params.leaf_num(0);
+        if params.num_queries >= leaf_num {
+            (0..leaf_num).collect()
+        } else {
+            transcript.challenge_indices(params.num_queries, leaf_num)
+        }
+    }
+}
+
+impl<C: GKRConfig> PolynomialCommitment<C> for Basefold
+where
+    C::CircuitField: Sync,
+{
+    const TYPE: PolynomialCommitmentType = PolynomialCommitmentType::FRI;
+
+    type Params = BasefoldParams<C::CircuitField>;
+
+    type Commitment = BasefoldCommitment;
+
+    type Opening = BasefoldOpening<C>;
+
+    /// Transparent -- the twiddles are derived from `BASEFOLD_SEED`, and `rng` is unused.
+    fn setup(config: &Config<C>, num_vars: usize, _rng: impl RngCore) -> Self::Params {
+        // folding divides by two
+        assert_ne!(C::FIELD_TYPE, FieldType::GF2);
+        BasefoldParams::new(
+            num_vars + C::get_field_pack_size().trailing_zeros() as usize,
+            config.log_blowup,
+            config.security_bits,
+            BASEFOLD_SEED,
+        )
+    }
+
+    fn commit(params: &Self::Params, poly: &[C::SimdCircuitField]) -> Self::Commitment {
+        let timer = start_timer!(|| format!("basefold commit with {} vars", params.num_vars));
+        let evals = Self::flatten::<C>(poly);
+        assert_eq!(evals.len(), 1 << params.num_vars);
+        let root = Self::merkle_tree::<C, _>(&params.encode(&evals)).root();
+        end_timer!(timer);
+        BasefoldCommitment { root }
+    }
+
+    fn open(
+        params: &Self::Params,
+        poly: &[C::SimdCircuitField],
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening {
+        let timer = start_timer!(|| format!("basefold open with {} vars", params.num_vars));
+        let num_vars = params.num_vars;
+        let point = Self::point::<C>(x, r_simd);
+        assert_eq!(point.len(), num_vars);
+
+        // the commitment does not keep the prover data, so encode again
+        let evals = Self::flatten::<C>(poly);
+        let codeword = params.encode(&evals);
+        let tree = Self::merkle_tree::<C, _>(&codeword);
+
+        let mut f = evals
+            .iter()
+            .map(|v| C::ChallengeField::from(*v))
+            .collect::<Vec<_>>();
+        let mut eq = vec![C::ChallengeField::zero(); 1 << num_vars];
+        eq_evals_at_primitive(&point, &C::ChallengeField::one(), &mut eq);
+        let mut folded_codeword = codeword
+            .iter()
+            .map(|v| C::ChallengeField::from(*v))
+            .collect::<Vec<_>>();
+
+        let mut sumcheck_evals = vec![];
+        let mut folded_roots = vec![];
+        let mut folded_trees = vec![];
+        let mut folded_codewords = vec![];
+        for round in 0..num_vars {
+            let half = f.len() >> 1;
+            let mut h = [C::ChallengeField::zero(); 3];
+            for j in 0..half {
+                h[0] += f[j] * eq[j];
+                h[1] += f[j + half] * eq[j + half];
+                h[2] += (f[j + half].double() - f[j]) * (eq[j + half].double() - eq[j]);
+            }
+            Self::absorb::<C, _>(&h, transcript);
+            sumcheck_evals.push(h);
+
+            let r = transcript.challenge_f::<C>();
+            for j in 0..half {
+                f[j] = f[j] + (f[j + half] - f[j]) * r;
+                eq[j] = eq[j] + (eq[j + half] - eq[j]) * r;
+            }
+            f.truncate(half);
+            eq.truncate(half);
+
+            let leaf_num = params.leaf_num(round);
+            let twiddle_invs = &params.twiddle_invs[num_vars - round - 1];
+            folded_codeword = (0..leaf_num)
+                .map(|j| {
+                    Self::fold::<C>(
+                        &folded_codeword[j],
+                        &folded_codeword[j + leaf_num],
+                        &twiddle_invs[j],
+                        &r,
+                    )
+                })
+                .collect();
+
+            if round + 1 < num_vars {
+                let folded_tree = Self::merkle_tree::<C, _>(&folded_codeword);
+                transcript.append_public_u8_slice(&folded_tree.root());
+                folded_roots.push(folded_tree.root());
+                folded_trees.push(folded_tree);
+                folded_codewords.push(folded_codeword.clone());
+            }
+        }
+        let final_value = f[0];
+        Self::absorb::<C, _>(&[final_value], transcript);
+
+        let mut indices = Self::query_indices::<C>(params, transcript);
+        let leaf_num = params.leaf_num(0);
+        let mut queries = indices
+            .iter()
+            .map(|j| BasefoldQuery {
+                leaf: (codeword[*j], codeword[j + leaf_num]),
+                folded_leaves: vec![],
+            })
+            .collect::<Vec<_>>();
+        let path = tree.multi_path(&indices);
+        let mut folded_paths = vec![];
+        for (round, (folded_codeword, folded_tree)) in
+            folded_codewords.iter().zip(folded_trees.iter()).enumerate()
+        {
+            let leaf_num = params.leaf_num(round + 1);
+            indices.iter_mut().for_each(|j| *j %= leaf_num);
+            for (query, j) in queries.iter_mut().zip(indices.iter()) {
+                query
+                    .folded_leaves
+                    .push((folded_codeword[*j], folded_codeword[j + leaf_num]));
+            }
+            folded_paths.push(folded_tree.multi_path(&indices));
+        }
+
+        end_timer!(timer);
+        BasefoldOpening {
+            sumcheck_evals,
+            folded_roots,
+            final_value,
+            queries,
+            path,
+            folded_paths,
+        }
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        v: C::ChallengeField,
+        opening: &Self::Opening,
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool {
+        let num_vars = params.num_vars;
+        let point = Self::point::<C>(x, r_simd);
+        if point.len() != num_vars
+            || r_simd.len() != C::get_field_pack_size().trailing_zeros() as usize
+            || opening.sumcheck_evals.len() != num_vars
+            || opening.folded_roots.len() != num_vars.saturating_sub(1)
+            || opening.queries.len() != params.query_num()
+            || opening
+                .queries
+                .iter()
+                .any(|query| query.folded_leaves.len() != num_vars.saturating_sub(1))
+            || opening.folded_paths.len() != num_vars.saturating_sub(1)
+        {
+            return false;
+        }
+
+        // the sumcheck, binding the variables from the highest down
+        let mut claim = v;
+        let mut eq_at_r = C::ChallengeField::one();
+        let mut rs = vec![];
+        for (round, h) in opening.sumcheck_evals.iter().enumerate() {
+            if h[0] + h[1] != claim {
+                return false;
+            }
+            Self::absorb::<C, _>(h, transcript);
+            let r = transcript.challenge_f::<C>();
+            claim = Self::degree_2_eval(h, &r);
+
+            let z = point[num_vars - round - 1];
+            eq_at_r *= z * r + (C::ChallengeField::one() - z) * (C::ChallengeField::one() - r);
+            rs.push(r);
+            if round + 1 < num_vars {
+                transcript.append_public_u8_slice(&opening.folded_roots[round]);
+            }
+        }
+        Self::absorb::<C, _>(&[opening.final_value], transcript);
+        if claim != opening.final_value * eq_at_r {
+            return false;
+        }
+
+        // the queried pairs of every round are in the committed codewords
+        let indices = Self::query_indices::<C>(params, transcript);
+        let depth = |round: usize| params.leaf_num(round).trailing_zeros() as usize;
+        let leaves = opening
+            .queries
+            .iter()
+            .map(|query| Self::leaf_hash::<C, _>(&query.leaf.0, &query.leaf.1))
+            .collect::<Vec<_>>();
+        if !opening.path.verify::<C::FiatShamirHashType>(
+            &commitment.root,
+            depth(0),
+            &indices,
+            &leaves,
+        ) {
+            return false;
+        }
+        let mut folded_indices = indices.clone();
+        for round in 1..num_vars {
+            let leaf_num = params.leaf_num(round);
+            folded_indices.iter_mut().for_each(|j| *j %= leaf_num);
+            let leaves = opening
+                .queries
+                .iter()
+                .map(|query| {
+                    let (a, b) = &query.folded_leaves[round - 1];
+                    Self::leaf_hash::<C, _>(a, b)
+                })
+                .collect::<Vec<_>>();
+            if !opening.folded_paths[round - 1].verify::<C::FiatShamirHashType>(
+                &opening.folded_roots[round - 1],
+                depth(round),
+                &folded_indices,
+                &leaves,
+            ) {
+                return false;
+            }
+        }
+
+        // every query folds consistently down to the final value
+        indices
+            .iter()
+            .zip(opening.queries.iter())
+            .all(|(j, query)| {
+                let mut j = *j;
+                let mut folded = Self::fold::<C>(
+                    &C::ChallengeField::from(query.leaf.0),
+                    &C::ChallengeField::from(query.leaf.1),
+                    &params.twiddle_invs[num_vars - 1][j],
+                    &rs[0],
+                );
+
+                for round in 1..num_vars {
+                    let leaf_num = params.leaf_num(round);
+                    let (a, b) = &query.folded_leaves[round - 1];
+                    let expected = if j < leaf_num { a } else { b };
+                    j %= leaf_num;
+                    if folded != *expected {
+                        return false;
+                    }
+                    folded = Self::fold::<C>(
+                        a,
+                        b,
+                        &params.twiddle_invs[num_vars - round - 1][j],
+                        &rs[round],
+                    );
+                }
+                folded == opening.final_value
+            })
+    }
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        mut writer: W,
+    ) -> FieldSerdeResult<()> {
+        writer.write_all(&commitment.root)?;
+        Ok(())
+    }
+
+    fn deserialize_commitment<R: Read>(
+        _params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Commitment> {
+        let mut root = MerkleDigest::default();
+        reader.read_exact(&mut root)?;
+        Ok(BasefoldCommitment { root })
+    }
+
+    fn serialize_opening<W: Write>(opening: &Self::Opening, mut writer: W) -> FieldSerdeResult<()> {
+        for v in opening.sumcheck_evals.iter().flatten() {
+            v.serialize_into(&mut writer)?;
+        }
+        for root in opening.folded_roots.iter() {
+            writer.write_all(root)?;
+        }
+        opening.final_value.serialize_into(&mut writer)?;
+
+        for query in opening.queries.iter() {
+            query.leaf.0.serialize_into(&mut writer)?;
+            query.leaf.1.serialize_into(&mut writer)?;
+            for (a, b) in query.folded_leaves.iter() {
+                a.serialize_into(&mut writer)?;
+                b.serialize_into(&mut writer)?;
+            }
+        }
+        opening.path.serialize_into(&mut writer)?;
+        for path in opening.folded_paths.iter() {
+            path.serialize_into(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_opening<R: Read>(
+        params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Opening> {
+        let num_vars = params.num_vars;
+        let mut opening = BasefoldOpening::<C>::default();
+        for _ in 0..num_vars {
+            opening.sumcheck_evals.push([
+                C::ChallengeField::deserialize_from(&mut reader)?,
+                C::ChallengeField::deserialize_from(&mut reader)?,
+                C::ChallengeField::deserialize_from(&mut reader)?,
+            ]);
+        }
+        for _ in 0..num_vars.saturating_sub(1) {
+            let mut root = MerkleDigest::default();
+            reader.read_exact(&mut root)?;
+            opening.folded_roots.push(root);
+        }
+        opening.final_value = C::ChallengeField::deserialize_from(&mut reader)?;
+
+        for _ in 0..params.query_num() {
+            let mut query = BasefoldQuery::<C> {
+                leaf: (
+                    C::CircuitField::deserialize_from(&mut reader)?,
+                    C::CircuitField::deserialize_from(&mut reader)?,
+                ),
+                ..Default::default()
+            };
+            for _ in 1..num_vars {
+                query.folded_leaves.push((
+                    C::ChallengeField::deserialize_from(&mut reader)?,
+                    C::ChallengeField::deserialize_from(&mut reader)?,
+                ));
+            }
+            opening.queries.push(query);
+        }
+
+        let depth = |round: usize| params.leaf_num(round).trailing_zeros() as usize;
+        opening.path =
+            MerkleMultiPath::deserialize_from(&mut reader, depth(0), params.query_num())?;
+        for round in 1..num_vars {
+            opening.folded_paths.push(MerkleMultiPath::deserialize_from(
+                &mut reader,
+                depth(round),
+                params.query_num(),
+            )?);
+        }
+        Ok(opening)
+    }
+}
@@ -0,0 +1,432 @@
+//! Bi-KZG commitment to the input layer, for the BN254 configurations.
+//!
+//! The multilinear polynomial with evaluations `w` over the hypercube is laid out as the
+//! bivariate polynomial `f(X, Y) = \sum_{i, j} w_{i + j 2^{n_0}} X^i Y^j`,
+//! i.e., the first `n_0` variables go to `X` and the remaining ones to `Y`.
+//!
+//! The evaluation of the multilinear extension at `x` is proven by folding `f` one variable at
+//! a time, as in Gemini. Writing `f_k(X, Y) = E(X^2, Y) + X O(X^2, Y)`, the next polynomial is
+//! `f_{k+1} = (1 - x_k) E + x_k O`, and its value at `(a^2, b)` follows from the values of `f_k`
+//! at `(a, b)` and `(-a, b)`. Once `X` is exhausted, the folds go along `Y` in the same way.
+//! The last fold is the constant `f_n`, which is the claimed evaluation.
+
+use std::io::{Read, Write};
+
+use arith::{Field, FieldSerde, FieldSerdeError, FieldSerdeResult};
+use ark_std::{end_timer, rand::RngCore, start_timer};
+use bi_kzg::{
+    BiKZGBatchProof, BiKZGCommitment, BiKZGProof, BiKZGSRS, BiKZGVerifierParam,
+    BivariatePolynomial, CoeffFormBiKZG, PolynomialCommitmentScheme,
+};
+use halo2curves::{
+    bn256::{Bn256, Fr, G1Affine},
+    group::GroupEncoding,
+};
+
+use crate::{Config, GKRConfig, PolynomialCommitment, PolynomialCommitmentType, Transcript};
+
+/// Public parameters: the SRS for polynomials of degree `2^log_degree_0` in X
+/// and `2^log_degree_1` in Y.
+#[derive(Clone, Debug)]
+pub struct BiKZGParams {
+    pub srs: BiKZGSRS<Bn256>,
+    pub vk: BiKZGVerifierParam<Bn256>,
+    pub log_degree_0: usize,
+    pub log_degree_1: usize,
+}
+
+impl BiKZGParams {
+    pub fn new(srs: BiKZGSRS<Bn256>, log_degree_0: usize, log_degree_1: usize) -> Self {
+        assert_eq!(srs.powers_of_g.len(), 1 << (log_degree_0 + log_degree_1));
+        let vk = BiKZGVerifierParam::from(&srs);
+        Self {
+            srs,
+            vk,
+            log_degree_0,
+            log_degree_1,
+        }
+    }
+
+    #[inline]
+    pub fn degree_0(&self) -> usize {
+        1 << self.log_degree_0
+    }
+
+    #[inline]
+    pub fn degree_1(&self) -> usize {
+        1 << self.log_degree_1
+    }
+
+    /// Number of variables of a `num_vars` variate polynomial that go to X
+    #[inline]
+    pub fn x_var_num(&self, num_vars: usize) -> usize {
+        num_vars.min(self.log_degree_0)
+    }
+
+    #[inline]
+    pub fn supports(&self, num_vars: usize) -> bool {
+        num_vars <= self.log_degree_0 + self.log_degree_1
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BiKZGOpening {
+    /// Commitments to the folded polynomials f_1, ..., f_{n-1}
+    pub folded_commitments: Vec<BiKZGCommitment<Bn256>>,
+    /// f_k at its point, for k in 0..n; a single opening of f_0 if n = 0
+    pub evals: Vec<Fr>,
+    pub proofs: Vec<BiKZGProof<Bn256>>,
+    /// f_k at its point with the folded coordinate negated, for k in 0..n
+    pub neg_evals: Vec<Fr>,
+    pub neg_proofs: Vec<BiKZGProof<Bn256>>,
+}
+
+/// Bi-KZG polynomial commitment for configurations over the BN254 scalar field.
+#[derive(Clone, Debug, Default)]
+pub struct BiKZG;
+
+impl BiKZG {
+    /// Lay out the evaluations over the hypercube as the coefficients of a bivariate polynomial,
+    /// padded to the degrees supported by the SRS.
+    pub fn bivariate_layout(params: &BiKZGParams, poly: &[Fr]) -> BivariatePolynomial<Fr> {
+        assert!(poly.len().is_power_of_two());
+        let num_vars = poly.len().trailing_zeros() as usize;
+        assert!(params.supports(num_vars));
+
+        let degree_0 = params.degree_0();
+        let mut coefficients = vec![Fr::zero(); degree_0 * params.degree_1()];
+        poly.chunks(1 << params.x_var_num(num_vars))
+            .enumerate()
+            .for_each(|(j, row)| {
+                coefficients[j * degree_0..j * degree_0 + row.len()].copy_from_slice(row)
+            });
+        BivariatePolynomial::new(coefficients, degree_0, params.degree_1())
+    }
+
+    // Fold the next variable at r, along X while it has more than one coefficient, then along Y.
+    // x_len and y_len are the number of coefficients in use in each direction.
+    fn fold(
+        poly: &BivariatePolynomial<Fr>,
+        x_len: usize,
+        y_len: usize,
+        r: &Fr,
+    ) -> BivariatePolynomial<Fr> {
+        let degree_0 = poly.degree_0;
+        let c = &poly.coefficients;
+        let mut coefficients = vec![Fr::zero(); c.len()];
+        if x_len > 1 {
+            for j in 0..y_len {
+                for i in 0..x_len / 2 {
+                    let even = c[2 * i + j * degree_0];
+                    let odd = c[2 * i + 1 + j * degree_0];
+                    coefficients[i + j * degree_0] = even + (odd - even) * r;
+                }
+            }
+        } else {
+            for j in 0..y_len / 2 {
+                let even = c[2 * j * degree_0];
+                let odd = c[(2 * j + 1) * degree_0];
+                coefficients[j * degree_0] = even + (odd - even) * r;
+            }
+        }
+        BivariatePolynomial::new(coefficients, degree_0, poly.degree_1)
+    }
+
+    // The points f_0, ..., f_{n-1} are opened at, starting from (beta, gamma) and squaring
+    // the coordinate being folded.
+    fn fold_points(num_vars: usize, x_var_num: usize, beta: Fr, gamma: Fr) -> Vec<(Fr, Fr)> {
+        let mut point = (beta, gamma);
+        let mut points = vec![];
+        for k in 0..num_vars.max(1) {
+            points.push(point);
+            if k < x_var_num {
+                point.0 = point.0.square();
+            } else {
+                point.1 = point.1.square();
+            }
+        }
+        points
+    }
+
+    #[inline]
+    fn neg_point(point: &(Fr, Fr), k: usize, x_var_num: usize) -> (Fr, Fr) {
+        if k < x_var_num {
+            (-point.0, point.1)
+        } else {
+            (point.0, -point.1)
+        }
+    }
+
+    // The value of the folded polynomial at the squared coordinate,
+    // from the values at the coordinate and its negation
+    #[inline]
+    fn fold_evals(eval: &Fr, neg_eval: &Fr, coordinate: &Fr, r: &Fr) -> Option<Fr> {
+        let even = (*eval + neg_eval) * Fr::INV_2;
+        let odd = (*eval - neg_eval) * Fr::INV_2 * coordinate.inv()?;
+        Some(even + (odd - even) * r)
+    }
+
+    fn absorb_folded_commitments<C: GKRConfig<ChallengeField = Fr>>(
+        folded_commitments: &[BiKZGCommitment<Bn256>],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> (Fr, Fr) {
+        let mut buffer = vec![];
+        folded_commitments
+            .iter()
+            .for_each(|com| serialize_g1(&com.com, &mut buffer).unwrap());
+        transcript.append_public_u8_slice(&buffer);
+        (transcript.challenge_f::<C>(), transcript.challenge_f::<C>())
+    }
+}
+
+pub(crate) fn serialize_g1<W: Write>(point: &G1Affine, mut writer: W) -> FieldSerdeResult<()> {
+    writer.write_all(point.to_bytes().as_ref())?;
+    Ok(())
+}
+
+pub(crate) fn deserialize_g1<R: Read>(mut reader: R) -> FieldSerdeResult<G1Affine> {
+    let mut repr = <G1Affine as GroupEncoding>::Repr::default();
+    reader.read_exact(repr.as_mut())?;
+    G1Affine::from_bytes(&repr)
+        .into_option()
+        .ok_or(FieldSerdeError::DeserializeError)
+}
+
+fn serialize_proof<W: Write>(proof: &BiKZGProof<Bn256>, mut writer: W) -> FieldSerdeResult<()> {
+    serialize_g1(&proof.pi0, &mut writer)?;
+    serialize_g1(&proof.pi1, &mut writer)
+}
+
+fn deserialize_proof<R: Read>(mut reader: R) -> FieldSerdeResult<BiKZGProof<Bn256>> {
+    let pi0 = deserialize_g1(&mut reader)?;
+    let pi1 = deserialize_g1(&mut reader)?;
+    Ok(BiKZGProof { pi0, pi1 })
+}
+
+impl<C> PolynomialCommitment<C> for BiKZG
+where
+    C: GKRConfig<ChallengeField = Fr, SimdCircuitField = Fr>,
+{
+    const TYPE: PolynomialCommitmentType = PolynomialCommitmentType::KZG;
+
+    type Params = BiKZGParams;
+
+    type Commitment = BiKZGCommitment<Bn256>;
+
+    type Opening = BiKZGOpening;
+
+    /// Test only -- the trapdoor is sampled from `rng`.
+    fn setup(_config: &Config<C>, num_vars: usize, rng: impl RngCore) -> Self::Params {
+        let log_degree_0 = (num_vars + 1) / 2;
+        let log_degree_1 = num_vars - log_degree_0;
+        let srs =
+            CoeffFormBiKZG::<Bn256>::gen_srs_for_testing(rng, 1 << log_degree_0, 1 << log_degree_1);
+        BiKZGParams::new(srs, log_degree_0, log_degree_1)
+    }
+
+    fn commit(params: &Self::Params, poly: &[Fr]) -> Self::Commitment {
+        CoeffFormBiKZG::<Bn256>::commit(&params.srs, &Self::bivariate_layout(params, poly))
+    }
+
+    fn open(
+        params: &Self::Params,
+        poly: &[Fr],
+        x: &[Fr],
+        r_simd: &[Fr],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening {
+        let timer = start_timer!(|| format!("bi-kzg open with {} vars", x.len()));
+        assert_eq!(poly.len(), 1 << x.len());
+        // BN254 has no simd lanes
+        assert!(r_simd.is_empty());
+
+        let num_vars = x.len();
+        let x_var_num = params.x_var_num(num_vars);
+
+        let mut polys = vec![Self::bivariate_layout(params, poly)];
+        let mut x_len = 1 << x_var_num;
+        let mut y_len = 1 << (num_vars - x_var_num);
+        for r in x.iter().take(num_vars.saturating_sub(1)) {
+            polys.push(Self::fold(polys.last().unwrap(), x_len, y_len, r));
+            if x_len > 1 {
+                x_len >>= 1;
+            } else {
+                y_len >>= 1;
+            }
+        }
+
+        let folded_commitments = polys[1..]
+            .iter()
+            .map(|poly| CoeffFormBiKZG::<Bn256>::commit(&params.srs, poly))
+            .collect::<Vec<_>>();
+        let (beta, gamma) = Self::absorb_folded_commitments::<C>(&folded_commitments, transcript);
+
+        let points = Self::fold_points(num_vars, x_var_num, beta, gamma);
+        let (proofs, evals) = polys
+            .iter()
+            .zip(points.iter())
+            .map(|(poly, point)| CoeffFormBiKZG::<Bn256>::open(&params.srs, poly, point))
+            .unzip();
+        let (neg_proofs, neg_evals) = polys
+            .iter()
+            .zip(points.iter())
+            .take(num_vars)
+            .enumerate()
+            .map(|(k, (poly, point))| {
+                CoeffFormBiKZG::<Bn256>::open(
+                    &params.srs,
+                    poly,
+                    &Self::neg_point(point, k, x_var_num),
+                )
+            })
+            .unzip();
+
+        end_timer!(timer);
+        BiKZGOpening {
+            folded_commitments,
+            evals,
+            proofs,
+            neg_evals,
+            neg_proofs,
+        }
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[Fr],
+        r_simd: &[Fr],
+        v: Fr,
+        opening: &Self::Opening,
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool {
+        let num_vars = x.len();
+        if !r_simd.is_empty()
+            || !params.supports(num_vars)
+            || opening.folded_commitments.len() != num_vars.saturating_sub(1)
+            || opening.evals.len() != num_vars.max(1)
+            || opening.proofs.len() != num_vars.max(1)
+            || opening.neg_evals.len() != num_vars
+            || opening.neg_proofs.len() != num_vars
+        {
+            return false;
+        }
+        let x_var_num = params.x_var_num(num_vars);
+
+        let (beta, gamma) =
+            Self::absorb_folded_commitments::<C>(&opening.folded_commitments, transcript);
+        let points = Self::fold_points(num_vars, x_var_num, beta, gamma);
+        let commitments = std::iter::once(commitment)
+            .chain(opening.folded_commitments.iter())
+            .collect::<Vec<_>>();
+
+        // every claimed value is a valid opening, checked as a single batch
+        let batch_commitments = commitments
+            .iter()
+            .chain(commitments.iter().take(num_vars))
+            .map(|com| (*com).clone())
+            .collect::<Vec<_>>();
+        let batch_points = points
+            .iter()
+            .cloned()
+            .chain(
+                points
+                    .iter()
+                    .take(num_vars)
+                    .enumerate()
+                    .map(|(k, point)| Self::neg_point(point, k, x_var_num)),
+            )
+            .collect::<Vec<_>>();
+        // the points are distinct, so there is one proof per opening
+        let batch_proof = BiKZGBatchProof {
+            proofs: [opening.proofs.clone(), opening.neg_proofs.clone()].concat(),
+            evals: [opening.evals.clone(), opening.neg_evals.clone()].concat(),
+        };
+        if !CoeffFormBiKZG::<Bn256>::batch_verify(
+            &params.vk,
+            &batch_commitments,
+            &batch_points,
+            &batch_proof,
+        ) {
+            return false;
+        }
+
+        if num_vars == 0 {
+            return opening.evals[0] == v;
+        }
+
+        // every fold is consistent with the next polynomial, and the last one is the claim
+        (0..num_vars).all(|k| {
+            let coordinate = if k < x_var_num {
+                &points[k].0
+            } else {
+                &points[k].1
+            };
+            let expected = if k + 1 < num_vars {
+                opening.evals[k + 1]
+            } else {
+                v
+            };
+            Self::fold_evals(&opening.evals[k], &opening.neg_evals[k], coordinate, &x[k])
+                == Some(expected)
+        })
+    }
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        writer: W,
+    ) -> FieldSerdeResult<()> {
+        serialize_g1(&commitment.com, writer)
+    }
+
+    fn deserialize_commitment<R: Read>(
+        _params: &Self::Params,
+        reader: R,
+    ) -> FieldSerdeResult<Self::Commitment> {
+        Ok(BiKZGCommitment {
+            com: deserialize_g1(reader)?,
+        })
+    }
+
+    fn serialize_opening<W: Write>(opening: &Self::Opening, mut writer: W) -> FieldSerdeResult<()> {
+        (opening.neg_evals.len() as u64).serialize_into(&mut writer)?;
+        for com in opening.folded_commitments.iter() {
+            serialize_g1(&com.com, &mut writer)?;
+        }
+        for (eval, proof) in opening.evals.iter().zip(opening.proofs.iter()) {
+            eval.serialize_into(&mut writer)?;
+            serialize_proof(proof, &mut writer)?;
+        }
+        for (eval, proof) in opening.neg_evals.iter().zip(opening.neg_proofs.iter()) {
+            eval.serialize_into(&mut writer)?;
+            serialize_proof(proof, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_opening<R: Read>(
+        params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Opening> {
+        let num_vars = u64::deserialize_from(&mut reader)? as usize;
+        if !params.supports(num_vars) {
+            return Err(FieldSerdeError::DeserializeError);
+        }
+
+        let mut opening = BiKZGOpening::default();
+        for _ in 0..num_vars.saturating_sub(1) {
+            opening.folded_commitments.push(BiKZGCommitment {
+                com: deserialize_g1(&mut reader)?,
+            });
+        }
+        for _ in 0..num_vars.max(1) {
+            opening.evals.push(Fr::deserialize_from(&mut reader)?);
+            opening.proofs.push(deserialize_proof(&mut reader)?);
+        }
+        for _ in 0..num_vars {
+            opening.neg_evals.push(Fr::deserialize_from(&mut reader)?);
+            opening.neg_proofs.push(deserialize_proof(&mut reader)?);
+        }
+        Ok(opening)
+    }
+}
@@ -0,0 +1,122 @@
+//! Hyrax commitment to the input layer, for the BN254 configurations.
+//!
+//! Unlike Bi-KZG, it needs no trusted setup: the Pedersen generators are hashed to the curve.
+//! The evaluations are laid out as a matrix with the first half of the variables indexing the
+//! columns, each row is committed separately, and the opening at `x` is the vector-matrix
+//! product of the eq evaluations at the row variables with the matrix.
+
+use std::io::{Read, Write};
+
+use arith::{FieldSerde, FieldSerdeError, FieldSerdeResult};
+use ark_std::rand::RngCore;
+use bi_kzg::{
+    Hyrax as HyraxScheme, HyraxCommitment, HyraxProof, HyraxSRS, PolynomialCommitmentScheme,
+};
+use halo2curves::bn256::{Fr, G1Affine};
+
+use super::bi_kzg::{deserialize_g1, serialize_g1};
+use crate::{Config, GKRConfig, PolynomialCommitment, PolynomialCommitmentType, Transcript};
+
+/// Seed the Hyrax generators are derived from.
+pub const HYRAX_SEED: &[u8] = b"Expander Hyrax";
+
+/// Hyrax polynomial commitment for configurations over the BN254 scalar field.
+#[derive(Clone, Debug, Default)]
+pub struct Hyrax;
+
+impl<C> PolynomialCommitment<C> for Hyrax
+where
+    C: GKRConfig<ChallengeField = Fr, SimdCircuitField = Fr>,
+{
+    const TYPE: PolynomialCommitmentType = PolynomialCommitmentType::Hyrax;
+
+    type Params = HyraxSRS<G1Affine>;
+
+    type Commitment = HyraxCommitment<G1Affine>;
+
+    type Opening = HyraxProof<G1Affine>;
+
+    /// Transparent -- the generators are derived from `HYRAX_SEED`, and `rng` is unused.
+    fn setup(_config: &Config<C>, num_vars: usize, _rng: impl RngCore) -> Self::Params {
+        HyraxScheme::<G1Affine>::setup(num_vars, HYRAX_SEED)
+    }
+
+    fn commit(params: &Self::Params, poly: &[Fr]) -> Self::Commitment {
+        HyraxScheme::<G1Affine>::commit(params, &poly.to_vec())
+    }
+
+    fn open(
+        params: &Self::Params,
+        poly: &[Fr],
+        x: &[Fr],
+        r_simd: &[Fr],
+        _transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening {
+        // BN254 has no simd lanes
+        assert!(r_simd.is_empty());
+        HyraxScheme::<G1Affine>::open(params, &poly.to_vec(), &x.to_vec()).0
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[Fr],
+        r_simd: &[Fr],
+        v: Fr,
+        opening: &Self::Opening,
+        _transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool {
+        r_simd.is_empty()
+            && HyraxScheme::<G1Affine>::verify(params, commitment, &x.to_vec(), &v, opening)
+    }
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        mut writer: W,
+    ) -> FieldSerdeResult<()> {
+        (commitment.row_comms.len() as u64).serialize_into(&mut writer)?;
+        for com in commitment.row_comms.iter() {
+            serialize_g1(com, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_commitment<R: Read>(
+        _params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Commitment> {
+        let row_num = u64::deserialize_from(&mut reader)? as usize;
+        if !row_num.is_power_of_two() {
+            return Err(FieldSerdeError::DeserializeError);
+        }
+        // not preallocated, the length is not trusted
+        let mut row_comms = vec![];
+        for _ in 0..row_num {
+            row_comms.push(deserialize_g1(&mut reader)?);
+        }
+        Ok(HyraxCommitment { row_comms })
+    }
+
+    fn serialize_opening<W: Write>(opening: &Self::Opening, mut writer: W) -> FieldSerdeResult<()> {
+        (opening.combined_row.len() as u64).serialize_into(&mut writer)?;
+        for v in opening.combined_row.iter() {
+            v.serialize_into(&mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_opening<R: Read>(
+        params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Opening> {
+        let col_num = u64::deserialize_from(&mut reader)? as usize;
+        if col_num > params.generators.len() {
+            return Err(FieldSerdeError::DeserializeError);
+        }
+        let mut combined_row = Vec::with_capacity(col_num);
+        for _ in 0..col_num {
+            combined_row.push(Fr::deserialize_from(&mut reader)?);
+        }
+        Ok(HyraxProof { combined_row })
+    }
+}
@@ -0,0 +1,293 @@
+//! Merkle tree over the Fiat-Shamir hash, used by the hash-based commitments.
+
+use std::{
+    io::{Read, Write},
+    marker::PhantomData,
+};
+
+use arith::{FieldSerde, FieldSerdeError, FieldSerdeResult};
+
+use crate::FiatShamirHash;
+
+pub const MERKLE_DIGEST_SIZE: usize = 32;
+
+pub type MerkleDigest = [u8; MERKLE_DIGEST_SIZE];
+
+// Leaves and internal nodes are hashed with different prefixes
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+#[derive(Clone, Debug)]
+pub struct MerkleTree<H: FiatShamirHash> {
+    /// nodes[1] is the root, the children of nodes[i] are nodes[2i] and nodes[2i + 1],
+    /// and the leaves are nodes[n..2n]
+    nodes: Vec<MerkleDigest>,
+    _phantom: PhantomData<H>,
+}
+
+impl<H: FiatShamirHash> MerkleTree<H> {
+    // Evaluated when the hashes below are instantiated, so a hash with another digest size
+    // does not compile
+    const DIGEST_SIZE_CHECK: () = assert!(
+        H::DIGEST_SIZE == MERKLE_DIGEST_SIZE,
+        "the Merkle tree needs a 32-byte digest"
+    );
+
+    pub fn new(leaves: &[MerkleDigest]) -> Self {
+        let leaf_num = leaves.len();
+        assert!(leaf_num.is_power_of_two());
+
+        let mut nodes = vec![MerkleDigest::default(); leaf_num * 2];
+        nodes[leaf_num..].copy_from_slice(leaves);
+        for i in (1..leaf_num).rev() {
+            nodes[i] = Self::hash_node(&nodes[i * 2], &nodes[i * 2 + 1]);
+        }
+        Self {
+            nodes,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The tree over leaves holding field elements
+    pub fn from_field_leaves<F: FieldSerde, L: AsRef<[F]>>(leaves: &[L]) -> Self {
+        let leaves = leaves
+            .iter()
+            .map(|leaf| Self::hash_field_leaf(leaf.as_ref()))
+            .collect::<Vec<_>>();
+        Self::new(&leaves)
+    }
+
+    #[inline]
+    pub fn hash_leaf(data: &[u8]) -> MerkleDigest {
+        let () = Self::DIGEST_SIZE_CHECK;
+        let mut input = vec![LEAF_PREFIX];
+        input.extend_from_slice(data);
+        let mut output = MerkleDigest::default();
+        H::new().hash(&mut output, &input);
+        output
+    }
+
+    /// Hash a leaf holding field elements, in their `FieldSerde` serialization
+    #[inline]
+    pub fn hash_field_leaf<F: FieldSerde>(leaf: &[F]) -> MerkleDigest {
+        let mut buffer = vec![];
+        leaf.iter()
+            .for_each(|v| v.serialize_into(&mut buffer).unwrap());
+        Self::hash_leaf(&buffer)
+    }
+
+    #[inline]
+    pub fn hash_node(left: &MerkleDigest, right: &MerkleDigest) -> MerkleDigest {
+        let () = Self::DIGEST_SIZE_CHECK;
+        let mut input = vec![NODE_PREFIX];
+        input.extend_from_slice(left);
+        input.extend_from_slice(right);
+        let mut output = MerkleDigest::default();
+        H::new().hash(&mut output, &input);
+        output
+    }
+
+    #[inline]
+    pub fn root(&self) -> MerkleDigest {
+        self.nodes[1]
+    }
+
+    #[inline]
+    pub fn leaf_num(&self) -> usize {
+        self.nodes.len() >> 1
+    }
+
+    /// Authentication path of the leaf at `idx`, from the bottom up
+    pub fn path(&self, idx: usize) -> MerklePath {
+        let mut node_idx = idx + self.leaf_num();
+        let mut siblings = vec![];
+        while node_idx > 1 {
+            siblings.push(self.nodes[node_idx ^ 1]);
+            node_idx >>= 1;
+        }
+        MerklePath { siblings }
+    }
+
+    /// Batched authentication paths of the leaves at `indices`, sharing the common nodes
+    pub fn multi_path(&self, indices: &[usize]) -> MerkleMultiPath {
+        let nodes = MerkleMultiPath::sibling_nodes(self.leaf_num(), indices)
+            .into_iter()
+            .map(|node_idx| self.nodes[node_idx])
+            .collect();
+        MerkleMultiPath { nodes }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerklePath {
+    pub siblings: Vec<MerkleDigest>,
+}
+
+impl MerklePath {
+    pub fn verify<H: FiatShamirHash>(
+        &self,
+        root: &MerkleDigest,
+        idx: usize,
+        leaf: &MerkleDigest,
+    ) -> bool {
+        if idx.checked_shr(self.siblings.len() as u32).unwrap_or(0) != 0 {
+            return false;
+        }
+
+        let mut node_idx = idx;
+        let mut node = *leaf;
+        for sibling in self.siblings.iter() {
+            node = if node_idx & 1 == 0 {
+                MerkleTree::<H>::hash_node(&node, sibling)
+            } else {
+                MerkleTree::<H>::hash_node(sibling, &node)
+            };
+            node_idx >>= 1;
+        }
+        node == *root
+    }
+
+    #[inline]
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        for sibling in self.siblings.iter() {
+            writer.write_all(sibling)?;
+        }
+        Ok(())
+    }
+
+    /// Deserialize a path in a tree with `2^depth` leaves
+    #[inline]
+    pub fn deserialize_from<R: Read>(mut reader: R, depth: usize) -> FieldSerdeResult<Self> {
+        let mut siblings = vec![MerkleDigest::default(); depth];
+        for sibling in siblings.iter_mut() {
+            reader.read_exact(sibling)?;
+        }
+        Ok(Self { siblings })
+    }
+}
+
+/// Authentication paths of several leaves, with every node that can be recomputed from the
+/// leaves, or that is shared by several paths, left out.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MerkleMultiPath {
+    /// The siblings needed, from the bottom up and from left to right on each level
+    pub nodes: Vec<MerkleDigest>,
+}
+
+impl MerkleMultiPath {
+    // Indices in the tree of the siblings needed to authenticate the leaves at `indices`,
+    // in the order they are used
+    fn sibling_nodes(leaf_num: usize, indices: &[usize]) -> Vec<usize> {
+        let mut level = indices.iter().map(|idx| idx + leaf_num).collect::<Vec<_>>();
+        level.sort_unstable();
+        level.dedup();
+
+        let mut siblings = vec![];
+        while level.first().map_or(false, |node_idx| *node_idx > 1) {
+            let mut i = 0;
+            while i < level.len() {
+                if level[i] & 1 == 0 && level.get(i + 1) == Some(&(level[i] + 1)) {
+                    i += 2;
+                } else {
+                    siblings.push(level[i] ^ 1);
+                    i += 1;
+                }
+            }
+            level.iter_mut().for_each(|node_idx| *node_idx >>= 1);
+            level.dedup();
+        }
+        siblings
+    }
+
+    /// Check the leaves at `indices` against the root of a tree with `2^depth` leaves.
+    /// An index may appear several times, with the same leaf.
+    pub fn verify<H: FiatShamirHash>(
+        &self,
+        root: &MerkleDigest,
+        depth: usize,
+        indices: &[usize],
+        leaves: &[MerkleDigest],
+    ) -> bool {
+        let leaf_num = match 1usize.checked_shl(depth as u32) {
+            Some(leaf_num) => leaf_num,
+            None => return false,
+        };
+        if indices.is_empty()
+            || indices.len() != leaves.len()
+            || indices.iter().any(|idx| *idx >= leaf_num)
+        {
+            return false;
+        }
+
+        let mut level = indices
+            .iter()
+            .map(|idx| idx + leaf_num)
+            .zip(leaves.iter().cloned())
+            .collect::<Vec<_>>();
+        level.sort_unstable_by_key(|(node_idx, _)| *node_idx);
+        if level
+            .windows(2)
+            .any(|pair| pair[0].0 == pair[1].0 && pair[0].1 != pair[1].1)
+        {
+            return false;
+        }
+        level.dedup_by_key(|(node_idx, _)| *node_idx);
+
+        let mut siblings = self.nodes.iter();
+        while level[0].0 > 1 {
+            let mut next_level = vec![];
+            let mut i = 0;
+            while i < level.len() {
+                let (node_idx, node) = level[i];
+                let parent = if node_idx & 1 == 0
+                    && level.get(i + 1).map(|(idx, _)| *idx) == Some(node_idx + 1)
+                {
+                    i += 2;
+                    MerkleTree::<H>::hash_node(&node, &level[i - 1].1)
+                } else {
+                    i += 1;
+                    let sibling = match siblings.next() {
+                        Some(sibling) => sibling,
+                        None => return false,
+                    };
+                    if node_idx & 1 == 0 {
+                        MerkleTree::<H>::hash_node(&node, sibling)
+                    } else {
+                        MerkleTree::<H>::hash_node(sibling, &node)
+                    }
+                };
+                next_level.push((node_idx >> 1, parent));
+            }
+            level = next_level;
+        }
+        siblings.next().is_none() && level[0].1 == *root
+    }
+
+    /// The nodes, prefixed by their number, as the reader of a proof learns the indices
+    /// from the transcript only after the proof is deserialized
+    #[inline]
+    pub fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        (self.nodes.len() as u64).serialize_into(&mut writer)?;
+        for node in self.nodes.iter() {
+            writer.write_all(node)?;
+        }
+        Ok(())
+    }
+
+    /// Deserialize the paths of `leaf_num` leaves in a tree with `2^depth` leaves
+    pub fn deserialize_from<R: Read>(
+        mut reader: R,
+        depth: usize,
+        leaf_num: usize,
+    ) -> FieldSerdeResult<Self> {
+        let node_num = u64::deserialize_from(&mut reader)? as usize;
+        if node_num > leaf_num.saturating_mul(depth) {
+            return Err(FieldSerdeError::DeserializeError);
+        }
+        let mut nodes = vec![MerkleDigest::default(); node_num];
+        for node in nodes.iter_mut() {
+            reader.read_exact(node)?;
+        }
+        Ok(Self { nodes })
+    }
+}
@@ -0,0 +1,545 @@
+//! Orion-style commitment for the small fields.
+//!
+//! The evaluations are arranged into a matrix with `2^log_row_num` rows, the low variables
+//! indexing the columns. Every row is encoded with a linear-time expander code over the
+//! challenge field, and the columns of the encoded matrix are committed with a Merkle tree.
+//! The code is derived from `ORION_SEED`, so that whoever runs the setup has no say in it.
+//!
+//! To open at `(x, r_simd)`, the prover sends the combination of the rows by `eq(x_row, .)`,
+//! from which the verifier computes the evaluation, and a random combination of the rows
+//! for the proximity test. Both are checked against the encoded columns at random positions.
+
+use std::io::{Read, Write};
+
+use arith::{Field, FieldSerde, FieldSerdeResult, SimdField};
+use ark_std::{end_timer, rand::RngCore, start_timer};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    eq_evals_at_primitive, Config, GKRConfig, MerkleDigest, MerkleMultiPath, MerkleTree,
+    MultiLinearPoly, PolynomialCommitment, PolynomialCommitmentType, Transcript,
+};
+
+/// Seed the Orion code is derived from.
+pub const ORION_SEED: &[u8] = b"Expander Orion";
+
+/// Relative distance of the expander code with the parameters below.
+///
+/// This is an assumption, not a derived bound: the random sparse matrices are not checked to be
+/// good expanders, and the value is a conservative guess below the distances reported for
+/// Spielman-style codes of rate 1/4 with similar degrees. The number of queries, hence the
+/// soundness, is only as good as this assumption. It also needs the random nonzero coefficients
+/// to come from a large field, hence the code over the challenge field: over GF2 itself they
+/// would all be one.
+pub const ORION_CODE_DISTANCE: f64 = 0.05;
+
+// Messages up to this length are encoded with a dense code
+const ORION_CODE_BASE_LEN: usize = 16;
+// Number of outputs of every input in the two sparse matrices of the recursion
+const ORION_CODE_DEGREE_A: usize = 10;
+const ORION_CODE_DEGREE_B: usize = 20;
+
+/// The public randomness of the code, hashed from a seed and a counter
+struct CodeSampler<'a> {
+    seed: &'a [u8],
+    counter: u64,
+}
+
+impl<'a> CodeSampler<'a> {
+    fn new(seed: &'a [u8]) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    fn digest(&mut self) -> [u8; 32] {
+        let digest = Sha256::new()
+            .chain_update(self.seed)
+            .chain_update(self.counter.to_le_bytes())
+            .finalize();
+        self.counter += 1;
+        digest.into()
+    }
+
+    #[inline]
+    fn index(&mut self, len: usize) -> usize {
+        (u64::from_le_bytes(self.digest()[..8].try_into().unwrap()) % len as u64) as usize
+    }
+
+    #[inline]
+    fn field<F: Field>(&mut self) -> F {
+        F::from_uniform_bytes(&self.digest())
+    }
+
+    #[inline]
+    fn nonzero<F: Field>(&mut self) -> F {
+        loop {
+            let f = self.field::<F>();
+            if !f.is_zero() {
+                return f;
+            }
+        }
+    }
+}
+
+/// Sparse matrix, stored as the outgoing edges of every input
+#[derive(Clone, Debug)]
+struct SparseMatrix<F> {
+    out_len: usize,
+    edges: Vec<Vec<(usize, F)>>,
+}
+
+impl<F: Field> SparseMatrix<F> {
+    fn sample(in_len: usize, out_len: usize, degree: usize, sampler: &mut CodeSampler) -> Self {
+        let edges = (0..in_len)
+            .map(|_| {
+                (0..degree.min(out_len))
+                    .map(|_| (sampler.index(out_len), sampler.nonzero()))
+                    .collect()
+            })
+            .collect();
+        Self { out_len, edges }
+    }
+
+    fn apply<T: Field>(&self, input: &[T], mul: &impl Fn(&F, &T) -> T) -> Vec<T> {
+        let mut output = vec![T::zero(); self.out_len];
+        for (x, edges) in input.iter().zip(self.edges.iter()) {
+            for (o, coef) in edges.iter() {
+                output[*o] += mul(coef, x);
+            }
+        }
+        output
+    }
+}
+
+#[derive(Clone, Debug)]
+enum OrionCodeLayer<F> {
+    /// Systematic code with a dense random parity matrix
+    Base { parity: Vec<Vec<F>> },
+    /// Spielman's recursive construction: the message x is followed by z = Enc(A x) and B z
+    Recursive {
+        a: SparseMatrix<F>,
+        inner: Box<OrionCode<F>>,
+        b: SparseMatrix<F>,
+    },
+}
+
+/// Linear-time encodable code of rate 1/4 for messages whose length is a power of two,
+/// with coefficients in `F`.
+#[derive(Clone, Debug)]
+pub struct OrionCode<F> {
+    msg_len: usize,
+    layer: OrionCodeLayer<F>,
+}
+
+impl<F: Field> OrionCode<F> {
+    /// The code with its coefficients hashed from `seed`
+    pub fn new(msg_len: usize, seed: &[u8]) -> Self {
+        Self::sample(msg_len, &mut CodeSampler::new(seed))
+    }
+
+    fn sample(msg_len: usize, sampler: &mut CodeSampler) -> Self {
+        assert!(msg_len.is_power_of_two());
+
+        let layer = if msg_len <= ORION_CODE_BASE_LEN {
+            let parity = (0..3 * msg_len)
+                .map(|_| (0..msg_len).map(|_| sampler.field()).collect())
+                .collect();
+            OrionCodeLayer::Base { parity }
+        } else {
+            // |x| + |z| + |B z| = k + 2k + k
+            let a = SparseMatrix::sample(msg_len, msg_len / 2, ORION_CODE_DEGREE_A, sampler);
+            let inner = Box::new(Self::sample(msg_len / 2, sampler));
+            let b = SparseMatrix::sample(msg_len * 2, msg_len, ORION_CODE_DEGREE_B, sampler);
+            OrionCodeLayer::Recursive { a, inner, b }
+        };
+        Self { msg_len, layer }
+    }
+
+    #[inline]
+    pub fn msg_len(&self) -> usize {
+        self.msg_len
+    }
+
+    #[inline]
+    pub fn codeword_len(&self) -> usize {
+        self.msg_len * 4
+    }
+
+    /// Encode a message over any field the coefficients act on, through `mul`
+    pub fn encode<T: Field>(&self, msg: &[T], mul: &impl Fn(&F, &T) -> T) -> Vec<T> {
+        assert_eq!(msg.len(), self.msg_len);
+
+        let mut codeword = msg.to_vec();
+        match &self.layer {
+            OrionCodeLayer::Base { parity } => {
+                codeword.extend(parity.iter().map(|row| {
+                    row.iter()
+                        .zip(msg.iter())
+                        .fold(T::zero(), |acc, (c, m)| acc + mul(c, m))
+                }));
+            }
+            OrionCodeLayer::Recursive { a, inner, b } => {
+                let z = inner.encode(&a.apply(msg, mul), mul);
+                let v = b.apply(&z, mul);
+                codeword.extend(z);
+                codeword.extend(v);
+            }
+        }
+        codeword
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct OrionParams<F> {
+    pub num_vars: usize,
+    pub log_row_num: usize,
+    pub num_queries: usize,
+    pub code: OrionCode<F>,
+}
+
+impl<F: Field> OrionParams<F> {
+    pub fn new(num_vars: usize, security_bits: usize, seed: &[u8]) -> Self {
+        // every distinct query catches a codeword far from the code with probability distance / 3
+        let num_queries =
+            (security_bits as f64 / -(1.0 - ORION_CODE_DISTANCE / 3.0).log2()).ceil() as usize;
+
+        // a query opens a whole column, while the two combined rows are sent in full,
+        // so balance 2^log_row_num * num_queries against 2^(num_vars - log_row_num)
+        let log_num_queries = num_queries.next_power_of_two().trailing_zeros() as usize;
+        let log_row_num = ((num_vars + 1).saturating_sub(log_num_queries) / 2).min(num_vars);
+
+        let code = OrionCode::new(1 << (num_vars - log_row_num), seed);
+        Self {
+            num_vars,
+            log_row_num,
+            num_queries,
+            code,
+        }
+    }
+
+    #[inline]
+    pub fn row_num(&self) -> usize {
+        1 << self.log_row_num
+    }
+
+    /// Number of distinct columns opened: every column once if there are too few of them.
+    /// The columns are sampled without replacement, so every query counts toward soundness.
+    #[inline]
+    pub fn query_num(&self) -> usize {
+        self.num_queries.min(self.code.codeword_len())
+    }
+
+    #[inline]
+    pub fn codeword_depth(&self) -> usize {
+        self.code.codeword_len().trailing_zeros() as usize
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OrionCommitment {
+    pub root: MerkleDigest,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OrionOpening<C: GKRConfig> {
+    /// The rows combined by eq(x_row, .)
+    pub eval_row: Vec<C::Field>,
+    /// The rows combined by random coefficients
+    pub proximity_row: Vec<C::Field>,
+    /// The queried columns of the encoded matrix, with their Merkle paths
+    pub query_columns: Vec<Vec<C::Field>>,
+    pub query_path: MerkleMultiPath,
+}
+
+/// Orion polynomial commitment, for the M31 and GF2 configurations.
+#[derive(Clone, Debug, Default)]
+pub struct Orion;
+
+impl Orion {
+    // The code coefficients are in the challenge field, so the encoded rows are over `C::Field`
+    fn encode_rows<C: GKRConfig>(
+        params: &OrionParams<C::ChallengeField>,
+        poly: &[C::SimdCircuitField],
+    ) -> Vec<Vec<C::Field>> {
+        poly.chunks(params.code.msg_len())
+            .map(|row| {
+                let row = row
+                    .iter()
+                    .map(C::simd_circuit_field_into_field)
+                    .collect::<Vec<_>>();
+                params.code.encode(&row, &C::challenge_mul_field)
+            })
+            .collect()
+    }
+
+    fn merkle_tree<C: GKRConfig>(
+        params: &OrionParams<C::ChallengeField>,
+        encoded_rows: &[Vec<C::Field>],
+    ) -> MerkleTree<C::FiatShamirHashType> {
+        let columns = (0..params.code.codeword_len())
+            .map(|j| Self::column::<C>(encoded_rows, j))
+            .collect::<Vec<_>>();
+        MerkleTree::from_field_leaves(&columns)
+    }
+
+    #[inline]
+    fn column<C: GKRConfig>(encoded_rows: &[Vec<C::Field>], j: usize) -> Vec<C::Field> {
+        encoded_rows.iter().map(|row| row[j]).collect()
+    }
+
+    // The rows of the matrix combined by the coefficients
+    fn combine_rows<C: GKRConfig>(
+        poly: &[C::SimdCircuitField],
+        coefs: &[C::ChallengeField],
+        row_len: usize,
+    ) -> Vec<C::Field> {
+        let mut combined = vec![C::Field::zero(); row_len];
+        for (row, coef) in poly.chunks(row_len).zip(coefs.iter()) {
+            for (c, v) in combined.iter_mut().zip(row.iter()) {
+                *c += C::simd_circuit_field_mul_challenge_field(v, coef);
+            }
+        }
+        combined
+    }
+
+    #[inline]
+    fn combine_column<C: GKRConfig>(column: &[C::Field], coefs: &[C::ChallengeField]) -> C::Field {
+        column
+            .iter()
+            .zip(coefs.iter())
+            .fold(C::Field::zero(), |acc, (v, coef)| {
+                acc + C::challenge_mul_field(coef, v)
+            })
+    }
+
+    #[inline]
+    fn eq_evals<C: GKRConfig>(r: &[C::ChallengeField]) -> Vec<C::ChallengeField> {
+        let mut eq_evals = vec![C::ChallengeField::zero(); 1 << r.len()];
+        eq_evals_at_primitive(r, &C::ChallengeField::one(), &mut eq_evals);
+        eq_evals
+    }
+
+    // Absorb the two combined rows, then sample the columns to query
+    fn query_indices<C: GKRConfig>(
+        params: &OrionParams<C::ChallengeField>,
+        eval_row: &[C::Field],
+        proximity_row: &[C::Field],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Vec<usize> {
+        let mut buffer = vec![];
+        eval_row
+            .iter()
+            .chain(proximity_row.iter())
+            .for_each(|v| v.serialize_into(&mut buffer).unwrap());
+        transcript.append_public_u8_slice(&buffer);
+
+        let codeword_len = params.code.codeword_len();
+        if params.num_queries >= codeword_len {
+            (0..codeword_len).collect()
+        } else {
+            transcript.challenge_distinct_indices(params.num_queries, codeword_len)
+        }
+    }
+}
+
+impl<C: GKRConfig> PolynomialCommitment<C> for Orion
+where
+    C::ChallengeField: Sync,
+{
+    const TYPE: PolynomialCommitmentType = PolynomialCommitmentType::Orion;
+
+    type Params = OrionParams<C::ChallengeField>;
+
+    type Commitment = OrionCommitment;
+
+    type Opening = OrionOpening<C>;
+
+    /// Transparent -- the code is derived from `ORION_SEED`, and `rng` is unused.
+    fn setup(config: &Config<C>, num_vars: usize, _rng: impl RngCore) -> Self::Params {
+        OrionParams::new(num_vars, config.security_bits, ORION_SEED)
+    }
+
+    fn commit(params: &Self::Params, poly: &[C::SimdCircuitField]) -> Self::Commitment {
+        let timer = start_timer!(|| format!("orion commit with {} vars", params.num_vars));
+        assert_eq!(poly.len(), 1 << params.num_vars);
+        let encoded_rows = Self::encode_rows::<C>(params, poly);
+        let root = Self::merkle_tree::<C>(params, &encoded_rows).root();
+        end_timer!(timer);
+        OrionCommitment { root }
+    }
+
+    fn open(
+        params: &Self::Params,
+        poly: &[C::SimdCircuitField],
+        x: &[C::ChallengeField],
+        _r_simd: &[C::ChallengeField],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening {
+        let timer = start_timer!(|| format!("orion open with {} vars", params.num_vars));
+        assert_eq!(x.len(), params.num_vars);
+        assert_eq!(poly.len(), 1 << params.num_vars);
+
+        // the commitment does not keep the prover data, so encode again
+        let encoded_rows = Self::encode_rows::<C>(params, poly);
+        let tree = Self::merkle_tree::<C>(params, &encoded_rows);
+
+        let msg_len = params.code.msg_len();
+        let eq_row = Self::eq_evals::<C>(&x[params.num_vars - params.log_row_num..]);
+        let proximity_coefs = transcript.challenge_fs::<C>(params.row_num());
+        let eval_row = Self::combine_rows::<C>(poly, &eq_row, msg_len);
+        let proximity_row = Self::combine_rows::<C>(poly, &proximity_coefs, msg_len);
+
+        let indices = Self::query_indices::<C>(params, &eval_row, &proximity_row, transcript);
+        let query_columns = indices
+            .iter()
+            .map(|j| Self::column::<C>(&encoded_rows, *j))
+            .collect();
+        let query_path = tree.multi_path(&indices);
+
+        end_timer!(timer);
+        OrionOpening {
+            eval_row,
+            proximity_row,
+            query_columns,
+            query_path,
+        }
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        v: C::ChallengeField,
+        opening: &Self::Opening,
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool {
+        let msg_len = params.code.msg_len();
+        if x.len() != params.num_vars
+            || opening.eval_row.len() != msg_len
+            || opening.proximity_row.len() != msg_len
+            || opening.query_columns.len() != params.query_num()
+            || opening
+                .query_columns
+                .iter()
+                .any(|column| column.len() != params.row_num())
+        {
+            return false;
+        }
+
+        let (x_col, x_row) = x.split_at(params.num_vars - params.log_row_num);
+        let eq_row = Self::eq_evals::<C>(x_row);
+        let proximity_coefs = transcript.challenge_fs::<C>(params.row_num());
+        let indices = Self::query_indices::<C>(
+            params,
+            &opening.eval_row,
+            &opening.proximity_row,
+            transcript,
+        );
+
+        // the combined rows are consistent with the committed columns
+        let encoded_eval_row = params
+            .code
+            .encode(&opening.eval_row, &C::challenge_mul_field);
+        let encoded_proximity_row = params
+            .code
+            .encode(&opening.proximity_row, &C::challenge_mul_field);
+        let leaves = opening
+            .query_columns
+            .iter()
+            .map(|column| MerkleTree::<C::FiatShamirHashType>::hash_field_leaf(column))
+            .collect::<Vec<_>>();
+        let paths_verified = opening.query_path.verify::<C::FiatShamirHashType>(
+            &commitment.root,
+            params.codeword_depth(),
+            &indices,
+            &leaves,
+        );
+        let columns_verified =
+            indices
+                .iter()
+                .zip(opening.query_columns.iter())
+                .all(|(j, column)| {
+                    Self::combine_column::<C>(column, &eq_row) == encoded_eval_row[*j]
+                        && Self::combine_column::<C>(column, &proximity_coefs)
+                            == encoded_proximity_row[*j]
+                });
+
+        // the evaluation follows from the rows combined by eq(x_row, .)
+        let eq_col = Self::eq_evals::<C>(x_col);
+        let v_simd = opening
+            .eval_row
+            .iter()
+            .zip(eq_col.iter())
+            .fold(C::Field::zero(), |acc, (y, eq)| {
+                acc + C::challenge_mul_field(eq, y)
+            });
+        let v_simd_unpacked = v_simd.unpack();
+        let mut scratch = vec![C::ChallengeField::default(); v_simd_unpacked.len()];
+
+        paths_verified
+            && columns_verified
+            && r_simd.len() == C::get_field_pack_size().trailing_zeros() as usize
+            && MultiLinearPoly::eval_generic(&v_simd_unpacked, r_simd, &mut scratch) == v
+    }
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        mut writer: W,
+    ) -> FieldSerdeResult<()> {
+        writer.write_all(&commitment.root)?;
+        Ok(())
+    }
+
+    fn deserialize_commitment<R: Read>(
+        _params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Commitment> {
+        let mut root = MerkleDigest::default();
+        reader.read_exact(&mut root)?;
+        Ok(OrionCommitment { root })
+    }
+
+    fn serialize_opening<W: Write>(opening: &Self::Opening, mut writer: W) -> FieldSerdeResult<()> {
+        for v in opening.eval_row.iter().chain(opening.proximity_row.iter()) {
+            v.serialize_into(&mut writer)?;
+        }
+        for v in opening.query_columns.iter().flatten() {
+            v.serialize_into(&mut writer)?;
+        }
+        opening.query_path.serialize_into(&mut writer)
+    }
+
+    fn deserialize_opening<R: Read>(
+        params: &Self::Params,
+        mut reader: R,
+    ) -> FieldSerdeResult<Self::Opening> {
+        let msg_len = params.code.msg_len();
+        let eval_row = (0..msg_len)
+            .map(|_| C::Field::deserialize_from(&mut reader))
+            .collect::<FieldSerdeResult<_>>()?;
+        let proximity_row = (0..msg_len)
+            .map(|_| C::Field::deserialize_from(&mut reader))
+            .collect::<FieldSerdeResult<_>>()?;
+
+        let mut query_columns = vec![];
+        for _ in 0..params.query_num() {
+            query_columns.push(
+                (0..params.row_num())
+                    .map(|_| C::Field::deserialize_from(&mut reader))
+                    .collect::<FieldSerdeResult<_>>()?,
+            );
+        }
+        let query_path = MerkleMultiPath::deserialize_from(
+            &mut reader,
+            params.codeword_depth(),
+            params.query_num(),
+        )?;
+
+        Ok(OrionOpening {
+            eval_row,
+            proximity_row,
+            query_columns,
+            query_path,
+        })
+    }
+}
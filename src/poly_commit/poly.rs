@@ -63,4 +63,17 @@ impl MultiLinearPoly {
 
         ret
     }
+
+    /// Evaluate the simd circuit values at `x`, then fold the simd lanes at `r_simd`
+    pub fn eval_circuit_vals_at_point<C: GKRConfig>(
+        evals: &[C::SimdCircuitField],
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+    ) -> C::ChallengeField {
+        let mut scratch = vec![C::Field::default(); evals.len()];
+        let y_simd = Self::eval_circuit_vals_at_challenge::<C>(evals, x, &mut scratch);
+        let y_simd_unpacked = y_simd.unpack();
+        let mut scratch = vec![C::ChallengeField::default(); y_simd_unpacked.len()];
+        Self::eval_generic(&y_simd_unpacked, r_simd, &mut scratch)
+    }
 }
@@ -3,9 +3,12 @@
 
 use std::io::{Read, Write};
 
-use arith::{Field, FieldSerde, FieldSerdeResult, SimdField};
+use arith::{FieldSerde, FieldSerdeResult};
+use ark_std::rand::RngCore;
 
-use crate::{GKRConfig, MultiLinearPoly};
+use crate::{
+    Config, GKRConfig, MultiLinearPoly, PolynomialCommitment, PolynomialCommitmentType, Transcript,
+};
 
 pub struct RawOpening {}
 
@@ -14,25 +17,28 @@ pub struct RawCommitment<C: GKRConfig> {
 }
 
 impl<C: GKRConfig> RawCommitment<C> {
+    /// Serialized size, including the length prefix
     #[inline]
     pub fn size(&self) -> usize {
-        self.poly_vals.len() * C::SimdCircuitField::SIZE
+        u64::SERIALIZED_SIZE + self.poly_vals.len() * C::SimdCircuitField::SIZE
     }
 
     #[inline]
     pub fn serialize_into<W: Write>(&self, mut writer: W) -> FieldSerdeResult<()> {
+        (self.poly_vals.len() as u64).serialize_into(&mut writer)?;
         self.poly_vals
             .iter()
             .try_for_each(|v| v.serialize_into(&mut writer))
     }
 
     #[inline]
-    pub fn deserialize_from<R: Read>(mut reader: R, poly_size: usize) -> Self {
+    pub fn deserialize_from<R: Read>(mut reader: R) -> FieldSerdeResult<Self> {
+        let poly_size = u64::deserialize_from(&mut reader)? as usize;
         let poly_vals = (0..poly_size)
-            .map(|_| C::SimdCircuitField::deserialize_from(&mut reader).unwrap()) // TODO: error propagation
-            .collect();
+            .map(|_| C::SimdCircuitField::deserialize_from(&mut reader))
+            .collect::<FieldSerdeResult<_>>()?;
 
-        RawCommitment { poly_vals }
+        Ok(RawCommitment { poly_vals })
     }
 }
 
@@ -44,6 +50,11 @@ impl<C: GKRConfig> RawCommitment<C> {
         }
     }
 
+    #[inline]
+    pub fn eval(&self, x: &[C::ChallengeField], r_simd: &[C::ChallengeField]) -> C::ChallengeField {
+        MultiLinearPoly::eval_circuit_vals_at_point::<C>(&self.poly_vals, x, r_simd)
+    }
+
     #[inline]
     pub fn verify(
         &self,
@@ -51,11 +62,71 @@ impl<C: GKRConfig> RawCommitment<C> {
         r_simd: &[C::ChallengeField],
         y: C::ChallengeField,
     ) -> bool {
-        let mut scratch = vec![C::Field::default(); self.poly_vals.len()];
-        let y_simd =
-            MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(&self.poly_vals, x, &mut scratch);
-        let y_simd_unpacked = y_simd.unpack();
-        let mut scratch = vec![C::ChallengeField::default(); y_simd_unpacked.len()];
-        y == MultiLinearPoly::eval_generic(&y_simd_unpacked, r_simd, &mut scratch)
+        // the length prefix comes from the prover, so check it before evaluating
+        self.poly_vals.len() == 1 << x.len() && y == self.eval(x, r_simd)
+    }
+}
+
+impl<C: GKRConfig> PolynomialCommitment<C> for RawCommitment<C> {
+    const TYPE: PolynomialCommitmentType = PolynomialCommitmentType::Raw;
+
+    type Params = ();
+
+    type Commitment = RawCommitment<C>;
+
+    type Opening = RawOpening;
+
+    fn setup(_config: &Config<C>, _num_vars: usize, _rng: impl RngCore) -> Self::Params {}
+
+    fn commit(_params: &Self::Params, poly: &[C::SimdCircuitField]) -> Self::Commitment {
+        RawCommitment::new(poly)
+    }
+
+    fn open(
+        _params: &Self::Params,
+        _poly: &[C::SimdCircuitField],
+        _x: &[C::ChallengeField],
+        _r_simd: &[C::ChallengeField],
+        _transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> Self::Opening {
+        // the verifier holds the whole polynomial
+        RawOpening {}
+    }
+
+    fn verify(
+        _params: &Self::Params,
+        commitment: &Self::Commitment,
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        v: C::ChallengeField,
+        _opening: &Self::Opening,
+        _transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) -> bool {
+        commitment.verify(x, r_simd, v)
+    }
+
+    fn serialize_commitment<W: Write>(
+        commitment: &Self::Commitment,
+        writer: W,
+    ) -> FieldSerdeResult<()> {
+        commitment.serialize_into(writer)
+    }
+
+    fn deserialize_commitment<R: Read>(
+        _params: &Self::Params,
+        reader: R,
+    ) -> FieldSerdeResult<Self::Commitment> {
+        RawCommitment::deserialize_from(reader)
+    }
+
+    fn serialize_opening<W: Write>(_opening: &Self::Opening, _writer: W) -> FieldSerdeResult<()> {
+        Ok(())
+    }
+
+    fn deserialize_opening<R: Read>(
+        _params: &Self::Params,
+        _reader: R,
+    ) -> FieldSerdeResult<Self::Opening> {
+        Ok(RawOpening {})
     }
 }
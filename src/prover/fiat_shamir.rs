@@ -22,10 +22,10 @@ impl<H: FiatShamirHash> Transcript<H> {
     fn hash_to_digest(&mut self) {
         let hash_end_idx = self.proof.bytes.len();
         if hash_end_idx > self.hash_start_idx {
-            self.hasher.hash(
-                &mut self.digest,
-                &self.proof.bytes[self.hash_start_idx..hash_end_idx],
-            );
+            // chain the previous digest, so that every challenge depends on the whole transcript
+            let mut input = self.digest.clone();
+            input.extend_from_slice(&self.proof.bytes[self.hash_start_idx..hash_end_idx]);
+            self.hasher.hash(&mut self.digest, &input);
             self.hash_start_idx = hash_end_idx;
         } else {
             self.hasher.hash_inplace(&mut self.digest)
@@ -63,6 +63,18 @@ impl<H: FiatShamirHash> Transcript<H> {
         self.proof.bytes.extend_from_slice(buffer);
     }
 
+    /// Absorb data known to both parties, e.g., the circuit digest.
+    /// Unlike `append_u8_slice`, the data is not written into the proof.
+    #[inline]
+    pub fn append_public_u8_slice(&mut self, buffer: &[u8]) {
+        let hash_end_idx = self.proof.bytes.len();
+        let mut input = self.digest.clone();
+        input.extend_from_slice(&self.proof.bytes[self.hash_start_idx..hash_end_idx]);
+        input.extend_from_slice(buffer);
+        self.hasher.hash(&mut self.digest, &input);
+        self.hash_start_idx = hash_end_idx;
+    }
+
     #[inline]
     pub fn challenge_f<C: GKRConfig>(&mut self) -> C::ChallengeField {
         self.hash_to_digest();
@@ -75,6 +87,35 @@ impl<H: FiatShamirHash> Transcript<H> {
         (0..size).map(|_| self.challenge_f::<C>()).collect()
     }
 
+    /// Sample `num` indices in `[0, range)`, where `range` is a power of two
+    #[inline]
+    pub fn challenge_indices(&mut self, num: usize, range: usize) -> Vec<usize> {
+        assert!(range.is_power_of_two());
+        (0..num).map(|_| self.challenge_index(range)).collect()
+    }
+
+    /// Sample `num` distinct indices in `[0, range)`, resampling the repeated ones
+    pub fn challenge_distinct_indices(&mut self, num: usize, range: usize) -> Vec<usize> {
+        assert!(range.is_power_of_two());
+        assert!(num <= range);
+        let mut sampled = vec![false; range];
+        let mut indices = Vec::with_capacity(num);
+        while indices.len() < num {
+            let index = self.challenge_index(range);
+            if !sampled[index] {
+                sampled[index] = true;
+                indices.push(index);
+            }
+        }
+        indices
+    }
+
+    #[inline]
+    fn challenge_index(&mut self, range: usize) -> usize {
+        self.hash_to_digest();
+        u64::from_le_bytes(self.digest[..8].try_into().unwrap()) as usize & (range - 1)
+    }
+
     #[inline]
     pub fn circuit_f<C: GKRConfig>(&mut self) -> C::CircuitField {
         self.hash_to_digest();
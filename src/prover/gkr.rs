@@ -4,7 +4,8 @@ use arith::{Field, SimdField};
 use ark_std::{end_timer, start_timer};
 
 use crate::{
-    sumcheck_prove_gkr_layer, Circuit, GKRConfig, GkrScratchpad, MultiLinearPoly, Transcript,
+    sumcheck_prove_gkr_layer, sumcheck_prove_input_claim_reduction, Circuit, GKRConfig,
+    GkrScratchpad, MultiLinearPoly, Transcript,
 };
 
 // FIXME
@@ -17,7 +18,6 @@ pub fn gkr_prove<C: GKRConfig>(
     C::ChallengeField,
     Vec<C::ChallengeField>,
     Vec<C::ChallengeField>,
-    Vec<C::ChallengeField>,
 ) {
     let timer = start_timer!(|| "gkr prove");
     let layer_num = circuit.layers.len();
@@ -30,6 +30,12 @@ pub fn gkr_prove<C: GKRConfig>(
         rz1.push(C::ChallengeField::zero());
     }
 
+    // one output claim per simd lane, so the verifier can check each lane against its outputs
+    let output_vals = &circuit.layers.last().unwrap().output_vals;
+    let claimed_v_simd =
+        MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(output_vals, &rz0, &mut sp.hg_evals);
+    transcript.append_f::<C>(claimed_v_simd);
+
     for _ in 0..C::get_field_pack_size().trailing_zeros() {
         r_simd.push(transcript.challenge_f::<C>());
     }
@@ -37,9 +43,6 @@ pub fn gkr_prove<C: GKRConfig>(
     let mut alpha = C::ChallengeField::one();
     let mut beta = C::ChallengeField::zero();
 
-    let output_vals = &circuit.layers.last().unwrap().output_vals;
-    let claimed_v_simd =
-        MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(output_vals, &rz0, &mut sp.hg_evals);
     let claimed_v = MultiLinearPoly::eval_generic::<C::ChallengeField>(
         &claimed_v_simd.unpack(),
         &r_simd,
@@ -69,6 +72,17 @@ pub fn gkr_prove<C: GKRConfig>(
         log::trace!("rz1.2: {:?}", rz1[2]);
     }
 
+    // a single claim on the input layer, so the PCS is opened once
+    let rz = sumcheck_prove_input_claim_reduction::<C>(
+        &circuit.layers[0].input_vals,
+        &rz0,
+        &rz1,
+        &r_simd,
+        &alpha,
+        &beta,
+        transcript,
+    );
+
     end_timer!(timer);
-    (claimed_v, rz0, rz1, r_simd)
+    (claimed_v, rz, r_simd)
 }
@@ -1,31 +1,50 @@
 // an implementation of the GKR^2 protocol
 //! This module implements the core GKR^2 IOP.
 
+use arith::SimdField;
 use ark_std::{end_timer, start_timer};
 
 use crate::{
     sumcheck_prove_gkr_square_layer, Circuit, GKRConfig, GkrScratchpad, MultiLinearPoly, Transcript,
 };
 
+#[allow(clippy::type_complexity)]
 pub fn gkr_square_prove<C: GKRConfig>(
     circuit: &Circuit<C>,
     sp: &mut GkrScratchpad<C>,
     transcript: &mut Transcript<C::FiatShamirHashType>,
-) -> (C::Field, Vec<C::ChallengeField>) {
+) -> (
+    C::ChallengeField,
+    Vec<C::ChallengeField>,
+    Vec<C::ChallengeField>,
+) {
     let timer = start_timer!(|| "gkr^2 prove");
     let layer_num = circuit.layers.len();
 
     let mut rz0 = vec![];
+    let mut r_simd = vec![];
     for _i in 0..circuit.layers.last().unwrap().output_var_num {
         rz0.push(transcript.challenge_f::<C>());
     }
 
     let circuit_output = &circuit.layers.last().unwrap().output_vals;
-    let claimed_v = MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(
+    let claimed_v_simd = MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(
         circuit_output,
         &rz0,
         &mut sp.hg_evals,
     );
+    // GKR^2 keeps one claim per simd lane, so the lane claims go into the proof
+    transcript.append_f::<C>(claimed_v_simd);
+
+    for _ in 0..C::get_field_pack_size().trailing_zeros() {
+        r_simd.push(transcript.challenge_f::<C>());
+    }
+
+    let claimed_v = MultiLinearPoly::eval_generic::<C::ChallengeField>(
+        &claimed_v_simd.unpack(),
+        &r_simd,
+        &mut sp.eq_evals_at_r_simd0,
+    );
 
     for i in (0..layer_num).rev() {
         rz0 = sumcheck_prove_gkr_square_layer(&circuit.layers[i], &rz0, transcript, sp);
@@ -36,6 +55,12 @@ pub fn gkr_square_prove<C: GKRConfig>(
         log::trace!("rz0.2: {:?}", rz0[2]);
     }
 
+    // fold the per-lane input claims with fresh randomness
+    r_simd.clear();
+    for _ in 0..C::get_field_pack_size().trailing_zeros() {
+        r_simd.push(transcript.challenge_f::<C>());
+    }
+
     end_timer!(timer);
-    (claimed_v, rz0)
+    (claimed_v, rz0, r_simd)
 }
@@ -3,8 +3,8 @@
 use ark_std::{end_timer, start_timer};
 
 use crate::{
-    gkr_prove, gkr_square_prove, Circuit, Config, GKRConfig, GKRScheme, GkrScratchpad, Proof,
-    RawCommitment, Transcript,
+    gkr_prove, gkr_square_prove, Circuit, Config, GKRConfig, GKRScheme, GkrScratchpad,
+    MultiLinearPoly, PolynomialCommitment, Proof, RawCommitment, Transcript,
 };
 
 #[cfg(feature = "grinding")]
@@ -37,22 +37,26 @@ pub(crate) fn grind<C: GKRConfig>(
     end_timer!(timer);
 }
 
-#[derive(Default)]
-pub struct Prover<C: GKRConfig> {
+pub struct Prover<C: GKRConfig, PCS: PolynomialCommitment<C> = RawCommitment<C>> {
     config: Config<C>,
     sp: GkrScratchpad<C>,
+    pcs_params: PCS::Params,
 }
 
 impl<C: GKRConfig> Prover<C> {
     pub fn new(config: &Config<C>) -> Self {
+        Self::new_with_pcs_params(config, ())
+    }
+}
+
+impl<C: GKRConfig, PCS: PolynomialCommitment<C>> Prover<C, PCS> {
+    pub fn new_with_pcs_params(config: &Config<C>, pcs_params: PCS::Params) -> Self {
         // assert_eq!(config.fs_hash, crate::config::FiatShamirHashType::SHA256);
-        assert_eq!(
-            config.polynomial_commitment_type,
-            crate::config::PolynomialCommitmentType::Raw
-        );
+        assert_eq!(config.polynomial_commitment_type, PCS::TYPE);
         Prover {
             config: config.clone(),
             sp: GkrScratchpad::default(),
+            pcs_params,
         }
     }
     pub fn prepare_mem(&mut self, c: &Circuit<C>) {
@@ -75,12 +79,15 @@ impl<C: GKRConfig> Prover<C> {
         let timer = start_timer!(|| "prove");
         // std::thread::sleep(std::time::Duration::from_secs(1)); // TODO
 
-        // PC commit
-        let commitment = RawCommitment::<C>::new(&c.layers[0].input_vals);
+        // PC commit, public inputs are known to the verifier and left out of the commitment
+        let commitment = PCS::commit(&self.pcs_params, c.private_input());
 
         let mut buffer = vec![];
-        commitment.serialize_into(&mut buffer).unwrap(); // TODO: error propagation
+        PCS::serialize_commitment(&commitment, &mut buffer).unwrap(); // TODO: error propagation
         let mut transcript = Transcript::new();
+        transcript.append_public_u8_slice(&self.config.domain_separator());
+        transcript.append_public_u8_slice(&c.digest());
+        transcript.append_public_u8_slice(&c.public_input_bytes());
         transcript.append_u8_slice(&buffer);
 
         #[cfg(feature = "grinding")]
@@ -89,25 +96,45 @@ impl<C: GKRConfig> Prover<C> {
         c.fill_rnd_coefs(&mut transcript);
         c.evaluate();
 
-        let mut claimed_v = C::ChallengeField::default();
-        let mut _rx = vec![];
-        let mut _ry = vec![];
-        let mut _rsimd = vec![];
-
-        if self.config.gkr_scheme == GKRScheme::GkrSquare {
-            (_, _rx) = gkr_square_prove(c, &mut self.sp, &mut transcript);
+        // both schemes end with a single claim on the input layer
+        let (claimed_v, rz, rsimd) = if self.config.gkr_scheme == GKRScheme::GkrSquare {
+            gkr_square_prove(c, &mut self.sp, &mut transcript)
         } else {
-            (claimed_v, _rx, _ry, _rsimd) = gkr_prove(c, &mut self.sp, &mut transcript);
-        }
+            gkr_prove(c, &mut self.sp, &mut transcript)
+        };
 
         // open
-        match self.config.polynomial_commitment_type {
-            crate::config::PolynomialCommitmentType::Raw => {
-                // no need to update transcript
-            }
-            _ => todo!(),
-        }
+        self.open_private_input(c, &rz, &rsimd, &mut transcript);
+
         end_timer!(timer);
         (claimed_v, transcript.proof)
     }
+
+    // The input layer claim at x splits into the private part, opened by the PCS,
+    // and the public part the verifier evaluates on its own.
+    fn open_private_input(
+        &self,
+        c: &Circuit<C>,
+        x: &[C::ChallengeField],
+        r_simd: &[C::ChallengeField],
+        transcript: &mut Transcript<C::FiatShamirHashType>,
+    ) {
+        let private_input = c.private_input();
+        let x_private = &x[..c.log_private_input_size()];
+
+        let private_v =
+            MultiLinearPoly::eval_circuit_vals_at_point::<C>(private_input, x_private, r_simd);
+        transcript.append_challenge_f::<C>(&private_v);
+
+        let opening = PCS::open(
+            &self.pcs_params,
+            private_input,
+            x_private,
+            r_simd,
+            transcript,
+        );
+        let mut buffer = vec![];
+        PCS::serialize_opening(&opening, &mut buffer).unwrap(); // TODO: error propagation
+        transcript.append_u8_slice(&buffer);
+    }
 }
@@ -20,6 +20,12 @@ impl Proof {
         self.idx += size;
     }
 
+    /// Bytes that have not been read yet
+    #[inline(always)]
+    pub fn remaining_bytes(&self) -> &[u8] {
+        &self.bytes[self.idx..]
+    }
+
     #[inline(always)]
     pub fn get_next_and_step<F: Field + FieldSerde>(&mut self) -> F {
         let ret = F::deserialize_from(&self.bytes[self.idx..]).unwrap(); // TODO: error propagation
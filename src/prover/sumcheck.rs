@@ -1,5 +1,8 @@
+use arith::{ExtensionField, Field, SimdField};
+
 use crate::{
-    CircuitLayer, GKRConfig, GkrScratchpad, SumcheckGkrHelper, SumcheckGkrSquareHelper, Transcript,
+    eq_evals_at_primitive, CircuitLayer, FieldType, GKRConfig, GkrScratchpad, SumcheckGkrHelper,
+    SumcheckGkrSquareHelper, Transcript,
 };
 
 #[inline(always)]
@@ -7,7 +10,7 @@ fn transcript_io<C: GKRConfig>(
     ps: &[C::ChallengeField],
     transcript: &mut Transcript<C::FiatShamirHashType>,
 ) -> C::ChallengeField {
-    debug_assert!(ps.len() == 3 || ps.len() == 4); // 3 for x, y; 4 for simd var
+    debug_assert!(ps.len() == 3 || ps.len() == 4 || ps.len() == 7); // 3 for x, y; 4 for simd var; 7 with pow5
     for p in ps {
         transcript.append_challenge_f::<C>(p);
     }
@@ -36,15 +39,17 @@ pub fn sumcheck_prove_gkr_layer<C: GKRConfig>(
     helper.prepare_simd();
     helper.prepare_x_vals();
 
+    let x_degree = helper.x_degree();
     for i_var in 0..helper.input_var_num {
-        let evals = helper.poly_evals_at_rx(i_var, 2);
+        let evals = helper.poly_evals_at_rx(i_var, x_degree);
         let r = transcript_io::<C>(&evals, transcript);
         helper.receive_rx(i_var, r);
     }
 
     helper.prepare_simd_var_vals();
+    let simd_degree = helper.simd_degree();
     for i_var in 0..helper.simd_var_num {
-        let evals = helper.poly_evals_at_r_simd_var(i_var, 2);
+        let evals = helper.poly_evals_at_r_simd_var(i_var, simd_degree);
         let r = transcript_io::<C>(&evals, transcript);
         helper.receive_r_simd_var(i_var, r);
     }
@@ -96,10 +101,6 @@ pub fn sumcheck_prove_gkr_square_layer<C: GKRConfig>(
         log::trace!("i_var={} evals: {:?} r: {:?}", i_var, evals, r);
 
         helper.receive_challenge(i_var, r);
-        if i_var == layer.input_var_num - 1 {
-            log::trace!("vx claim: {:?}", helper.vx_claim());
-            transcript.append_f::<C>(helper.vx_claim());
-        }
     }
 
     log::trace!("claimed vx = {:?}", helper.vx_claim());
@@ -108,6 +109,76 @@ pub fn sumcheck_prove_gkr_square_layer<C: GKRConfig>(
     helper.rx
 }
 
+/// Reduce the claims alpha * V(rz0) + beta * V(rz1) on the input layer to a single claim V(rz),
+/// with a sumcheck on \sum_b V(b) (alpha * eq(rz0, b) + beta * eq(rz1, b)).
+/// The simd variables are already bound to r_simd in both claims.
+pub fn sumcheck_prove_input_claim_reduction<C: GKRConfig>(
+    input_vals: &[C::SimdCircuitField],
+    rz0: &[C::ChallengeField],
+    rz1: &[C::ChallengeField],
+    r_simd: &[C::ChallengeField],
+    alpha: &C::ChallengeField,
+    beta: &C::ChallengeField,
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+) -> Vec<C::ChallengeField> {
+    let var_num = rz0.len();
+    assert_eq!(input_vals.len(), 1 << var_num);
+
+    let mut eq_evals_at_r_simd = vec![C::ChallengeField::zero(); 1 << r_simd.len()];
+    eq_evals_at_primitive(r_simd, &C::ChallengeField::one(), &mut eq_evals_at_r_simd);
+    let mut bk_f = input_vals
+        .iter()
+        .map(|v| {
+            v.unpack()
+                .iter()
+                .zip(eq_evals_at_r_simd.iter())
+                .map(|(lane, eq)| C::challenge_mul_circuit_field(eq, lane))
+                .sum::<C::ChallengeField>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut bk_eq = vec![C::ChallengeField::zero(); 1 << var_num];
+    let mut eq_evals_at_rz1 = vec![C::ChallengeField::zero(); 1 << var_num];
+    eq_evals_at_primitive(rz0, alpha, &mut bk_eq);
+    eq_evals_at_primitive(rz1, beta, &mut eq_evals_at_rz1);
+    bk_eq
+        .iter_mut()
+        .zip(eq_evals_at_rz1.iter())
+        .for_each(|(eq, eq_at_rz1)| *eq += eq_at_rz1);
+
+    // the third evaluation point is 2, or X over GF2
+    let eval_at_2 = |v_0: C::ChallengeField, v_1: C::ChallengeField| {
+        if C::FIELD_TYPE == FieldType::GF2 {
+            v_0 + (v_1 - v_0).mul_by_x()
+        } else {
+            v_1.double() - v_0
+        }
+    };
+
+    let mut rz = vec![];
+    for i_var in 0..var_num {
+        let eval_size = 1 << (var_num - i_var - 1);
+        let mut evals = [C::ChallengeField::zero(); 3];
+        for i in 0..eval_size {
+            let (f_v_0, f_v_1) = (bk_f[i * 2], bk_f[i * 2 + 1]);
+            let (eq_v_0, eq_v_1) = (bk_eq[i * 2], bk_eq[i * 2 + 1]);
+            evals[0] += f_v_0 * eq_v_0;
+            evals[1] += f_v_1 * eq_v_1;
+            evals[2] += eval_at_2(f_v_0, f_v_1) * eval_at_2(eq_v_0, eq_v_1);
+        }
+
+        let r = transcript_io::<C>(&evals, transcript);
+        for i in 0..eval_size {
+            bk_f[i] = bk_f[i * 2] + (bk_f[i * 2 + 1] - bk_f[i * 2]) * r;
+            bk_eq[i] = bk_eq[i * 2] + (bk_eq[i * 2 + 1] - bk_eq[i * 2]) * r;
+        }
+        rz.push(r);
+    }
+
+    transcript.append_challenge_f::<C>(&bk_f[0]);
+    rz
+}
+
 // #[cfg(test)]
 // mod tests {
 // use crate::BN254ConfigKeccak;
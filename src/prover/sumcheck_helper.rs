@@ -274,6 +274,39 @@ impl SumcheckMultilinearProdSimdVarHelper {
         [p0, p1, p2, p3]
     }
 
+    // eq * (f * hg + hg_5 * f^5), evaluated at 0, 1, ..., 6
+    fn poly_eval_at_with_pow5<C: GKRConfig>(
+        &self,
+        var_idx: usize,
+        bk_eq: &mut [C::ChallengeField],
+        bk_f: &mut [C::ChallengeField],
+        bk_hg: &mut [C::ChallengeField],
+        hg_5: &C::ChallengeField,
+    ) -> [C::ChallengeField; 7] {
+        debug_assert!(C::FIELD_TYPE != FieldType::GF2);
+        let mut p = [C::ChallengeField::zero(); 7];
+
+        let eval_size = 1 << (self.var_num - var_idx - 1);
+        for i in 0..eval_size {
+            let mut eq_v = bk_eq[i * 2];
+            let mut f_v = bk_f[i * 2];
+            let mut hg_v = bk_hg[i * 2];
+            let delta_eq = bk_eq[i * 2 + 1] - eq_v;
+            let delta_f = bk_f[i * 2 + 1] - f_v;
+            let delta_hg = bk_hg[i * 2 + 1] - hg_v;
+
+            for p_k in p.iter_mut() {
+                let f_v_5 = f_v.square().square() * f_v;
+                *p_k += eq_v * (f_v * hg_v + f_v_5 * hg_5);
+                eq_v += delta_eq;
+                f_v += delta_f;
+                hg_v += delta_hg;
+            }
+        }
+
+        p
+    }
+
     fn receive_challenge<C: GKRConfig>(
         &mut self,
         var_idx: usize,
@@ -308,6 +341,8 @@ pub(crate) struct SumcheckGkrHelper<'a, C: GKRConfig> {
 
     pub(crate) input_var_num: usize,
     pub(crate) simd_var_num: usize,
+    /// pow5 gates raise the degree of the x and simd rounds from 2/3 to 6
+    pub(crate) has_pow5: bool,
 
     xy_helper: SumcheckMultilinearProdHelper,
     simd_var_helper: SumcheckMultilinearProdSimdVarHelper,
@@ -344,6 +379,65 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
         );
     }
 
+    /// Extends the evaluations of a degree 2 polynomial at 0, 1, 2 to 0, 1, ..., 6
+    #[inline(always)]
+    fn extend_degree_2_evals(p: [C::Field; 3]) -> [C::Field; 7] {
+        let mut ret = [C::Field::zero(); 7];
+        ret[..3].copy_from_slice(&p);
+        for i in 3..7 {
+            ret[i] = (ret[i - 1] - ret[i - 2]).mul_by_3() + ret[i - 3];
+        }
+        ret
+    }
+
+    /// sum_x hg_5(x) * v(x)^5, evaluated at 0, 1, ..., 6 for the current x variable
+    fn pow5_evals_at_rx(&self, var_idx: usize) -> [C::Field; 7] {
+        let mut p = [C::Field::zero(); 7];
+        let hg_5 = &self.sp.hg_evals_5;
+        // the vanilla helper tracks add/mul inputs in gate_exists_5, pow5 inputs in gate_exists_1
+        let gate_exists = &self.sp.gate_exists_1;
+        let eval_size = 1 << (self.input_var_num - var_idx - 1);
+
+        for i in 0..eval_size {
+            if !gate_exists[i * 2] && !gate_exists[i * 2 + 1] {
+                continue;
+            }
+            let (mut f_v, delta_f) = if var_idx == 0 {
+                let f_v_0 = C::simd_circuit_field_into_field(&self.layer.input_vals[i * 2]);
+                let f_v_1 = C::simd_circuit_field_into_field(&self.layer.input_vals[i * 2 + 1]);
+                (f_v_0, f_v_1 - f_v_0)
+            } else {
+                let f_v_0 = self.sp.v_evals[i * 2];
+                (f_v_0, self.sp.v_evals[i * 2 + 1] - f_v_0)
+            };
+            let mut hg_v = hg_5[i * 2];
+            let delta_hg = hg_5[i * 2 + 1] - hg_v;
+
+            for p_k in p.iter_mut() {
+                let f_v_5 = f_v.square().square() * f_v;
+                *p_k += C::challenge_mul_field(&hg_v, &f_v_5);
+                f_v += delta_f;
+                hg_v += delta_hg;
+            }
+        }
+        p
+    }
+
+    fn pow5_receive_challenge(&mut self, var_idx: usize, r: C::ChallengeField) {
+        let hg_5 = &mut self.sp.hg_evals_5;
+        let gate_exists = &mut self.sp.gate_exists_1;
+        let eval_size = 1 << (self.input_var_num - var_idx - 1);
+        for i in 0..eval_size {
+            if !gate_exists[i * 2] && !gate_exists[i * 2 + 1] {
+                gate_exists[i] = false;
+                hg_5[i] = C::ChallengeField::zero();
+            } else {
+                gate_exists[i] = true;
+                hg_5[i] = hg_5[2 * i] + (hg_5[2 * i + 1] - hg_5[2 * i]) * r;
+            }
+        }
+    }
+
     #[inline(always)]
     fn simd_helper_receive_challenge(&mut self, var_idx: usize, r: C::ChallengeField) {
         self.simd_var_helper.receive_challenge::<C>(
@@ -369,6 +463,11 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
         sp: &'a mut GkrScratchpad<C>,
     ) -> Self {
         let simd_var_num = C::get_field_pack_size().trailing_zeros() as usize;
+        let has_pow5 = layer.uni.iter().any(|g| g.gate_type == 12345);
+        assert!(
+            !has_pow5 || C::FIELD_TYPE != FieldType::GF2,
+            "pow5 gates are not supported over GF2"
+        );
         SumcheckGkrHelper {
             rx: vec![],
             ry: vec![],
@@ -384,6 +483,7 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
 
             input_var_num: layer.input_var_num,
             simd_var_num,
+            has_pow5,
 
             xy_helper: SumcheckMultilinearProdHelper::new(layer.input_var_num),
             simd_var_helper: SumcheckMultilinearProdSimdVarHelper::new(simd_var_num),
@@ -394,37 +494,60 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
         &mut self,
         var_idx: usize,
         degree: usize,
-    ) -> [C::ChallengeField; 3] {
+    ) -> Vec<C::ChallengeField> {
         debug_assert!(var_idx < self.input_var_num);
-        let [p0, p1, p2] = self.xy_helper.poly_eval_at::<C>(
+        let p = self.xy_helper.poly_eval_at::<C>(
             var_idx,
-            degree,
+            2,
             &mut self.sp.v_evals,
             &mut self.sp.hg_evals,
             &self.layer.input_vals,
             &self.sp.gate_exists_5,
         );
-        [
-            Self::unpack_and_combine(p0, &self.sp.eq_evals_at_r_simd0),
-            Self::unpack_and_combine(p1, &self.sp.eq_evals_at_r_simd0),
-            Self::unpack_and_combine(p2, &self.sp.eq_evals_at_r_simd0),
-        ]
+        let p = if degree == 6 {
+            let mut p = Self::extend_degree_2_evals(p);
+            let p_5 = self.pow5_evals_at_rx(var_idx);
+            for (p_k, p_5_k) in p.iter_mut().zip(p_5.iter()) {
+                *p_k += p_5_k;
+            }
+            p.to_vec()
+        } else {
+            debug_assert_eq!(degree, 2);
+            p.to_vec()
+        };
+        p.into_iter()
+            .map(|p_k| Self::unpack_and_combine(p_k, &self.sp.eq_evals_at_r_simd0))
+            .collect()
     }
 
     pub(crate) fn poly_evals_at_r_simd_var(
         &mut self,
         var_idx: usize,
         degree: usize,
-    ) -> [C::ChallengeField; 4] {
-        debug_assert_eq!(degree, 3);
+    ) -> Vec<C::ChallengeField> {
         debug_assert!(var_idx < self.simd_var_num);
-        self.simd_var_helper.poly_eval_at::<C>(
-            var_idx,
-            degree,
-            &mut self.sp.eq_evals_at_r_simd0,
-            &mut self.sp.simd_var_v_evals,
-            &mut self.sp.simd_var_hg_evals,
-        )
+        if degree == 6 {
+            self.simd_var_helper
+                .poly_eval_at_with_pow5::<C>(
+                    var_idx,
+                    &mut self.sp.eq_evals_at_r_simd0,
+                    &mut self.sp.simd_var_v_evals,
+                    &mut self.sp.simd_var_hg_evals,
+                    &self.sp.hg_evals_5[0],
+                )
+                .to_vec()
+        } else {
+            debug_assert_eq!(degree, 3);
+            self.simd_var_helper
+                .poly_eval_at::<C>(
+                    var_idx,
+                    2,
+                    &mut self.sp.eq_evals_at_r_simd0,
+                    &mut self.sp.simd_var_v_evals,
+                    &mut self.sp.simd_var_hg_evals,
+                )
+                .to_vec()
+        }
     }
 
     #[inline(always)]
@@ -432,11 +555,34 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
         &mut self,
         var_idx: usize,
         degree: usize,
-    ) -> [C::ChallengeField; 3] {
+    ) -> Vec<C::ChallengeField> {
+        debug_assert_eq!(degree, 2);
         self.poly_evals_at_rx(var_idx, degree)
     }
 
+    /// Degree of the x and simd rounds
+    #[inline(always)]
+    pub(crate) fn x_degree(&self) -> usize {
+        if self.has_pow5 {
+            6
+        } else {
+            2
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn simd_degree(&self) -> usize {
+        if self.has_pow5 {
+            6
+        } else {
+            3
+        }
+    }
+
     pub(crate) fn receive_rx(&mut self, var_idx: usize, r: C::ChallengeField) {
+        if self.has_pow5 {
+            self.pow5_receive_challenge(var_idx, r);
+        }
         self.xy_helper_receive_challenge(var_idx, r);
         self.rx.push(r);
     }
@@ -524,6 +670,29 @@ impl<'a, C: GKRConfig> SumcheckGkrHelper<'a, C> {
             ));
             gate_exists[g.i_ids[0]] = true;
         }
+
+        if self.has_pow5 {
+            unsafe {
+                std::ptr::write_bytes(self.sp.hg_evals_5.as_mut_ptr(), 0, vals.len());
+                std::ptr::write_bytes(self.sp.gate_exists_1.as_mut_ptr(), 0, vals.len());
+            }
+        }
+
+        for g in self.layer.uni.iter() {
+            let r = C::challenge_mul_circuit_field(&eq_evals_at_rz0[g.o_id], &g.coef);
+            match g.gate_type {
+                12345 => {
+                    self.sp.hg_evals_5[g.i_ids[0]] += r;
+                    self.sp.gate_exists_1[g.i_ids[0]] = true;
+                }
+                // pow1 is linear, so it shares the add gate term
+                12346 => {
+                    hg_vals[g.i_ids[0]] += C::Field::from(r);
+                    gate_exists[g.i_ids[0]] = true;
+                }
+                _ => panic!("Unsupported gate type"),
+            }
+        }
     }
 
     pub(crate) fn prepare_simd_var_vals(&mut self) {
@@ -1,91 +1,61 @@
 use std::{io::Cursor, vec};
 
-use arith::{ExtensionField, Field};
+use arith::{powers, ExtensionField, Field, FieldSerdeResult, LagrangeDomain, SimdField};
 use ark_std::{end_timer, start_timer};
 
 #[cfg(feature = "grinding")]
 use crate::grind;
 
 use crate::{
-    eq_evals_at_primitive, Circuit, CircuitLayer, Config, FieldType, GKRConfig, Gate, Proof,
-    RawCommitment, Transcript, _eq_vec,
+    eq_evals_at_primitive, Circuit, CircuitLayer, Config, FieldType, GKRConfig, GKRScheme, Gate,
+    GateUni, MultiLinearPoly, PolynomialCommitment, Proof, RawCommitment, Transcript, _eq_vec,
 };
 
-#[inline]
-fn degree_2_eval<C: GKRConfig>(
-    p0: C::ChallengeField,
-    p1: C::ChallengeField,
-    p2: C::ChallengeField,
-    x: C::ChallengeField,
-) -> C::ChallengeField {
+// The points the prover evaluates the sumcheck polynomials of the given degree at:
+// 0, 1, ..., degree over prime fields, and 0, 1, X, ..., X^(degree - 1) over GF2.
+fn sumcheck_eval_points<C: GKRConfig>(degree: usize) -> Vec<C::ChallengeField> {
     if C::FIELD_TYPE == FieldType::GF2 {
-        let c0 = &p0;
-        let c2 = (p2 - p0 - p1.mul_by_x() + p0.mul_by_x())
-            * (C::ChallengeField::X - C::ChallengeField::one())
-                .mul_by_x()
-                .inv()
-                .unwrap();
-        let c1 = p1 - p0 - c2;
-        *c0 + (c2 * x + c1) * x
+        let mut points = vec![C::ChallengeField::zero()];
+        points.extend(powers(&<C::ChallengeField as ExtensionField>::X, degree));
+        points
     } else {
-        let c0 = &p0;
-        let c2 = C::ChallengeField::INV_2 * (p2 - p1 - p1 + p0);
-        let c1 = p1 - p0 - c2;
-        *c0 + (c2 * x + c1) * x
+        (0..=degree as u32).map(C::ChallengeField::from).collect()
     }
 }
 
-#[inline(always)]
-fn lag_eval<F: Field + ExtensionField>(base: &[F], vals: &[F], x: &F) -> F {
-    debug_assert_eq!(base.len(), vals.len());
-    // trivial lag eval:
-    let mut v = F::zero();
-    for i in 0..base.len() {
-        let mut numerator = F::one();
-        let mut denominator = F::one();
-        for j in 0..base.len() {
-            if j == i {
-                continue;
-            }
-            numerator *= *x - base[j];
-            denominator *= base[i] - base[j];
+// The Lagrange weights of the sumcheck evaluation points,
+// computed once per proof instead of inverting the denominators at every round.
+struct SumcheckDomains<C: GKRConfig> {
+    degree_2: LagrangeDomain<C::ChallengeField>,
+    degree_3: LagrangeDomain<C::ChallengeField>,
+    degree_6: LagrangeDomain<C::ChallengeField>,
+}
+
+impl<C: GKRConfig> SumcheckDomains<C> {
+    fn new() -> Self {
+        Self {
+            degree_2: LagrangeDomain::new(&sumcheck_eval_points::<C>(2)),
+            degree_3: LagrangeDomain::new(&sumcheck_eval_points::<C>(3)),
+            degree_6: LagrangeDomain::new(&sumcheck_eval_points::<C>(6)),
         }
-        v += numerator * denominator.inv().unwrap() * vals[i];
     }
-    v
 }
 
 #[inline]
-fn degree_3_eval<C: GKRConfig>(
+fn degree_2_eval<C: GKRConfig>(
+    domains: &SumcheckDomains<C>,
     p0: C::ChallengeField,
     p1: C::ChallengeField,
     p2: C::ChallengeField,
-    p3: C::ChallengeField,
     x: C::ChallengeField,
 ) -> C::ChallengeField {
-    // TODO-OPTIMIZATION: precompute values and inverses
     if C::FIELD_TYPE == FieldType::GF2 {
-        lag_eval(
-            &[
-                C::ChallengeField::zero(),
-                C::ChallengeField::one(),
-                C::ChallengeField::X,
-                C::ChallengeField::X.mul_by_x(),
-            ],
-            &[p0, p1, p2, p3],
-            &x,
-        )
+        domains.degree_2.evaluate(&[p0, p1, p2], &x)
     } else {
-        lag_eval(
-            &[
-                C::ChallengeField::zero(),
-                C::ChallengeField::one(),
-                C::ChallengeField::from(2),
-                C::ChallengeField::from(3),
-            ],
-            &[p0, p1, p2, p3],
-            &x,
-        )
+        let c0 = &p0;
+        let c2 = C::ChallengeField::INV_2 * (p2 - p1 - p1 + p0);
+        let c1 = p1 - p0 - c2;
+        *c0 + (c2 * x + c1) * x
     }
 }
 
@@ -158,8 +128,51 @@ fn eval_sparse_circuit_connect_poly<C: GKRConfig, const INPUT_NUM: usize>(
     }
 }
 
+// Returns the (pow5, pow1) parts of the uni gate connection polynomial,
+// or None if a gate has an unknown type
+#[allow(clippy::too_many_arguments)]
+fn eval_sparse_circuit_uni_poly<C: GKRConfig>(
+    gates: &[GateUni<C>],
+    rz0: &[C::ChallengeField],
+    rz1: &[C::ChallengeField],
+    r_simd: &[C::ChallengeField],
+    alpha: C::ChallengeField,
+    beta: C::ChallengeField,
+    rx: &[C::ChallengeField],
+    r_simd_xy: &[C::ChallengeField],
+) -> Option<(C::ChallengeField, C::ChallengeField)> {
+    if gates.is_empty() {
+        return Some((C::ChallengeField::zero(), C::ChallengeField::zero()));
+    }
+
+    let mut eq_evals_at_rz0 = vec![C::ChallengeField::zero(); 1 << rz0.len()];
+    let mut eq_evals_at_rz1 = vec![C::ChallengeField::zero(); 1 << rz1.len()];
+    let mut eq_evals_at_rx = vec![C::ChallengeField::zero(); 1 << rx.len()];
+
+    eq_evals_at_primitive(rz0, &alpha, &mut eq_evals_at_rz0);
+    eq_evals_at_primitive(rz1, &beta, &mut eq_evals_at_rz1);
+    eq_evals_at_primitive(rx, &C::ChallengeField::one(), &mut eq_evals_at_rx);
+
+    let mut pow5 = C::ChallengeField::zero();
+    let mut pow1 = C::ChallengeField::zero();
+    for uni_gate in gates {
+        let tmp =
+            C::challenge_mul_circuit_field(&eq_evals_at_rx[uni_gate.i_ids[0]], &uni_gate.coef);
+        let v = (eq_evals_at_rz0[uni_gate.o_id] + eq_evals_at_rz1[uni_gate.o_id]) * tmp;
+        match uni_gate.gate_type {
+            12345 => pow5 += v,
+            12346 => pow1 += v,
+            _ => return None,
+        }
+    }
+
+    let eq_simd = _eq_vec(r_simd, r_simd_xy);
+    Some((pow5 * eq_simd, pow1 * eq_simd))
+}
+
 #[inline(always)]
 fn verify_sumcheck_step<C: GKRConfig>(
+    domains: &SumcheckDomains<C>,
     proof: &mut Proof,
     degree: usize,
     transcript: &mut Transcript<C::FiatShamirHashType>,
@@ -178,9 +191,11 @@ fn verify_sumcheck_step<C: GKRConfig>(
     let verified = (ps[0] + ps[1]) == *claimed_sum;
 
     if degree == 2 {
-        *claimed_sum = degree_2_eval::<C>(ps[0], ps[1], ps[2], r);
+        *claimed_sum = degree_2_eval(domains, ps[0], ps[1], ps[2], r);
     } else if degree == 3 {
-        *claimed_sum = degree_3_eval::<C>(ps[0], ps[1], ps[2], ps[3], r);
+        *claimed_sum = domains.degree_3.evaluate(&ps, &r);
+    } else if degree == 6 {
+        *claimed_sum = domains.degree_6.evaluate(&ps, &r);
     }
 
     verified
@@ -190,6 +205,7 @@ fn verify_sumcheck_step<C: GKRConfig>(
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 fn sumcheck_verify_gkr_layer<C: GKRConfig>(
+    domains: &SumcheckDomains<C>,
     layer: &CircuitLayer<C>,
     rz0: &[C::ChallengeField],
     rz1: &[C::ChallengeField],
@@ -228,19 +244,35 @@ fn sumcheck_verify_gkr_layer<C: GKRConfig>(
     let mut r_simd_xy = vec![];
     let mut verified = true;
 
+    // pow5 gates raise the degree of the x and simd rounds to 6
+    let has_pow5 = layer.uni.iter().any(|g| g.gate_type == 12345);
+    let (x_degree, simd_degree) = if has_pow5 { (6, 6) } else { (2, 3) };
+
     for _i_var in 0..var_num {
-        verified &= verify_sumcheck_step::<C>(proof, 2, transcript, &mut sum, &mut rx);
+        verified &= verify_sumcheck_step(domains, proof, x_degree, transcript, &mut sum, &mut rx);
         // println!("x {} var, verified? {}", _i_var, verified);
     }
 
     for _i_var in 0..simd_var_num {
-        verified &= verify_sumcheck_step::<C>(proof, 3, transcript, &mut sum, &mut r_simd_xy);
+        verified &= verify_sumcheck_step(
+            domains,
+            proof,
+            simd_degree,
+            transcript,
+            &mut sum,
+            &mut r_simd_xy,
+        );
         // println!("{} simd var, verified? {}", _i_var, verified);
     }
 
     let vx_claim = proof.get_next_and_step::<C::ChallengeField>();
+    let uni_evals =
+        eval_sparse_circuit_uni_poly(&layer.uni, rz0, rz1, r_simd0, alpha, beta, &rx, &r_simd_xy);
+    // the circuit comes from a file, reject it rather than panic
+    verified &= uni_evals.is_some();
+    let (pow5_eval, pow1_eval) = uni_evals.unwrap_or_default();
     sum -= vx_claim
-        * eval_sparse_circuit_connect_poly(
+        * (eval_sparse_circuit_connect_poly(
             &layer.add,
             rz0,
             rz1,
@@ -250,11 +282,12 @@ fn sumcheck_verify_gkr_layer<C: GKRConfig>(
             &rx,
             &[],
             &r_simd_xy,
-        );
+        ) + pow1_eval)
+        + vx_claim.square().square() * vx_claim * pow5_eval;
     transcript.append_challenge_f::<C>(&vx_claim);
 
     for _i_var in 0..var_num {
-        verified &= verify_sumcheck_step::<C>(proof, 2, transcript, &mut sum, &mut ry);
+        verified &= verify_sumcheck_step(domains, proof, 2, transcript, &mut sum, &mut ry);
         // println!("y {} var, verified? {}", _i_var, verified);
     }
 
@@ -269,19 +302,69 @@ fn sumcheck_verify_gkr_layer<C: GKRConfig>(
     (verified, rx, ry, r_simd_xy, vx_claim, vy_claim)
 }
 
+/// Output values the verifier expects from the circuit
+pub enum ExpectedOutputs<'a, C: GKRConfig> {
+    /// All outputs are zero, the constraint convention of the compiler
+    Zero,
+    /// Explicit values for the output layer
+    Values(&'a [C::SimdCircuitField]),
+}
+
+impl<C: GKRConfig> ExpectedOutputs<'_, C> {
+    /// Whether there is one expected value per output of the circuit
+    pub fn fits(&self, circuit: &Circuit<C>) -> bool {
+        match self {
+            ExpectedOutputs::Zero => true,
+            ExpectedOutputs::Values(vals) => circuit
+                .layers
+                .last()
+                .map_or(false, |layer| vals.len() == 1 << layer.output_var_num),
+        }
+    }
+}
+
+// Reads the per simd lane output claims at rz0 from the proof,
+// and compares each of them with the MLE of the expected outputs at rz0.
+fn verify_output_claims<C: GKRConfig>(
+    expected_outputs: &ExpectedOutputs<C>,
+    rz0: &[C::ChallengeField],
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+    proof: &mut Proof,
+) -> (Vec<bool>, C::Field) {
+    let claimed_v_simd = proof.get_next_and_step::<C::Field>();
+    transcript.append_f::<C>(claimed_v_simd);
+
+    let expected_v_simd = match expected_outputs {
+        ExpectedOutputs::Zero => C::Field::zero(),
+        ExpectedOutputs::Values(vals) => {
+            // checked by the verifier with `ExpectedOutputs::fits`
+            debug_assert_eq!(vals.len(), 1 << rz0.len());
+            let mut scratch = vec![C::Field::zero(); vals.len()];
+            MultiLinearPoly::eval_circuit_vals_at_challenge::<C>(vals, rz0, &mut scratch)
+        }
+    };
+
+    let lanes_verified = claimed_v_simd
+        .unpack()
+        .iter()
+        .zip(expected_v_simd.unpack().iter())
+        .map(|(claimed, expected)| claimed == expected)
+        .collect();
+    (lanes_verified, claimed_v_simd)
+}
+
 // todo: FIXME
 #[allow(clippy::type_complexity)]
 pub fn gkr_verify<C: GKRConfig>(
     circuit: &Circuit<C>,
-    claimed_v: &C::ChallengeField,
+    expected_outputs: &ExpectedOutputs<C>,
     transcript: &mut Transcript<C::FiatShamirHashType>,
     proof: &mut Proof,
 ) -> (
     bool,
+    Vec<bool>,
     Vec<C::ChallengeField>,
     Vec<C::ChallengeField>,
-    Vec<C::ChallengeField>,
-    C::ChallengeField,
     C::ChallengeField,
 ) {
     let timer = start_timer!(|| "gkr verify");
@@ -295,19 +378,26 @@ pub fn gkr_verify<C: GKRConfig>(
         rz1.push(C::ChallengeField::zero());
     }
 
+    let (lanes_verified, claimed_v_simd) =
+        verify_output_claims(expected_outputs, &rz0, transcript, proof);
+
     for _ in 0..C::get_field_pack_size().trailing_zeros() {
         r_simd.push(transcript.challenge_f::<C>());
     }
 
     let mut alpha = C::ChallengeField::one();
     let mut beta = C::ChallengeField::zero();
-    let mut claimed_v0 = *claimed_v;
+    let mut scratch = vec![C::ChallengeField::zero(); C::get_field_pack_size()];
+    let mut claimed_v0 =
+        MultiLinearPoly::eval_generic(&claimed_v_simd.unpack(), &r_simd, &mut scratch);
     let mut claimed_v1 = C::ChallengeField::zero();
 
+    let domains = SumcheckDomains::new();
     let mut verified = true;
     for i in (0..layer_num).rev() {
         let cur_verified;
         (cur_verified, rz0, rz1, r_simd, claimed_v0, claimed_v1) = sumcheck_verify_gkr_layer(
+            &domains,
             &circuit.layers[i],
             &rz0,
             &rz1,
@@ -331,81 +421,369 @@ pub fn gkr_verify<C: GKRConfig>(
             claimed_v1
         );
     }
+
+    let (reduction_verified, rz, claimed_v) = sumcheck_verify_input_claim_reduction(
+        &domains, &rz0, &rz1, claimed_v0, claimed_v1, alpha, beta, proof, transcript,
+    );
+    verified &= reduction_verified;
+
+    end_timer!(timer);
+    (verified, lanes_verified, rz, r_simd, claimed_v)
+}
+
+// Reduces the claims on the input layer at rz0 and rz1 to a single claim at rz,
+// with a sumcheck on alpha * eq(rz0, b) * V(b) + beta * eq(rz1, b) * V(b).
+#[allow(clippy::too_many_arguments)]
+fn sumcheck_verify_input_claim_reduction<C: GKRConfig>(
+    domains: &SumcheckDomains<C>,
+    rz0: &[C::ChallengeField],
+    rz1: &[C::ChallengeField],
+    claimed_v0: C::ChallengeField,
+    claimed_v1: C::ChallengeField,
+    alpha: C::ChallengeField,
+    beta: C::ChallengeField,
+    proof: &mut Proof,
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+) -> (bool, Vec<C::ChallengeField>, C::ChallengeField) {
+    let mut sum = claimed_v0 * alpha + claimed_v1 * beta;
+    let mut rz = vec![];
+    let mut verified = true;
+    for _i_var in 0..rz0.len() {
+        verified &= verify_sumcheck_step(domains, proof, 2, transcript, &mut sum, &mut rz);
+    }
+
+    let claimed_v = proof.get_next_and_step::<C::ChallengeField>();
+    verified &= sum == claimed_v * (alpha * _eq_vec(rz0, &rz) + beta * _eq_vec(rz1, &rz));
+    transcript.append_challenge_f::<C>(&claimed_v);
+    (verified, rz, claimed_v)
+}
+
+// The GKR^2 sumcheck only covers the constant and uni gates: a proof ignoring the add and mul
+// gates of a layer must not pass. Its degree 6 polynomials are evaluated at 0, 1, ..., 6,
+// which are not distinct over GF2.
+fn is_gkr_square_circuit<C: GKRConfig>(circuit: &Circuit<C>) -> bool {
+    C::FIELD_TYPE != FieldType::GF2
+        && circuit
+            .layers
+            .iter()
+            .all(|layer| layer.add.is_empty() && layer.mul.is_empty())
+}
+
+// GKR^2 does not run sumcheck over the simd variables:
+// each layer carries one claim per simd lane, packed into a C::Field.
+#[allow(clippy::type_complexity)]
+fn sumcheck_verify_gkr_square_layer<C: GKRConfig>(
+    domains: &SumcheckDomains<C>,
+    layer: &CircuitLayer<C>,
+    rz0: &[C::ChallengeField],
+    claimed_v0: C::Field,
+    proof: &mut Proof,
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+) -> (bool, Vec<C::ChallengeField>, C::Field) {
+    const D: usize = 7;
+
+    let mut eq_evals_at_rz0 = vec![C::ChallengeField::zero(); 1 << rz0.len()];
+    eq_evals_at_primitive(rz0, &C::ChallengeField::one(), &mut eq_evals_at_rz0);
+
+    // the prover leaves the constant gates out of the sumcheck
+    let cst_eval: C::ChallengeField = layer
+        .const_
+        .iter()
+        .map(|gate| C::challenge_mul_circuit_field(&eq_evals_at_rz0[gate.o_id], &gate.coef))
+        .sum();
+    let mut sums = claimed_v0
+        .unpack()
+        .into_iter()
+        .map(|v| v - cst_eval)
+        .collect::<Vec<_>>();
+
+    let mut rx = vec![];
+    let mut verified = true;
+
+    for _i_var in 0..layer.input_var_num {
+        let mut ps = vec![];
+        for _ in 0..D {
+            let p = proof.get_next_and_step::<C::Field>();
+            transcript.append_f::<C>(p);
+            ps.push(p.unpack());
+        }
+        let r = transcript.challenge_f::<C>();
+        rx.push(r);
+
+        for (lane, sum) in sums.iter_mut().enumerate() {
+            let lane_ps = ps.iter().map(|p| p[lane]).collect::<Vec<_>>();
+            verified &= lane_ps[0] + lane_ps[1] == *sum;
+            *sum = domains.degree_6.evaluate(&lane_ps, &r);
+        }
+    }
+
+    let vx_claim = proof.get_next_and_step::<C::Field>();
+    transcript.append_f::<C>(vx_claim);
+
+    let mut eq_evals_at_rx = vec![C::ChallengeField::zero(); 1 << rx.len()];
+    eq_evals_at_primitive(&rx, &C::ChallengeField::one(), &mut eq_evals_at_rx);
+
+    let mut pow5_eval = C::ChallengeField::zero();
+    let mut pow1_eval = C::ChallengeField::zero();
+    for gate in &layer.uni {
+        let v = C::challenge_mul_circuit_field(
+            &(eq_evals_at_rz0[gate.o_id] * eq_evals_at_rx[gate.i_ids[0]]),
+            &gate.coef,
+        );
+        match gate.gate_type {
+            12345 => pow5_eval += v,
+            12346 => pow1_eval += v,
+            // the circuit comes from a file, reject it rather than panic
+            _ => return (false, rx, vx_claim),
+        }
+    }
+
+    for (sum, vx) in sums.iter().zip(vx_claim.unpack()) {
+        let vx_5 = vx.square().square() * vx;
+        verified &= *sum == pow5_eval * vx_5 + pow1_eval * vx;
+    }
+
+    (verified, rx, vx_claim)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn gkr_square_verify<C: GKRConfig>(
+    circuit: &Circuit<C>,
+    expected_outputs: &ExpectedOutputs<C>,
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+    proof: &mut Proof,
+) -> (
+    bool,
+    Vec<bool>,
+    Vec<C::ChallengeField>,
+    Vec<C::ChallengeField>,
+    C::ChallengeField,
+) {
+    let timer = start_timer!(|| "gkr^2 verify");
+    let layer_num = circuit.layers.len();
+    let mut rz0 = vec![];
+    let mut r_simd = vec![];
+
+    for _ in 0..circuit.layers.last().unwrap().output_var_num {
+        rz0.push(transcript.challenge_f::<C>());
+    }
+
+    let (lanes_verified, claimed_v_simd) =
+        verify_output_claims(expected_outputs, &rz0, transcript, proof);
+
+    for _ in 0..C::get_field_pack_size().trailing_zeros() {
+        r_simd.push(transcript.challenge_f::<C>());
+    }
+
+    let mut scratch = vec![C::ChallengeField::zero(); C::get_field_pack_size()];
+    let mut verified = true;
+    let mut claimed_v0 = claimed_v_simd;
+    let domains = SumcheckDomains::new();
+    for i in (0..layer_num).rev() {
+        let cur_verified;
+        (cur_verified, rz0, claimed_v0) = sumcheck_verify_gkr_square_layer(
+            &domains,
+            &circuit.layers[i],
+            &rz0,
+            claimed_v0,
+            proof,
+            transcript,
+        );
+        verified &= cur_verified;
+        log::trace!("Layer {} verified, claimed_v0={:?}", i, claimed_v0);
+    }
+
+    // fold the per-lane input claims with fresh randomness
+    r_simd.clear();
+    for _ in 0..C::get_field_pack_size().trailing_zeros() {
+        r_simd.push(transcript.challenge_f::<C>());
+    }
+    let claimed_v0 = MultiLinearPoly::eval_generic(&claimed_v0.unpack(), &r_simd, &mut scratch);
+
     end_timer!(timer);
-    (verified, rz0, rz1, r_simd, claimed_v0, claimed_v1)
+    (verified, lanes_verified, rz0, r_simd, claimed_v0)
+}
+
+// Reads an object of variable size from the proof, returns it along with its serialized bytes.
+// The caller decides when the bytes are appended to the transcript.
+fn read_from_proof<T>(
+    proof: &mut Proof,
+    deserialize: impl FnOnce(&mut Cursor<&[u8]>) -> FieldSerdeResult<T>,
+) -> FieldSerdeResult<(T, Vec<u8>)> {
+    let mut cursor = Cursor::new(proof.remaining_bytes());
+    let ret = deserialize(&mut cursor)?;
+    let size = cursor.position() as usize;
+    let bytes = proof.remaining_bytes()[..size].to_vec();
+    proof.step(size);
+    Ok((ret, bytes))
+}
+
+// The input layer is the committed private part on [0, 2^m), followed by the public part:
+// V(x) = eq(x[m..], 0) * W(x[..m]) + Pub(x),
+// where Pub is the MLE of the public inputs, zero on [0, 2^m).
+// The prover sends W(x[..m]) along with its opening.
+#[allow(clippy::too_many_arguments)]
+fn verify_input_claim<C: GKRConfig, PCS: PolynomialCommitment<C>>(
+    pcs_params: &PCS::Params,
+    commitment: &PCS::Commitment,
+    circuit: &Circuit<C>,
+    x: &[C::ChallengeField],
+    r_simd: &[C::ChallengeField],
+    claimed_v: C::ChallengeField,
+    proof: &mut Proof,
+    transcript: &mut Transcript<C::FiatShamirHashType>,
+) -> bool {
+    let private_var_num = circuit.log_private_input_size();
+    let eq_at_zero: C::ChallengeField = x[private_var_num..]
+        .iter()
+        .map(|r| C::ChallengeField::one() - r)
+        .product();
+
+    let public_v = if private_var_num == x.len() {
+        C::ChallengeField::zero()
+    } else {
+        let input_vals = &circuit.layers[0].input_vals;
+        let mut public_vals = vec![C::SimdCircuitField::zero(); 1 << x.len()];
+        public_vals[1 << private_var_num..].copy_from_slice(&input_vals[1 << private_var_num..]);
+        MultiLinearPoly::eval_circuit_vals_at_point::<C>(&public_vals, x, r_simd)
+    };
+
+    let private_v = proof.get_next_and_step::<C::ChallengeField>();
+    transcript.append_challenge_f::<C>(&private_v);
+
+    let (opening, opening_bytes) =
+        match read_from_proof(proof, |reader| PCS::deserialize_opening(pcs_params, reader)) {
+            Ok(ret) => ret,
+            Err(_) => return false,
+        };
+
+    let verified = claimed_v == eq_at_zero * private_v + public_v
+        && PCS::verify(
+            pcs_params,
+            commitment,
+            &x[..private_var_num],
+            r_simd,
+            private_v,
+            &opening,
+            transcript,
+        );
+    // the opening replays its own Fiat-Shamir before being absorbed, same as the prover
+    transcript.append_u8_slice(&opening_bytes);
+    verified
 }
 
-pub struct Verifier<C: GKRConfig> {
+pub struct Verifier<C: GKRConfig, PCS: PolynomialCommitment<C> = RawCommitment<C>> {
     config: Config<C>,
+    pcs_params: PCS::Params,
 }
 
 impl<C: GKRConfig> Default for Verifier<C> {
     fn default() -> Self {
         Self {
             config: Config::<C>::default(),
+            pcs_params: (),
         }
     }
 }
 
 impl<C: GKRConfig> Verifier<C> {
     pub fn new(config: &Config<C>) -> Self {
+        Self::new_with_pcs_params(config, ())
+    }
+}
+
+impl<C: GKRConfig, PCS: PolynomialCommitment<C>> Verifier<C, PCS> {
+    pub fn new_with_pcs_params(config: &Config<C>, pcs_params: PCS::Params) -> Self {
+        assert_eq!(config.polynomial_commitment_type, PCS::TYPE);
         Verifier {
             config: config.clone(),
+            pcs_params,
         }
     }
 
+    /// Verify the proof, and that every simd lane produces the expected outputs.
     pub fn verify(
         &self,
         circuit: &mut Circuit<C>,
-        claimed_v: &C::ChallengeField,
+        expected_outputs: &ExpectedOutputs<C>,
         proof: &Proof,
     ) -> bool {
+        self.verify_lanes(circuit, expected_outputs, proof)
+            .iter()
+            .all(|lane_verified| *lane_verified)
+    }
+
+    /// Verify the proof, and report for each simd lane whether it produces the expected outputs.
+    /// All lanes fail if the proof itself is invalid.
+    pub fn verify_lanes(
+        &self,
+        circuit: &mut Circuit<C>,
+        expected_outputs: &ExpectedOutputs<C>,
+        proof: &Proof,
+    ) -> Vec<bool> {
         let timer = start_timer!(|| "verify");
 
-        let poly_size = circuit.layers.first().unwrap().input_vals.len();
-        let mut cursor = Cursor::new(&proof.bytes);
+        // the circuit comes from a file, reject it rather than panic
+        if !circuit.has_valid_public_input_size()
+            || !expected_outputs.fits(circuit)
+            || (self.config.gkr_scheme == GKRScheme::GkrSquare && !is_gkr_square_circuit(circuit))
+        {
+            return vec![false; C::get_field_pack_size()];
+        }
 
-        let commitment = RawCommitment::<C>::deserialize_from(&mut cursor, poly_size);
+        let mut proof = proof.clone(); // FIXME: consider separating pointers to make proof always immutable?
 
         let mut transcript = Transcript::new();
-        transcript.append_u8_slice(&proof.bytes[..commitment.size()]);
+        transcript.append_public_u8_slice(&self.config.domain_separator());
+        transcript.append_public_u8_slice(&circuit.digest());
+        transcript.append_public_u8_slice(&circuit.public_input_bytes());
+
+        let (commitment, commitment_bytes) = match read_from_proof(&mut proof, |reader| {
+            PCS::deserialize_commitment(&self.pcs_params, reader)
+        }) {
+            Ok(ret) => ret,
+            Err(_) => return vec![false; C::get_field_pack_size()],
+        };
+        transcript.append_u8_slice(&commitment_bytes);
 
         // ZZ: shall we use probabilistic grinding so the verifier can avoid this cost?
         // (and also be recursion friendly)
         #[cfg(feature = "grinding")]
         grind::<C>(&mut transcript, &self.config);
+        #[cfg(feature = "grinding")]
+        proof.step(32);
 
         circuit.fill_rnd_coefs(&mut transcript);
 
-        let mut proof = proof.clone(); // FIXME: consider separating pointers to make proof always immutable?
-
-        #[cfg(feature = "grinding")]
-        proof.step(commitment.size() + 32);
-        #[cfg(not(feature = "grinding"))]
-        proof.step(commitment.size());
-
-        let (mut verified, rz0, rz1, r_simd, claimed_v0, claimed_v1) =
-            gkr_verify(circuit, claimed_v, &mut transcript, &mut proof);
+        // both schemes end with a single claim on the input layer
+        let (mut verified, lanes_verified, rz, r_simd, claimed_v) = match self.config.gkr_scheme {
+            GKRScheme::Vanilla => {
+                gkr_verify(circuit, expected_outputs, &mut transcript, &mut proof)
+            }
+            GKRScheme::GkrSquare => {
+                gkr_square_verify(circuit, expected_outputs, &mut transcript, &mut proof)
+            }
+        };
 
         log::info!("GKR verification: {}", verified);
 
-        match self.config.polynomial_commitment_type {
-            crate::PolynomialCommitmentType::Raw => {
-                // for Raw, no need to load from proof
-                log::trace!("rz0.size() = {}", rz0.len());
-                log::trace!("Poly_vals.size() = {}", commitment.poly_vals.len());
-
-                let v1 = commitment.verify(&rz0, &r_simd, claimed_v0);
-                let v2 = commitment.verify(&rz1, &r_simd, claimed_v1);
-
-                verified &= v1;
-                verified &= v2;
-            }
-            _ => todo!(),
-        }
+        log::trace!("rz.size() = {}", rz.len());
+        verified &= verify_input_claim::<C, PCS>(
+            &self.pcs_params,
+            &commitment,
+            circuit,
+            &rz,
+            &r_simd,
+            claimed_v,
+            &mut proof,
+            &mut transcript,
+        );
 
         end_timer!(timer);
 
-        verified
+        lanes_verified
+            .into_iter()
+            .map(|lane_verified| lane_verified && verified)
+            .collect()
     }
 }
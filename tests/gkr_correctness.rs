@@ -1,8 +1,12 @@
+use arith::{Field, SimdField};
 use expander_rs::{utils::*, FieldType};
 use expander_rs::{
-    BN254ConfigKeccak, BN254ConfigSha2, Circuit, CircuitLayer, Config, GF2ExtConfigKeccak,
-    GF2ExtConfigSha2, GKRConfig, GKRScheme, GateAdd, GateMul, M31ExtConfigKeccak, M31ExtConfigSha2,
-    Prover, Verifier,
+    BN254ConfigKeccak, BN254ConfigSha2, BN254x8ConfigKeccak, BN254x8ConfigSha2,
+    BabyBearExtConfigKeccak, BabyBearExtConfigSha2, Basefold, BiKZG, Circuit, CircuitLayer, Config,
+    ExpectedOutputs, GF2ExtConfigKeccak, GF2ExtConfigSha2, GKRConfig, GKRScheme, GateAdd, GateMul,
+    GateUni, GoldilocksExtConfigKeccak, GoldilocksExtConfigSha2, Hyrax, M31Ext6ConfigKeccak,
+    M31Ext6ConfigSha2, M31ExtConfigKeccak, M31ExtConfigSha2, Orion, PolynomialCommitment, Prover,
+    Transcript, Verifier,
 };
 use std::panic;
 use std::panic::AssertUnwindSafe;
@@ -11,7 +15,6 @@ use std::time::Instant;
 use rand::Rng;
 use sha2::Digest;
 
-#[allow(dead_code)]
 fn gen_simple_circuit<C: GKRConfig>() -> Circuit<C> {
     let mut circuit = Circuit::default();
     let mut l0 = CircuitLayer::default();
@@ -46,9 +49,433 @@ fn gen_simple_circuit<C: GKRConfig>() -> Circuit<C> {
         gate_type: 1,
     });
     circuit.layers.push(l0.clone());
+    circuit.identify_rnd_coefs();
     circuit
 }
 
+// a two-layer circuit mixing pow5/pow1 uni gates with add and mul gates
+fn gen_uni_gate_circuit<C: GKRConfig>() -> Circuit<C> {
+    let mut circuit = Circuit::default();
+    let mut l0 = CircuitLayer::default();
+    l0.input_var_num = 3;
+    l0.output_var_num = 3;
+    for i in 0..8 {
+        l0.uni.push(GateUni {
+            i_ids: [i],
+            o_id: i,
+            coef: C::CircuitField::from(i as u32 + 1),
+            is_random: false,
+            gate_type: 12345,
+        });
+    }
+    l0.uni.push(GateUni {
+        i_ids: [3],
+        o_id: 0,
+        coef: C::CircuitField::from(7),
+        is_random: false,
+        gate_type: 12346,
+    });
+    l0.add.push(GateAdd {
+        i_ids: [5],
+        o_id: 2,
+        coef: C::CircuitField::from(1),
+        is_random: false,
+        gate_type: 1,
+    });
+    circuit.layers.push(l0);
+
+    let mut l1 = CircuitLayer::default();
+    l1.input_var_num = 3;
+    l1.output_var_num = 3;
+    for i in 0..8 {
+        l1.mul.push(GateMul {
+            i_ids: [i, 7 - i],
+            o_id: i,
+            coef: C::CircuitField::from(1),
+            is_random: false,
+            gate_type: 0,
+        });
+        l1.uni.push(GateUni {
+            i_ids: [i],
+            o_id: 7 - i,
+            coef: C::CircuitField::from(2),
+            is_random: false,
+            gate_type: 12346,
+        });
+    }
+    circuit.layers.push(l1);
+    circuit.identify_rnd_coefs();
+    circuit
+}
+
+#[test]
+fn test_gkr_uni_gate_correctness() {
+    test_gkr_uni_gate_correctness_helper::<M31ExtConfigSha2>(&Config::<M31ExtConfigSha2>::new(
+        GKRScheme::Vanilla,
+    ));
+    test_gkr_uni_gate_correctness_helper::<BN254ConfigSha2>(&Config::<BN254ConfigSha2>::new(
+        GKRScheme::Vanilla,
+    ));
+}
+
+fn test_gkr_uni_gate_correctness_helper<C: GKRConfig>(config: &Config<C>) {
+    let mut circuit = gen_uni_gate_circuit::<C>();
+    circuit.set_random_input_for_test();
+
+    let mut prover = Prover::new(config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let mut outputs = circuit.layers.last().unwrap().output_vals.clone();
+
+    let verifier = Verifier::new(config);
+    assert!(verifier.verify(&mut circuit, &ExpectedOutputs::Values(&outputs), &proof));
+
+    // only the lane with a wrong expected output fails
+    let bad_lane = C::get_field_pack_size() - 1;
+    let mut output_lanes = outputs[0].unpack();
+    output_lanes[bad_lane] += C::CircuitField::one();
+    outputs[0] = C::SimdCircuitField::pack(&output_lanes);
+    let lanes_verified =
+        verifier.verify_lanes(&mut circuit, &ExpectedOutputs::Values(&outputs), &proof);
+    assert_eq!(lanes_verified.len(), C::get_field_pack_size());
+    for (i, lane_verified) in lanes_verified.iter().enumerate() {
+        assert_eq!(*lane_verified, i != bad_lane);
+    }
+    assert!(!verifier.verify(&mut circuit, &ExpectedOutputs::Values(&outputs), &proof));
+
+    // the wrong number of expected outputs fails every lane
+    let lanes_verified = verifier.verify_lanes(
+        &mut circuit,
+        &ExpectedOutputs::Values(&outputs[1..]),
+        &proof,
+    );
+    assert_eq!(lanes_verified, vec![false; C::get_field_pack_size()]);
+}
+
+#[test]
+fn test_domain_separator() {
+    let bn254 = Config::<BN254ConfigSha2>::new(GKRScheme::Vanilla).domain_separator();
+    let bn254x8 = Config::<BN254x8ConfigSha2>::new(GKRScheme::Vanilla).domain_separator();
+    assert_ne!(bn254, bn254x8);
+
+    let m31_ext3 = Config::<M31ExtConfigSha2>::new(GKRScheme::Vanilla).domain_separator();
+    let m31_ext6 = Config::<M31Ext6ConfigSha2>::new(GKRScheme::Vanilla).domain_separator();
+    assert_ne!(m31_ext3, m31_ext6);
+}
+
+#[test]
+fn test_gkr_zero_outputs() {
+    type C = M31ExtConfigSha2;
+    let config = Config::<C>::new(GKRScheme::Vanilla);
+
+    // out[i] = in[i] * in[i] - in[i]^2, zero for every input
+    let mut circuit = Circuit::<C>::default();
+    let mut l0 = CircuitLayer::default();
+    l0.input_var_num = 3;
+    l0.output_var_num = 3;
+    for i in 0..8 {
+        l0.mul.push(GateMul {
+            i_ids: [i, i],
+            o_id: i,
+            coef: <C as GKRConfig>::CircuitField::one(),
+            is_random: false,
+            gate_type: 0,
+        });
+        l0.mul.push(GateMul {
+            i_ids: [i, i],
+            o_id: i,
+            coef: -<C as GKRConfig>::CircuitField::one(),
+            is_random: false,
+            gate_type: 0,
+        });
+    }
+    circuit.layers.push(l0);
+    circuit.identify_rnd_coefs();
+    circuit.set_random_input_for_test();
+
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+
+    let verifier = Verifier::new(&config);
+    assert!(verifier.verify(&mut circuit, &ExpectedOutputs::Zero, &proof));
+
+    let mut bad_circuit = gen_uni_gate_circuit::<C>();
+    bad_circuit.set_random_input_for_test();
+    prover.prepare_mem(&bad_circuit);
+    let (_claimed_v, bad_proof) = prover.prove(&mut bad_circuit);
+    assert!(!verifier.verify(&mut bad_circuit, &ExpectedOutputs::Zero, &bad_proof));
+}
+
+#[test]
+fn test_gkr_proof_bound_to_circuit() {
+    let config = Config::<M31ExtConfigSha2>::new(GKRScheme::Vanilla);
+    let mut circuit = gen_uni_gate_circuit::<M31ExtConfigSha2>();
+    circuit.set_random_input_for_test();
+
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+    let expected_outputs = ExpectedOutputs::Values(&outputs);
+
+    // same shape, different wiring
+    let mut other_circuit = circuit.clone();
+    other_circuit.layers[0].add[0].i_ids = [6];
+    assert_ne!(circuit.digest(), other_circuit.digest());
+
+    let verifier = Verifier::new(&config);
+    assert!(verifier.verify(&mut circuit, &expected_outputs, &proof));
+    assert!(!verifier.verify(&mut other_circuit, &expected_outputs, &proof));
+}
+
+#[test]
+fn test_gkr_unknown_uni_gate() {
+    let config = Config::<M31ExtConfigSha2>::new(GKRScheme::Vanilla);
+    let mut circuit = gen_uni_gate_circuit::<M31ExtConfigSha2>();
+    circuit.set_random_input_for_test();
+
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+
+    let mut bad_circuit = circuit.clone();
+    bad_circuit.layers[1].uni[0].gate_type = 54321;
+    let verifier = Verifier::new(&config);
+    assert!(!verifier.verify(&mut bad_circuit, &ExpectedOutputs::Values(&outputs), &proof));
+}
+
+#[test]
+fn test_gkr_public_input() {
+    type C = M31ExtConfigSha2;
+    let config = Config::<C>::new(GKRScheme::Vanilla);
+
+    let mut circuit = gen_uni_gate_circuit::<C>();
+    circuit.public_input_size = 4;
+    circuit.set_random_input_for_test();
+    assert_eq!(circuit.log_private_input_size(), 2);
+
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+    let expected_outputs = ExpectedOutputs::Values(&outputs);
+
+    // the verifier only knows the public part of the input layer
+    let mut verifier_circuit = gen_uni_gate_circuit::<C>();
+    verifier_circuit.public_input_size = 4;
+    verifier_circuit.set_public_input(circuit.public_input());
+
+    let verifier = Verifier::new(&config);
+    assert!(verifier.verify(&mut verifier_circuit, &expected_outputs, &proof));
+
+    let mut bad_public_input = circuit.public_input().to_vec();
+    bad_public_input[0] += <C as GKRConfig>::SimdCircuitField::one();
+    verifier_circuit.set_public_input(&bad_public_input);
+    assert!(!verifier.verify(&mut verifier_circuit, &expected_outputs, &proof));
+}
+
+#[test]
+fn test_gkr_public_input_size_not_power_of_two() {
+    type C = M31ExtConfigSha2;
+    let config = Config::<C>::new(GKRScheme::Vanilla);
+
+    // 8 inputs with 3 public ones: rounding the 5 private slots up to 8 would commit
+    // the public slots as private data, unchecked against the public inputs
+    let mut circuit = gen_uni_gate_circuit::<C>();
+    circuit.public_input_size = 3;
+    circuit.set_random_input_for_test();
+    assert!(!circuit.has_valid_public_input_size());
+    let public_input = circuit.public_input().to_vec();
+
+    // tamper a public slot
+    *circuit.layers[0].input_vals.last_mut().unwrap() += <C as GKRConfig>::SimdCircuitField::one();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut prover = Prover::new(&config);
+        prover.prepare_mem(&circuit);
+        prover.prove(&mut circuit.clone())
+    }));
+    assert!(result.is_err());
+
+    // the same attack with the whole input layer committed
+    circuit.public_input_size = 0;
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+
+    let mut verifier_circuit = gen_uni_gate_circuit::<C>();
+    verifier_circuit.public_input_size = 3;
+    verifier_circuit.set_public_input(&public_input);
+    let verifier = Verifier::new(&config);
+    assert!(!verifier.verify(
+        &mut verifier_circuit,
+        &ExpectedOutputs::Values(&outputs),
+        &proof
+    ));
+}
+
+#[test]
+fn test_gkr_square_rejects_add_and_mul_gates() {
+    type C = M31ExtConfigSha2;
+    let config = Config::<C>::new(GKRScheme::GkrSquare);
+
+    let mut circuit = Circuit::<C>::load_circuit(POSEIDON_CIRCUIT);
+    circuit.set_random_input_for_test();
+
+    let mut prover = Prover::new(&config);
+    prover.prepare_mem(&circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+    let expected_outputs = ExpectedOutputs::Values(&outputs);
+
+    let verifier = Verifier::new(&config);
+    assert!(verifier.verify(&mut circuit, &expected_outputs, &proof));
+
+    // the GKR^2 sumcheck never looks at the add and mul gates
+    let mut add_circuit = circuit.clone();
+    add_circuit.layers[0].add.push(GateAdd {
+        i_ids: [0],
+        o_id: 0,
+        coef: <C as GKRConfig>::CircuitField::one(),
+        is_random: false,
+        gate_type: 1,
+    });
+    assert!(!verifier.verify(&mut add_circuit, &expected_outputs, &proof));
+
+    let mut mul_circuit = circuit.clone();
+    mul_circuit.layers[0].mul.push(GateMul {
+        i_ids: [0, 1],
+        o_id: 0,
+        coef: <C as GKRConfig>::CircuitField::one(),
+        is_random: false,
+        gate_type: 0,
+    });
+    assert!(!verifier.verify(&mut mul_circuit, &expected_outputs, &proof));
+}
+
+#[test]
+#[should_panic(expected = "GKR^2 is not supported over GF2")]
+fn test_gkr_square_gf2_config() {
+    Config::<GF2ExtConfigSha2>::new(GKRScheme::GkrSquare);
+}
+
+#[test]
+fn test_gkr_bi_kzg() {
+    test_gkr_pcs_helper::<BN254ConfigSha2, BiKZG>(gen_uni_gate_circuit);
+}
+
+#[test]
+fn test_gkr_hyrax() {
+    test_gkr_pcs_helper::<BN254ConfigSha2, Hyrax>(gen_uni_gate_circuit);
+    test_gkr_pcs_helper::<BN254ConfigKeccak, Hyrax>(gen_uni_gate_circuit);
+}
+
+#[test]
+fn test_gkr_orion() {
+    test_gkr_pcs_helper::<M31ExtConfigSha2, Orion>(gen_uni_gate_circuit);
+    test_gkr_pcs_helper::<M31ExtConfigKeccak, Orion>(gen_uni_gate_circuit);
+
+    // no pow5 gates over GF2
+    test_gkr_pcs_helper::<GF2ExtConfigSha2, Orion>(gen_simple_circuit);
+    test_gkr_pcs_helper::<GF2ExtConfigKeccak, Orion>(gen_simple_circuit);
+}
+
+#[test]
+fn test_orion_setup_is_transparent() {
+    type C = GF2ExtConfigSha2;
+    let config = Config::<C>::new(GKRScheme::Vanilla);
+    let params = <Orion as PolynomialCommitment<C>>::setup(&config, 8, ark_std::test_rng());
+    let other_params = <Orion as PolynomialCommitment<C>>::setup(&config, 8, rand::thread_rng());
+
+    let mut rng = ark_std::test_rng();
+    let msg = (0..params.code.msg_len())
+        .map(|_| <C as GKRConfig>::ChallengeField::random_unsafe(&mut rng))
+        .collect::<Vec<_>>();
+    let mul = |c: &<C as GKRConfig>::ChallengeField, m: &<C as GKRConfig>::ChallengeField| *c * *m;
+    assert_eq!(
+        params.code.encode(&msg, &mul),
+        other_params.code.encode(&msg, &mul)
+    );
+}
+
+#[test]
+fn test_gkr_basefold() {
+    test_gkr_pcs_helper::<M31ExtConfigSha2, Basefold>(gen_uni_gate_circuit);
+    test_gkr_pcs_helper::<M31ExtConfigKeccak, Basefold>(gen_uni_gate_circuit);
+}
+
+#[test]
+fn test_basefold_setup_is_transparent() {
+    type C = M31ExtConfigSha2;
+    let mut config = Config::<C>::new(GKRScheme::Vanilla);
+    config.log_blowup = 3;
+    let params = <Basefold as PolynomialCommitment<C>>::setup(&config, 4, ark_std::test_rng());
+    let other_params = <Basefold as PolynomialCommitment<C>>::setup(&config, 4, rand::thread_rng());
+    assert_eq!(params.log_blowup, 3);
+    assert_eq!(params.twiddles, other_params.twiddles);
+}
+
+fn test_gkr_pcs_helper<C: GKRConfig, PCS: PolynomialCommitment<C>>(
+    gen_circuit: fn() -> Circuit<C>,
+) {
+    let mut config = Config::<C>::new(GKRScheme::Vanilla);
+    config.polynomial_commitment_type = PCS::TYPE;
+
+    // all private, then half of the input layer public
+    let input_size = 1 << gen_circuit().layers[0].input_var_num;
+    for public_input_size in [0, input_size / 2] {
+        let mut circuit = gen_circuit();
+        circuit.public_input_size = public_input_size;
+        circuit.set_random_input_for_test();
+        let pcs_params = PCS::setup(
+            &config,
+            circuit.log_private_input_size(),
+            ark_std::test_rng(),
+        );
+
+        let mut prover = Prover::<C, PCS>::new_with_pcs_params(&config, pcs_params.clone());
+        prover.prepare_mem(&circuit);
+        let (_claimed_v, proof) = prover.prove(&mut circuit);
+        let outputs = circuit.layers.last().unwrap().output_vals.clone();
+        let expected_outputs = ExpectedOutputs::Values(&outputs);
+
+        let verifier = Verifier::<C, PCS>::new_with_pcs_params(&config, pcs_params.clone());
+        assert!(verifier.verify(&mut circuit, &expected_outputs, &proof));
+
+        // the commitment starts the proof and the opening ends it; the Merkle multi-paths
+        // depend on the queries, so opening_bytes only gives the size of the opening roughly
+        let mut commitment_bytes = vec![];
+        let commitment = PCS::commit(&pcs_params, circuit.private_input());
+        PCS::serialize_commitment(&commitment, &mut commitment_bytes).unwrap();
+        let x = vec![C::ChallengeField::zero(); circuit.log_private_input_size()];
+        let r_simd =
+            vec![C::ChallengeField::zero(); C::get_field_pack_size().trailing_zeros() as usize];
+        let opening = PCS::open(
+            &pcs_params,
+            circuit.private_input(),
+            &x,
+            &r_simd,
+            &mut Transcript::new(),
+        );
+        let mut opening_bytes = vec![];
+        PCS::serialize_opening(&opening, &mut opening_bytes).unwrap();
+
+        let proof_len = proof.bytes.len();
+        for idx in [
+            0,
+            commitment_bytes.len() - 1,
+            proof_len - opening_bytes.len() / 2,
+            proof_len - 1,
+        ] {
+            let mut bad_proof = proof.clone();
+            bad_proof.bytes[idx] ^= 1;
+            assert!(!verifier.verify(&mut circuit, &expected_outputs, &bad_proof));
+        }
+    }
+}
+
 #[test]
 fn test_gkr_correctness() {
     test_gkr_correctness_helper::<GF2ExtConfigSha2>(&Config::<GF2ExtConfigSha2>::new(
@@ -63,12 +490,76 @@ fn test_gkr_correctness() {
     test_gkr_correctness_helper::<M31ExtConfigKeccak>(&Config::<M31ExtConfigKeccak>::new(
         GKRScheme::Vanilla,
     ));
+    test_gkr_correctness_helper::<M31Ext6ConfigSha2>(&Config::<M31Ext6ConfigSha2>::new(
+        GKRScheme::Vanilla,
+    ));
+    test_gkr_correctness_helper::<M31Ext6ConfigKeccak>(&Config::<M31Ext6ConfigKeccak>::new(
+        GKRScheme::Vanilla,
+    ));
     test_gkr_correctness_helper::<BN254ConfigSha2>(&Config::<BN254ConfigSha2>::new(
         GKRScheme::Vanilla,
     ));
     test_gkr_correctness_helper::<BN254ConfigKeccak>(&Config::<BN254ConfigKeccak>::new(
         GKRScheme::Vanilla,
     ));
+    test_gkr_correctness_helper::<BN254x8ConfigSha2>(&Config::<BN254x8ConfigSha2>::new(
+        GKRScheme::Vanilla,
+    ));
+    test_gkr_correctness_helper::<BN254x8ConfigKeccak>(&Config::<BN254x8ConfigKeccak>::new(
+        GKRScheme::Vanilla,
+    ));
+    test_gkr_correctness_helper::<GoldilocksExtConfigSha2>(
+        &Config::<GoldilocksExtConfigSha2>::new(GKRScheme::Vanilla),
+    );
+    test_gkr_correctness_helper::<GoldilocksExtConfigKeccak>(
+        &Config::<GoldilocksExtConfigKeccak>::new(GKRScheme::Vanilla),
+    );
+    test_gkr_correctness_helper::<BabyBearExtConfigSha2>(&Config::<BabyBearExtConfigSha2>::new(
+        GKRScheme::Vanilla,
+    ));
+    test_gkr_correctness_helper::<BabyBearExtConfigKeccak>(
+        &Config::<BabyBearExtConfigKeccak>::new(GKRScheme::Vanilla),
+    );
+}
+
+#[test]
+fn test_gkr_square_correctness() {
+    test_gkr_correctness_helper::<M31ExtConfigSha2>(&Config::<M31ExtConfigSha2>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<M31ExtConfigKeccak>(&Config::<M31ExtConfigKeccak>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<M31Ext6ConfigSha2>(&Config::<M31Ext6ConfigSha2>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<M31Ext6ConfigKeccak>(&Config::<M31Ext6ConfigKeccak>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<BN254ConfigSha2>(&Config::<BN254ConfigSha2>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<BN254ConfigKeccak>(&Config::<BN254ConfigKeccak>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<BN254x8ConfigSha2>(&Config::<BN254x8ConfigSha2>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<BN254x8ConfigKeccak>(&Config::<BN254x8ConfigKeccak>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<GoldilocksExtConfigSha2>(
+        &Config::<GoldilocksExtConfigSha2>::new(GKRScheme::GkrSquare),
+    );
+    test_gkr_correctness_helper::<GoldilocksExtConfigKeccak>(
+        &Config::<GoldilocksExtConfigKeccak>::new(GKRScheme::GkrSquare),
+    );
+    test_gkr_correctness_helper::<BabyBearExtConfigSha2>(&Config::<BabyBearExtConfigSha2>::new(
+        GKRScheme::GkrSquare,
+    ));
+    test_gkr_correctness_helper::<BabyBearExtConfigKeccak>(
+        &Config::<BabyBearExtConfigKeccak>::new(GKRScheme::GkrSquare),
+    );
 }
 
 #[allow(unreachable_patterns)]
@@ -79,17 +570,20 @@ fn test_gkr_correctness_helper<C: GKRConfig>(config: &Config<C>) {
         FieldType::GF2 => 1,
         FieldType::M31 => 2,
         FieldType::BN254 => 2,
+        FieldType::Goldilocks => 2,
+        FieldType::BabyBear => 2,
         _ => unreachable!(),
     };
     println!(
-        "Proving {} keccak instances at once.",
+        "Proving {} instances at once.",
         circuit_copy_size * C::get_field_pack_size()
     );
 
     println!("Config created.");
-    let circuit_path = match C::FIELD_TYPE {
-        FieldType::GF2 => KECCAK_GF2_CIRCUIT,
-        _ => KECCAK_M31_CIRCUIT, // Use this for both M31 and BN254-Fr
+    let circuit_path = match (&config.gkr_scheme, C::FIELD_TYPE) {
+        (GKRScheme::GkrSquare, _) => POSEIDON_CIRCUIT,
+        (_, FieldType::GF2) => KECCAK_GF2_CIRCUIT,
+        _ => KECCAK_M31_CIRCUIT, // Use this for M31, BN254-Fr, Goldilocks and BabyBear
     };
 
     let mut circuit = Circuit::<C>::load_circuit(circuit_path);
@@ -102,7 +596,9 @@ fn test_gkr_correctness_helper<C: GKRConfig>(config: &Config<C>) {
     prover.prepare_mem(&circuit);
 
     let proving_start = Instant::now();
-    let (claimed_v, proof) = prover.prove(&mut circuit);
+    let (_claimed_v, proof) = prover.prove(&mut circuit);
+    let outputs = circuit.layers.last().unwrap().output_vals.clone();
+    let expected_outputs = ExpectedOutputs::Values(&outputs);
     println!("Proving time: {} ms", proving_start.elapsed().as_millis());
 
     println!("Proof generated. Size: {} bytes", proof.bytes.len());
@@ -129,7 +625,7 @@ fn test_gkr_correctness_helper<C: GKRConfig>(config: &Config<C>) {
     let verifier = Verifier::new(config);
     println!("Verifier created.");
     let verification_start = Instant::now();
-    assert!(verifier.verify(&mut circuit, &claimed_v, &proof),);
+    assert!(verifier.verify(&mut circuit, &expected_outputs, &proof),);
     println!(
         "Verification time: {} ms",
         verification_start.elapsed().as_millis()
@@ -141,9 +637,10 @@ fn test_gkr_correctness_helper<C: GKRConfig>(config: &Config<C>) {
     let random_change = rng.gen_range(1..256) as u8;
     bad_proof.bytes[random_idx] ^= random_change;
 
-    // Catch the panic and treat it as returning `false`
+    // The byte may land in the GKR part of the proof, where a value that no longer
+    // deserializes hits the unwrap in `Proof::get_next_and_step`: treat that panic as `false`
     let result = panic::catch_unwind(AssertUnwindSafe(|| {
-        verifier.verify(&mut circuit, &claimed_v, &bad_proof)
+        verifier.verify(&mut circuit, &expected_outputs, &bad_proof)
     }));
 
     let final_result = result.unwrap_or_default();
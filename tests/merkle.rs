@@ -0,0 +1,76 @@
+use arith::{Field, FieldSerde, M31};
+use ark_std::test_rng;
+use expander_rs::{FiatShamirHash, Keccak256hasher, MerkleMultiPath, MerkleTree, SHA256hasher};
+
+#[test]
+fn test_merkle_multi_path() {
+    test_merkle_multi_path_helper::<SHA256hasher>();
+    test_merkle_multi_path_helper::<Keccak256hasher>();
+}
+
+fn test_merkle_multi_path_helper<H: FiatShamirHash>() {
+    let mut rng = test_rng();
+
+    for depth in 0..6 {
+        let leaves = (0..1 << depth)
+            .map(|_| {
+                (0..3)
+                    .map(|_| M31::random_unsafe(&mut rng))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let tree = MerkleTree::<H>::from_field_leaves(&leaves);
+        let root = tree.root();
+
+        // shared nodes, siblings in the queries and repeated queries
+        let leaf_num = 1usize << depth;
+        let index_sets = [
+            vec![0],
+            vec![leaf_num - 1, 0],
+            (0..leaf_num).collect(),
+            vec![leaf_num / 2, leaf_num / 2, 0, leaf_num - 1],
+        ];
+        for indices in index_sets.iter() {
+            let digests = indices
+                .iter()
+                .map(|idx| MerkleTree::<H>::hash_field_leaf(&leaves[*idx]))
+                .collect::<Vec<_>>();
+            let multi_path = tree.multi_path(indices);
+            assert!(multi_path.verify::<H>(&root, depth, indices, &digests));
+
+            // no more nodes than the separate paths
+            let mut unique_indices = indices.clone();
+            unique_indices.sort();
+            unique_indices.dedup();
+            assert!(multi_path.nodes.len() <= unique_indices.len() * depth);
+
+            let mut buffer = vec![];
+            multi_path.serialize_into(&mut buffer).unwrap();
+            let deserialized =
+                MerkleMultiPath::deserialize_from(buffer.as_slice(), depth, indices.len()).unwrap();
+            assert_eq!(deserialized, multi_path);
+
+            // a wrong leaf
+            let mut bad_digests = digests.clone();
+            bad_digests[0][0] ^= 1;
+            assert!(!multi_path.verify::<H>(&root, depth, indices, &bad_digests));
+
+            // a wrong node
+            if !multi_path.nodes.is_empty() {
+                let mut bad_multi_path = multi_path.clone();
+                bad_multi_path.nodes[0][0] ^= 1;
+                assert!(!bad_multi_path.verify::<H>(&root, depth, indices, &digests));
+            }
+        }
+
+        // the leaves are hashed from their serialization
+        let mut buffer = vec![];
+        leaves[0]
+            .iter()
+            .for_each(|v| v.serialize_into(&mut buffer).unwrap());
+        assert_eq!(
+            MerkleTree::<H>::hash_leaf(&buffer),
+            MerkleTree::<H>::hash_field_leaf(&leaves[0])
+        );
+    }
+}